@@ -0,0 +1,238 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use grin_core::core::amount_from_hr_string;
+
+/// Command to run in headless mode.
+#[derive(Clone, PartialEq)]
+pub enum HeadlessCommand {
+    /// Show usage information.
+    Help,
+    /// Show list of wallets.
+    WalletList,
+    /// Open the wallet and show its balance and accounts.
+    WalletOpen,
+    /// Create request to send funds.
+    /// * amount
+    /// * receiver address
+    Send(u64, Option<String>),
+    /// Create invoice to receive funds.
+    /// * amount
+    Receive(u64),
+    /// Create response for Slatepack message from sender or invoice issuer.
+    /// * Slatepack message or file path
+    Respond(String),
+    /// Finalize transaction from Slatepack message response and post it.
+    /// * Slatepack message or file path
+    Finalize(String),
+    /// Post finalized transaction to blockchain.
+    /// * local tx id
+    Post(u32),
+    /// Start integrated node and run it until interruption.
+    NodeStart,
+    /// Show status of running integrated node.
+    NodeStatus,
+}
+
+/// Parsed headless mode arguments.
+pub struct HeadlessArgs {
+    /// Command to run.
+    pub command: HeadlessCommand,
+    /// Wallet identifier or name.
+    pub wallet: Option<String>,
+    /// Wallet account label.
+    pub account: Option<String>,
+    /// External node URL to connect the wallet.
+    pub node: Option<String>,
+}
+
+impl HeadlessArgs {
+    /// Argument to start application in headless mode.
+    pub const HEADLESS_ARG: &'static str = "--headless";
+
+    /// Usage information.
+    pub const USAGE: &'static str = "Usage: grim --headless <command> [options]
+
+Commands:
+  wallet list                 Show list of wallets
+  wallet open                 Open the wallet and show balance and accounts
+  send <amount> [address]     Create request to send funds, send it over Tor if address provided
+  receive <amount>            Create invoice to receive funds
+  respond <slatepack>         Create response for request to send funds or invoice
+  finalize <slatepack>        Finalize transaction from response and post it
+  post <tx id>                Post finalized transaction to blockchain
+  node start                  Start integrated node until interruption
+  node status                 Show status of running integrated node
+  help                        Show this message
+
+Options:
+  --wallet <id|name>          Wallet to use, required when there is more than one wallet
+  --account <label>           Wallet account to use
+  --node <url>                External node to connect the wallet instead of integrated node
+
+Slatepack message can be provided as text or as a path to the file.
+Integrated node is started until the command is finished and synced before wallet opening.
+Wallet password is taken from GRIM_WALLET_PASSWORD environment variable or asked at input.";
+
+    /// Parse arguments provided after [`HeadlessArgs::HEADLESS_ARG`].
+    pub fn parse(args: &[String]) -> Result<HeadlessArgs, String> {
+        let mut wallet = None;
+        let mut account = None;
+        let mut node = None;
+        let mut values: Vec<String> = vec![];
+
+        // Split options and positional values.
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--wallet" | "-w" => {
+                    wallet = Some(iter.next().ok_or("Wallet value is missing")?.clone());
+                }
+                "--account" | "-a" => {
+                    account = Some(iter.next().ok_or("Account value is missing")?.clone());
+                }
+                "--node" | "-n" => {
+                    node = Some(iter.next().ok_or("Node value is missing")?.clone());
+                }
+                "--help" | "-h" => {
+                    values = vec!["help".to_string()];
+                    break;
+                }
+                a if a.starts_with('-') && a.len() > 1 => {
+                    return Err(format!("Unknown option: {}", a));
+                }
+                _ => values.push(arg.clone())
+            }
+        }
+
+        let value = |i: usize, name: &str| -> Result<String, String> {
+            values.get(i).cloned().ok_or(format!("Argument <{}> is missing", name))
+        };
+        let amount = |v: String| -> Result<u64, String> {
+            let a = amount_from_hr_string(v.trim().replace(",", ".").as_str())
+                .map_err(|_| format!("Incorrect amount: {}", v))?;
+            if a == 0 {
+                return Err("Amount should be more than zero".to_string());
+            }
+            Ok(a)
+        };
+
+        let command = match values.first().map(|v| v.as_str()) {
+            None | Some("help") => HeadlessCommand::Help,
+            Some("wallet") => match value(1, "list|open")?.as_str() {
+                "list" => HeadlessCommand::WalletList,
+                "open" => HeadlessCommand::WalletOpen,
+                c => return Err(format!("Unknown wallet command: {}", c))
+            },
+            Some("send") => {
+                HeadlessCommand::Send(amount(value(1, "amount")?)?, values.get(2).cloned())
+            }
+            Some("receive") => HeadlessCommand::Receive(amount(value(1, "amount")?)?),
+            Some("respond") => HeadlessCommand::Respond(value(1, "slatepack")?),
+            Some("finalize") => HeadlessCommand::Finalize(value(1, "slatepack")?),
+            Some("post") => {
+                let id = value(1, "tx id")?;
+                HeadlessCommand::Post(id.parse::<u32>()
+                    .map_err(|_| format!("Incorrect tx id: {}", id))?)
+            }
+            Some("node") => match value(1, "start|status")?.as_str() {
+                "start" => HeadlessCommand::NodeStart,
+                "status" => HeadlessCommand::NodeStatus,
+                c => return Err(format!("Unknown node command: {}", c))
+            },
+            Some(c) => return Err(format!("Unknown command: {}", c))
+        };
+        Ok(HeadlessArgs { command, wallet, account, node })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<HeadlessArgs, String> {
+        let args = args.split_whitespace().map(|a| a.to_string()).collect::<Vec<String>>();
+        HeadlessArgs::parse(&args)
+    }
+
+    #[test]
+    fn parses_help() {
+        assert!(parse("").unwrap().command == HeadlessCommand::Help);
+        assert!(parse("help").unwrap().command == HeadlessCommand::Help);
+        assert!(parse("send 1 --help").unwrap().command == HeadlessCommand::Help);
+    }
+
+    #[test]
+    fn parses_commands_with_options() {
+        let args = parse("wallet open --wallet main -a savings").unwrap();
+        assert!(args.command == HeadlessCommand::WalletOpen);
+        assert_eq!(args.wallet, Some("main".to_string()));
+        assert_eq!(args.account, Some("savings".to_string()));
+
+        let args = parse("-w 2 node status").unwrap();
+        assert!(args.command == HeadlessCommand::NodeStatus);
+        assert_eq!(args.wallet, Some("2".to_string()));
+        assert_eq!(args.account, None);
+        assert_eq!(args.node, None);
+
+        let args = parse("wallet open --node https://main.gri.mw").unwrap();
+        assert!(args.command == HeadlessCommand::WalletOpen);
+        assert_eq!(args.node, Some("https://main.gri.mw".to_string()));
+
+        assert!(parse("post 15").unwrap().command == HeadlessCommand::Post(15));
+        assert!(parse("respond file.slatepack").unwrap().command ==
+            HeadlessCommand::Respond("file.slatepack".to_string()));
+    }
+
+    #[test]
+    fn parses_amounts() {
+        assert!(parse("send 1.5").unwrap().command == HeadlessCommand::Send(1_500_000_000, None));
+        assert!(parse("send 0,25 grin1address").unwrap().command ==
+            HeadlessCommand::Send(250_000_000, Some("grin1address".to_string())));
+        assert!(parse("receive 2").unwrap().command == HeadlessCommand::Receive(2_000_000_000));
+    }
+
+    #[test]
+    fn rejects_invalid_amounts() {
+        assert_eq!(parse("send abc").err(), Some("Incorrect amount: abc".to_string()));
+        assert_eq!(parse("receive 0").err(),
+                   Some("Amount should be more than zero".to_string()));
+        assert_eq!(parse("send").err(), Some("Argument <amount> is missing".to_string()));
+        assert!(parse("send -1").is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_options() {
+        assert_eq!(parse("wallet list --verbose").err(),
+                   Some("Unknown option: --verbose".to_string()));
+        assert_eq!(parse("-x node start").err(), Some("Unknown option: -x".to_string()));
+    }
+
+    #[test]
+    fn rejects_missing_option_values() {
+        assert_eq!(parse("wallet open --wallet").err(),
+                   Some("Wallet value is missing".to_string()));
+        assert_eq!(parse("wallet open -a").err(), Some("Account value is missing".to_string()));
+        assert_eq!(parse("wallet open -n").err(), Some("Node value is missing".to_string()));
+    }
+
+    #[test]
+    fn rejects_unknown_commands() {
+        assert_eq!(parse("stake 1").err(), Some("Unknown command: stake".to_string()));
+        assert_eq!(parse("wallet delete").err(),
+                   Some("Unknown wallet command: delete".to_string()));
+        assert_eq!(parse("node stop").err(), Some("Unknown node command: stop".to_string()));
+        assert_eq!(parse("post abc").err(), Some("Incorrect tx id: abc".to_string()));
+    }
+}
@@ -0,0 +1,467 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use bytes::Bytes;
+use grin_chain::SyncStatus;
+use grin_core::core::amount_to_hr_string;
+use grin_util::to_base64;
+use grin_util::types::ZeroingString;
+use grin_wallet_libwallet::{Slate, SlateState, SlatepackAddress};
+use http_body_util::{BodyExt, Full};
+use hyper_util::client::legacy::Client;
+use hyper_util::rt::TokioExecutor;
+use serde_json::Value;
+
+use crate::cli::{HeadlessArgs, HeadlessCommand};
+use crate::node::{Node, NodeConfig, NodeError};
+use crate::wallet::types::{ConnectionMethod, WalletData};
+use crate::wallet::{ConnectionsConfig, ExternalConnection, InvoiceRequest, Wallet, WalletList};
+
+/// Environment variable to provide wallet password.
+const PASSWORD_ENV: &'static str = "GRIM_WALLET_PASSWORD";
+
+/// Delay to check node and wallet state.
+const WAIT_DELAY: Duration = Duration::from_millis(1000);
+
+/// Number of attempts to load wallet data before an error.
+const SYNC_ATTEMPTS: u8 = 3;
+
+/// Run command provided at arguments without graphical interface, returns exit code.
+pub fn start_headless(args: Vec<String>) -> i32 {
+    crate::setup_i18n();
+    let args = match HeadlessArgs::parse(&args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, HeadlessArgs::USAGE);
+            return 1;
+        }
+    };
+    let res = match args.command {
+        HeadlessCommand::Help => {
+            println!("{}", HeadlessArgs::USAGE);
+            Ok(())
+        }
+        HeadlessCommand::WalletList => wallet_list(),
+        HeadlessCommand::NodeStart => node_start(),
+        HeadlessCommand::NodeStatus => node_status(),
+        _ => wallet_command(&args)
+    };
+    match res {
+        Ok(_) => 0,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            1
+        }
+    }
+}
+
+/// Print list of wallets for current chain type.
+fn wallet_list() -> Result<(), String> {
+    let wallets = WalletList::default();
+    if wallets.list().is_empty() {
+        println!("No wallets");
+        return Ok(());
+    }
+    for w in wallets.list() {
+        let config = w.get_config();
+        let conn = match w.get_current_connection() {
+            ConnectionMethod::Integrated => "integrated node".to_string(),
+            ConnectionMethod::External(_, url) => url
+        };
+        println!("{}\t{}\t{}\t{}", config.id, config.name, config.account, conn);
+    }
+    Ok(())
+}
+
+/// Open the wallet to run provided command and close it after.
+fn wallet_command(args: &HeadlessArgs) -> Result<(), String> {
+    let wallet = find_wallet(&args.wallet)?;
+
+    // Use external node connection if provided.
+    if let Some(url) = &args.node {
+        let url = url.trim().trim_end_matches('/').to_string();
+        let conn = ConnectionsConfig::ext_conn_list()
+            .into_iter()
+            .find(|c| c.url == url)
+            .unwrap_or_else(|| {
+                let conn = ExternalConnection::new(url.clone(), None);
+                ConnectionsConfig::add_ext_conn(conn.clone());
+                conn
+            });
+        wallet.update_connection(&ConnectionMethod::External(conn.id, conn.url));
+    }
+
+    // Start integrated node if needed.
+    let integrated = wallet.get_current_connection() == ConnectionMethod::Integrated;
+    if integrated {
+        // Node started by another process can not be used as integrated.
+        if request_node_status().is_ok() {
+            return Err("Integrated node is already running in another process, \
+            connect to it or to external node with --node option".to_string());
+        }
+        println!("Starting integrated node, use --node option to connect to external node");
+        start_node()?;
+    }
+
+    let res = match read_password() {
+        Ok(pass) => match wallet.open(pass) {
+            Ok(_) => {
+                let res = run_wallet_command(&wallet, args);
+                close_wallet(&wallet);
+                res
+            }
+            Err(e) => Err(format!("Wallet opening failed: {}", e))
+        },
+        Err(e) => Err(e)
+    };
+
+    if integrated {
+        stop_node();
+    }
+    res
+}
+
+/// Run command with opened wallet.
+fn run_wallet_command(wallet: &Wallet, args: &HeadlessArgs) -> Result<(), String> {
+    if let Some(acc) = &args.account {
+        wallet.set_active_account(acc).map_err(|e| format!("{}", e))?;
+    }
+    let data = wait_wallet_data(wallet)?;
+    match &args.command {
+        HeadlessCommand::WalletOpen => {
+            print_wallet_info(wallet, &data);
+        }
+        HeadlessCommand::Send(amount, addr) => {
+            if data.info.amount_currently_spendable < *amount {
+                return Err("Not enough funds".to_string());
+            }
+            let receiver = match addr {
+                None => None,
+                Some(addr) => Some(SlatepackAddress::try_from(addr.trim())
+                    .map_err(|_| format!("Incorrect address: {}", addr))?)
+            };
+            let slate = wallet.send(*amount, receiver.clone(), true, None)
+                .map_err(|e| format!("{}", e))?;
+            if let Some(r) = receiver {
+                println!("Sending over Tor to {}", r);
+                let res = tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .unwrap()
+                    .block_on(async {
+                        wallet.send_tor(&slate, &r).await
+                    });
+                match res {
+                    Ok(s) => {
+                        let s = wallet.finalize(&s).map_err(|e| format!("{}", e))?;
+                        wallet.post(&s).map_err(|e| format!("{}", e))?;
+                        println!("Transaction {} was finalized and posted", s.id);
+                    }
+                    Err(e) => {
+                        eprintln!("Sending over Tor failed: {}", e);
+                        print_slatepack(wallet, &slate)?;
+                    }
+                }
+            } else {
+                print_slatepack(wallet, &slate)?;
+            }
+        }
+        HeadlessCommand::Receive(amount) => {
+            let slate = wallet.issue_invoice(*amount, InvoiceRequest::default()).map_err(|e| format!("{}", e))?;
+            print_slatepack(wallet, &slate)?;
+        }
+        HeadlessCommand::Respond(m) => {
+            let slate = wallet.parse_slatepack(&read_message(m))
+                .map_err(|e| format!("{}", e))?;
+            let res = match slate.state {
+                SlateState::Standard1 => wallet.receive(&slate),
+                SlateState::Invoice1 => {
+                    if data.info.amount_currently_spendable < slate.amount {
+                        return Err("Not enough funds".to_string());
+                    }
+                    wallet.pay(&slate)
+                }
+                _ => return Err("Slatepack message is not a request".to_string())
+            };
+            let slate = res.map_err(|e| format!("{}", e))?;
+            print_slatepack(wallet, &slate)?;
+        }
+        HeadlessCommand::Finalize(m) => {
+            let slate = wallet.parse_slatepack(&read_message(m))
+                .map_err(|e| format!("{}", e))?;
+            if slate.state != SlateState::Standard2 && slate.state != SlateState::Invoice2 {
+                return Err("Slatepack message is not a response".to_string());
+            }
+            let slate = wallet.finalize(&slate).map_err(|e| format!("{}", e))?;
+            wallet.post(&slate).map_err(|e| format!("{}", e))?;
+            println!("Transaction {} was finalized and posted", slate.id);
+        }
+        HeadlessCommand::Post(id) => {
+            let slate = wallet.get_tx(*id).ok_or(format!("Transaction {} not found", id))?;
+            wallet.post(&slate).map_err(|e| format!("{}", e))?;
+            println!("Transaction {} was posted", slate.id);
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Find wallet by identifier or name, select single wallet if nothing was provided.
+fn find_wallet(value: &Option<String>) -> Result<Wallet, String> {
+    let wallets = WalletList::default();
+    let list = wallets.list();
+    match value {
+        None => {
+            if list.len() == 1 {
+                return Ok(list[0].clone());
+            }
+            if list.is_empty() {
+                Err("No wallets".to_string())
+            } else {
+                Err("Choose the wallet with --wallet option".to_string())
+            }
+        }
+        Some(v) => {
+            list.iter()
+                .find(|w| {
+                    let config = w.get_config();
+                    &config.id.to_string() == v || &config.name == v
+                })
+                .cloned()
+                .ok_or(format!("Wallet {} not found", v))
+        }
+    }
+}
+
+/// Read wallet password from environment or input.
+fn read_password() -> Result<ZeroingString, String> {
+    if let Ok(pass) = std::env::var(PASSWORD_ENV) {
+        return Ok(ZeroingString::from(pass));
+    }
+    print!("Password: ");
+    let _ = std::io::stdout().flush();
+    let mut pass = String::new();
+    std::io::stdin().read_line(&mut pass).map_err(|e| format!("{}", e))?;
+    Ok(ZeroingString::from(pass.trim_end_matches(&['\r', '\n'][..]).to_string()))
+}
+
+/// Read Slatepack message from file or use provided value as message.
+fn read_message(value: &String) -> String {
+    match std::fs::read_to_string(value) {
+        Ok(m) => m,
+        Err(_) => value.clone()
+    }
+}
+
+/// Print saved Slatepack message for provided [`Slate`].
+fn print_slatepack(wallet: &Wallet, slate: &Slate) -> Result<(), String> {
+    let path = wallet.get_config().get_slate_path(slate);
+    let message = std::fs::read_to_string(&path).map_err(|e| format!("{}", e))?;
+    println!("{}", message);
+    Ok(())
+}
+
+/// Print wallet balance and accounts.
+fn print_wallet_info(wallet: &Wallet, data: &WalletData) {
+    let config = wallet.get_config();
+    let info = &data.info;
+    println!("Wallet: {} ({})", config.name, config.id);
+    println!("Account: {}", config.account);
+    if let Some(addr) = wallet.slatepack_address() {
+        println!("Address: {}", addr);
+    }
+    println!("Height: {}", info.last_confirmed_height);
+    println!("Spendable: {}", amount_to_hr_string(info.amount_currently_spendable, true));
+    println!("Awaiting confirmation: {}",
+             amount_to_hr_string(info.amount_awaiting_confirmation, true));
+    println!("Awaiting finalization: {}",
+             amount_to_hr_string(info.amount_awaiting_finalization, true));
+    println!("Locked: {}", amount_to_hr_string(info.amount_locked, true));
+    println!("Total: {}", amount_to_hr_string(info.total, true));
+    println!("Transactions: {}", data.txs.as_ref().map(|txs| txs.len()).unwrap_or(0));
+    println!("Accounts:");
+    for acc in wallet.accounts() {
+        println!("  {}\t{}\t{}",
+                 acc.label,
+                 acc.path,
+                 amount_to_hr_string(acc.spendable_amount, true));
+    }
+}
+
+/// Wait for wallet data to be loaded after opening.
+fn wait_wallet_data(wallet: &Wallet) -> Result<WalletData, String> {
+    let mut attempts = 0;
+    let mut last_progress = 0;
+    loop {
+        if wallet.sync_error() {
+            attempts += 1;
+            if attempts >= SYNC_ATTEMPTS {
+                return Err("Wallet loading failed".to_string());
+            }
+            wallet.set_sync_error(false);
+            wallet.sync();
+        }
+        if let Some(data) = wallet.get_data() {
+            if data.txs.is_some() && !wallet.syncing() {
+                return Ok(data);
+            }
+        }
+        let progress = wallet.info_sync_progress();
+        if progress != last_progress && progress != 0 {
+            println!("Wallet loading: {}%", progress);
+            last_progress = progress;
+        }
+        thread::sleep(WAIT_DELAY);
+    }
+}
+
+/// Close the wallet and wait until it will be closed.
+fn close_wallet(wallet: &Wallet) {
+    wallet.close();
+    while wallet.is_open() {
+        thread::sleep(Duration::from_millis(300));
+    }
+}
+
+/// Start integrated node and wait for synchronization.
+fn start_node() -> Result<(), String> {
+    Node::start();
+    let mut last_status = "".to_string();
+    loop {
+        thread::sleep(WAIT_DELAY);
+        if let Some(e) = Node::get_error() {
+            return Err(node_error_text(e));
+        }
+        let status = Node::get_sync_status_text();
+        if status != last_status {
+            println!("{}", status);
+            last_status = status;
+        }
+        if Node::get_sync_status() == Some(SyncStatus::NoSync) {
+            return Ok(());
+        }
+    }
+}
+
+/// Stop integrated node and wait until it will be stopped.
+fn stop_node() {
+    Node::stop(false);
+    while Node::is_running() {
+        thread::sleep(Duration::from_millis(300));
+    }
+}
+
+/// Run integrated node until interruption.
+fn node_start() -> Result<(), String> {
+    // Wait for interruption signal at separate thread.
+    let interrupted = Arc::new(AtomicBool::new(false));
+    let signal = interrupted.clone();
+    thread::spawn(move || {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async {
+                let _ = tokio::signal::ctrl_c().await;
+                signal.store(true, Ordering::Relaxed);
+            });
+    });
+
+    Node::start();
+    let mut last_status = "".to_string();
+    while !interrupted.load(Ordering::Relaxed) {
+        thread::sleep(WAIT_DELAY);
+        if let Some(e) = Node::get_error() {
+            return Err(node_error_text(e));
+        }
+        let mut status = Node::get_sync_status_text();
+        if let Some(stats) = Node::get_stats() {
+            status = format!("{} | height: {} | peers: {}",
+                             status,
+                             stats.chain_stats.height,
+                             stats.peer_count);
+        }
+        if status != last_status {
+            println!("{}", status);
+            last_status = status;
+        }
+    }
+    println!("Stopping node");
+    stop_node();
+    Ok(())
+}
+
+/// Show status of running integrated node.
+fn node_status() -> Result<(), String> {
+    let status = request_node_status()?;
+    println!("Chain: {}", status["chain"].as_str().unwrap_or_default());
+    println!("Sync status: {}", status["sync_status"].as_str().unwrap_or_default());
+    println!("Height: {}", status["tip"]["height"]);
+    println!("Last block: {}", status["tip"]["last_block_pushed"].as_str().unwrap_or_default());
+    println!("Difficulty: {}", status["tip"]["total_difficulty"]);
+    println!("Peers: {}", status["connections"]);
+    println!("User agent: {}", status["user_agent"].as_str().unwrap_or_default());
+    Ok(())
+}
+
+/// Request status of running integrated node from its API.
+fn request_node_status() -> Result<Value, String> {
+    let url = format!("http://{}/v2/owner", NodeConfig::get_api_address());
+    let body = r#"{"id":1,"jsonrpc":"2.0","method":"get_status","params":[]}"#;
+    let mut req = hyper::Request::builder()
+        .method(hyper::Method::POST)
+        .uri(url);
+    // Setup secret key auth.
+    if let Some(key) = NodeConfig::get_api_secret(false) {
+        let basic_auth = format!("Basic {}", to_base64(&format!("grin:{}", key)));
+        req = req.header(hyper::header::AUTHORIZATION, basic_auth);
+    }
+    let req = req.body(Full::<Bytes>::from(body)).map_err(|e| format!("{}", e))?;
+
+    let res: Result<Value, String> = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(async {
+            let client = Client::builder(TokioExecutor::new()).build_http::<Full<Bytes>>();
+            let res = client.request(req).await.map_err(|_| "Node is not running".to_string())?;
+            let body = res.into_body()
+                .collect()
+                .await
+                .map_err(|e| format!("{}", e))?
+                .to_bytes();
+            serde_json::from_slice::<Value>(&body).map_err(|e| format!("{}", e))
+        });
+    let status = res?["result"]["Ok"].clone();
+    if status.is_null() {
+        return Err("Node status is not available".to_string());
+    }
+    Ok(status)
+}
+
+/// Get text for integrated node error.
+fn node_error_text(e: NodeError) -> String {
+    match e {
+        NodeError::Storage => "Node storage error",
+        NodeError::P2P => "Node P2P server error",
+        NodeError::API => "Node API server error",
+        NodeError::Configuration => "Node configuration error",
+        NodeError::Unknown => "Node error"
+    }.to_string()
+}
@@ -0,0 +1,370 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::io::Write;
+use grin_core::core::amount_from_hr_string;
+use grin_util::ToHex;
+use grin_wallet_libwallet::SlatepackAddress;
+use rand::Rng;
+use ring::constant_time;
+use serde_derive::Deserialize;
+use serde_json::{json, Value};
+
+use crate::node::Node;
+use crate::wallet::types::{CoinControl, WalletTask, WalletTransaction};
+use crate::wallet::{InvoiceRequest, Wallet, WalletList};
+use crate::Settings;
+
+/// JSON-RPC request sent to application socket.
+#[derive(Deserialize)]
+struct ControlRequest {
+    jsonrpc: String,
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+/// JSON-RPC error.
+struct ControlError {
+    code: i32,
+    message: String,
+}
+
+impl ControlError {
+    fn new(code: i32, message: &str) -> Self {
+        Self { code, message: message.to_string() }
+    }
+}
+
+/// Invalid JSON-RPC request error code.
+const INVALID_REQUEST: i32 = -32600;
+/// Unknown method error code.
+const METHOD_NOT_FOUND: i32 = -32601;
+/// Invalid method parameters error code.
+const INVALID_PARAMS: i32 = -32602;
+/// Missing or wrong token error code.
+const UNAUTHORIZED: i32 = -32001;
+/// Wallet was not found or not opened error code.
+const WALLET_NOT_AVAILABLE: i32 = -32002;
+
+/// Handles JSON-RPC requests to control running application through the socket.
+pub struct ControlRpc {}
+
+impl ControlRpc {
+    /// Protocol version, incremented on incompatible changes.
+    pub const VERSION: u32 = 1;
+
+    /// Create token file readable only for current user to authorize requests
+    /// if it does not exist.
+    pub fn init_token() {
+        let path = Settings::socket_token_path();
+        if path.exists() {
+            // Fix permissions of existing file.
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                let _ = fs::set_permissions(&path, fs::Permissions::from_mode(0o600));
+            }
+            return;
+        }
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        if let Ok(mut file) = options.open(&path) {
+            let token = rand::rng().random::<[u8; 32]>().to_hex();
+            let _ = file.write_all(token.as_bytes());
+        }
+    }
+
+    /// Handle data received at socket, returns response if data is a JSON-RPC request.
+    pub fn handle(data: &str) -> Option<String> {
+        let data = data.trim();
+        if !data.starts_with("{") {
+            return None;
+        }
+        let req = serde_json::from_str::<ControlRequest>(data).ok()?;
+        let res = if req.jsonrpc != "2.0" {
+            Err(ControlError::new(INVALID_REQUEST, "Unsupported JSON-RPC version"))
+        } else if !Self::authorized(&req.params) {
+            Err(ControlError::new(UNAUTHORIZED, "Unauthorized"))
+        } else {
+            Self::call(req.method.as_str(), &req.params)
+        };
+        let res = match res {
+            Ok(result) => json!({
+                "jsonrpc": "2.0",
+                "id": req.id,
+                "result": result
+            }),
+            Err(e) => json!({
+                "jsonrpc": "2.0",
+                "id": req.id,
+                "error": {
+                    "code": e.code,
+                    "message": e.message
+                }
+            })
+        };
+        Some(format!("{}\n", res))
+    }
+
+    /// Check if request contains correct token.
+    fn authorized(params: &Value) -> bool {
+        let token = match fs::read_to_string(Settings::socket_token_path()) {
+            Ok(t) => t,
+            Err(_) => return false
+        };
+        let token = token.trim();
+        match params["token"].as_str() {
+            Some(t) if !token.is_empty() => {
+                // Compare tokens in constant time.
+                constant_time::verify_slices_are_equal(t.as_bytes(), token.as_bytes()).is_ok()
+            }
+            _ => false
+        }
+    }
+
+    /// Call method with provided parameters.
+    fn call(method: &str, params: &Value) -> Result<Value, ControlError> {
+        match method {
+            "get_version" => Ok(json!({
+                "version": Self::VERSION,
+                "app_version": crate::VERSION
+            })),
+            "list_wallets" => Ok(Self::list_wallets()),
+            "get_balance" => {
+                let w = Self::opened_wallet(params)?;
+                let data = w.get_data()
+                    .ok_or(ControlError::new(WALLET_NOT_AVAILABLE, "Wallet is loading"))?;
+                Ok(json!({
+                    "account": w.get_config().account,
+                    "info": data.info
+                }))
+            }
+            "get_txs" => {
+                let w = Self::opened_wallet(params)?;
+                let txs = w.get_data()
+                    .and_then(|d| d.txs)
+                    .ok_or(ControlError::new(WALLET_NOT_AVAILABLE, "Wallet is loading"))?;
+                Ok(Value::Array(txs.iter().map(|tx| Self::tx_value(tx)).collect()))
+            }
+            "get_outputs" => {
+                let w = Self::opened_wallet(params)?;
+                let outputs = w.outputs()
+                    .map_err(|e| ControlError::new(WALLET_NOT_AVAILABLE, &e.to_string()))?;
+                Ok(Value::Array(outputs.iter().map(|o| json!({
+                    "commit": o.commit,
+                    "value": o.value,
+                    "status": o.status.to_string(),
+                    "height": o.height,
+                    "lock_height": o.lock_height,
+                    "is_coinbase": o.is_coinbase,
+                    "account": o.account,
+                    "spendable": o.spendable
+                })).collect()))
+            }
+            "wallet_task" => {
+                let w = Self::opened_wallet(params)?;
+                let task = Self::parse_task(&w, &params["task"])?;
+                w.task(task)
+                    .map_err(|e| ControlError::new(WALLET_NOT_AVAILABLE, &e.to_string()))?;
+                Ok(json!(null))
+            }
+            "get_node_stats" => Ok(Self::node_stats()),
+            _ => Err(ControlError::new(METHOD_NOT_FOUND, "Method not found"))
+        }
+    }
+
+    /// Get list of wallets for current chain type.
+    fn list_wallets() -> Value {
+        let opened = Wallet::opened_list();
+        let wallets = WalletList::default();
+        let list = wallets.list().iter().map(|w| {
+            let config = w.get_config();
+            let open = opened.iter().any(|o| o.get_config().id == config.id);
+            json!({
+                "id": config.id,
+                "name": config.name,
+                "account": config.account,
+                "open": open
+            })
+        }).collect();
+        Value::Array(list)
+    }
+
+    /// Find opened wallet by identifier from parameters.
+    fn opened_wallet(params: &Value) -> Result<Wallet, ControlError> {
+        let id = params["wallet_id"].as_i64()
+            .ok_or(ControlError::new(INVALID_PARAMS, "Missing wallet_id"))?;
+        Wallet::opened_list()
+            .into_iter()
+            .find(|w| w.get_config().id == id && w.is_open() && !w.is_closing())
+            .ok_or(ControlError::new(WALLET_NOT_AVAILABLE, "Wallet is not opened"))
+    }
+
+    /// Convert transaction into JSON value.
+    fn tx_value(tx: &WalletTransaction) -> Value {
+        json!({
+            "data": tx.data,
+            "amount": tx.amount,
+            "height": tx.height,
+            "broadcasting": tx.broadcasting(),
+            "can_finalize": tx.can_finalize(),
+            "can_cancel": tx.can_cancel(),
+            "action_error": tx.action_error.as_ref().map(|e| e.to_string()),
+            "note": tx.note
+        })
+    }
+
+    /// Parse wallet task from parameters.
+    fn parse_task(w: &Wallet, task: &Value) -> Result<WalletTask, ControlError> {
+        let invalid = |m: &str| ControlError::new(INVALID_PARAMS, m);
+        let amount = || -> Result<u64, ControlError> {
+            let a = task["amount"].as_str().ok_or(invalid("Missing amount"))?;
+            match amount_from_hr_string(a) {
+                Ok(a) if a > 0 => Ok(a),
+                _ => Err(invalid("Incorrect amount"))
+            }
+        };
+        let tx = || -> Result<WalletTransaction, ControlError> {
+            let id = task["tx_id"].as_u64().ok_or(invalid("Missing tx_id"))?;
+            w.get_data()
+                .and_then(|d| d.txs)
+                .and_then(|txs| txs.into_iter().find(|tx| tx.data.id as u64 == id))
+                .ok_or(invalid("Transaction not found"))
+        };
+        match task["type"].as_str().unwrap_or_default() {
+            "open_message" => {
+                let m = task["message"].as_str().ok_or(invalid("Missing message"))?;
+                Ok(WalletTask::OpenMessage(m.to_string()))
+            }
+            "send" => {
+                let addr = match task["address"].as_str() {
+                    None => None,
+                    Some(a) => Some(SlatepackAddress::try_from(a.trim())
+                        .map_err(|_| invalid("Incorrect address"))?)
+                };
+                let proof = task["payment_proof"].as_bool().unwrap_or(true);
+                Ok(WalletTask::Send(amount()?, addr, proof, Self::parse_coin_control(task)?))
+            }
+            "receive" => Ok(WalletTask::Receive(amount()?, Self::parse_invoice_request(task)?)),
+            "finalize" => {
+                let tx = tx()?;
+                if !tx.can_finalize() {
+                    return Err(invalid("Transaction can not be finalized"));
+                }
+                Ok(WalletTask::Finalize(None, tx.data.id))
+            }
+            "post" => {
+                let tx = tx()?;
+                if !tx.finalized() {
+                    return Err(invalid("Transaction is not finalized"));
+                }
+                Ok(WalletTask::Post(None, tx.data.id))
+            }
+            "cancel" => {
+                let tx = tx()?;
+                if !tx.can_cancel() {
+                    return Err(invalid("Transaction can not be cancelled"));
+                }
+                Ok(WalletTask::Cancel(tx))
+            }
+            _ => Err(invalid("Unknown task type"))
+        }
+    }
+
+    /// Parse invoice details from receive task parameters.
+    fn parse_invoice_request(task: &Value) -> Result<InvoiceRequest, ControlError> {
+        let invalid = |m: &str| ControlError::new(INVALID_PARAMS, m);
+        let requested_by = match task["requested_by"].as_str() {
+            None => "".to_string(),
+            Some(a) => {
+                SlatepackAddress::try_from(a.trim()).map_err(|_| invalid("Incorrect address"))?;
+                a.trim().to_string()
+            }
+        };
+        let expires = match task["expires_in"].is_null() {
+            true => None,
+            false => {
+                let secs = task["expires_in"].as_i64().filter(|s| *s > 0)
+                    .ok_or(invalid("Incorrect expires_in"))?;
+                Some(chrono::Utc::now().timestamp() + secs)
+            }
+        };
+        Ok(InvoiceRequest {
+            memo: task["memo"].as_str().unwrap_or_default().trim().to_string(),
+            requested_by,
+            expires,
+        })
+    }
+
+    /// Parse optional inputs selection policy from send task parameters.
+    fn parse_coin_control(task: &Value) -> Result<Option<CoinControl>, ControlError> {
+        if task["outputs"].is_null() && task["max_inputs"].is_null()
+            && task["change_outputs"].is_null() {
+            return Ok(None);
+        }
+        let invalid = |m: &str| ControlError::new(INVALID_PARAMS, m);
+        let mut coin_control = CoinControl::default();
+        if let Some(outputs) = task["outputs"].as_array() {
+            for o in outputs {
+                let commit = o.as_str().ok_or(invalid("Incorrect outputs"))?;
+                coin_control.outputs.push(commit.to_string());
+            }
+        }
+        if !task["max_inputs"].is_null() {
+            let max = task["max_inputs"].as_u64().filter(|m| *m > 0 && *m <= u32::MAX as u64)
+                .ok_or(invalid("Incorrect max_inputs"))?;
+            coin_control.max_inputs = Some(max as u32);
+        }
+        if !task["change_outputs"].is_null() {
+            let change = task["change_outputs"].as_u64().filter(|c| *c > 0 && *c <= 100)
+                .ok_or(invalid("Incorrect change_outputs"))?;
+            coin_control.change_outputs = change as usize;
+        }
+        Ok(Some(coin_control))
+    }
+
+    /// Get integrated node statistics.
+    fn node_stats() -> Value {
+        let stats = Node::get_stats().map(|stats| json!({
+            "peer_count": stats.peer_count,
+            "disk_usage_gb": stats.disk_usage_gb,
+            "header": {
+                "height": stats.header_stats.height,
+                "last_block_h": stats.header_stats.last_block_h.to_string(),
+                "total_difficulty": stats.header_stats.total_difficulty.to_num(),
+                "latest_timestamp": stats.header_stats.latest_timestamp.timestamp()
+            },
+            "chain": {
+                "height": stats.chain_stats.height,
+                "last_block_h": stats.chain_stats.last_block_h.to_string(),
+                "total_difficulty": stats.chain_stats.total_difficulty.to_num(),
+                "latest_timestamp": stats.chain_stats.latest_timestamp.timestamp()
+            }
+        }));
+        json!({
+            "running": Node::is_running(),
+            "syncing": !Node::not_syncing(),
+            "sync_status": Node::get_sync_status_text(),
+            "stats": stats
+        })
+    }
+}
@@ -28,7 +28,7 @@ use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::types::{QrScanResult, QrScanState};
 use crate::gui::views::View;
 use crate::wallet::types::PhraseSize;
-use crate::wallet::WalletUtils;
+use crate::wallet::{PaymentUri, WalletUtils};
 
 /// Camera QR code scanner.
 pub struct CameraContent {
@@ -303,6 +303,10 @@ impl CameraContent {
         // Check if string starts with Grin address prefix.
         let text_string = String::from_utf8(data.clone()).unwrap_or("".to_string());
         let text = text_string.trim();
+        // Check payment request URI.
+        if let Some(uri) = PaymentUri::parse(text) {
+            return QrScanResult::PaymentUri(uri);
+        }
         if text.starts_with("tgrin") || text.starts_with("grin") {
             if SlatepackAddress::try_from(text).is_ok() {
                 return QrScanResult::Address(ZeroingString::from(text));
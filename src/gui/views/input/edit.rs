@@ -40,6 +40,8 @@ pub struct TextEdit {
     copy: bool,
     /// Show paste button.
     paste: bool,
+    /// Flag to ignore text pasted from clipboard.
+    no_paste: bool,
     /// Show button to scan QR code into text.
     scan_qr: bool,
     /// Scan button was pressed.
@@ -65,6 +67,7 @@ impl TextEdit {
             password: false,
             copy: false,
             paste: false,
+            no_paste: false,
             scan_qr: false,
             scan_pressed: false,
             enter_pressed: false,
@@ -135,6 +138,11 @@ impl TextEdit {
                     data.get_temp(focused_input_id)
                 }).unwrap_or(egui::Id::new("")) == self.id;
 
+                // Ignore text pasted from clipboard.
+                if self.no_paste && ui.memory(|m| m.has_focus(self.id)) {
+                    ui.input_mut(|i| i.events.retain(|e| !matches!(e, egui::Event::Paste(_))));
+                }
+
                 // Show text edit.
                 let text_edit_resp = egui::TextEdit::singleline(input)
                     .id(self.id)
@@ -324,6 +332,12 @@ impl TextEdit {
         self
     }
 
+    /// Ignore text pasted from clipboard.
+    pub fn no_paste(mut self) -> Self {
+        self.no_paste = true;
+        self
+    }
+
     /// Show button to scan QR code to text.
     pub fn scan_qr(mut self) -> Self {
         self.scan_qr = true;
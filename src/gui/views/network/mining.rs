@@ -12,31 +12,44 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use egui::{RichText, CornerRadius, ScrollArea, StrokeKind};
+use egui::{Align2, CornerRadius, FontId, Rect, RichText, ScrollArea, Sense, StrokeKind, vec2};
 use egui::scroll_area::ScrollBarVisibility;
 use grin_chain::SyncStatus;
+use grin_core::core::amount_to_hr_string;
 use grin_servers::WorkerStats;
 
 use crate::gui::Colors;
-use crate::gui::icons::{BARBELL, CLOCK_AFTERNOON, CPU, CUBE, FADERS, FOLDER_DASHED, FOLDER_SIMPLE_MINUS, FOLDER_SIMPLE_PLUS, HARD_DRIVES, PLUGS, PLUGS_CONNECTED, POLYGON};
+use crate::gui::icons::{BARBELL, CHART_BAR, CLOCK_AFTERNOON, COINS, CPU, CUBE, FADERS, FILE_CSV, FOLDER_DASHED, FOLDER_SIMPLE_MINUS, FOLDER_SIMPLE_PLUS, HARD_DRIVES, LIST, PLUGS, PLUGS_CONNECTED, POLYGON, SEAL_CHECK, SEAL_QUESTION, SEAL_WARNING, USER};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Content, View};
 use crate::gui::views::network::NetworkContent;
 use crate::gui::views::network::setup::StratumSetup;
 use crate::gui::views::network::types::{NodeTab, NodeTabType};
 use crate::gui::views::types::ContentContainer;
-use crate::node::{Node, NodeConfig};
+use crate::node::{FoundBlock, FoundBlockStatus, HistorySample, MiningHistory, Node, NodeConfig, PplnsReport};
 
 /// Mining tab content.
 pub struct NetworkMining {
     /// Stratum server setup content.
     stratum_server_setup: StratumSetup,
+    /// Flag to show mining history instead of workers.
+    show_history: bool,
+    /// Mining history loaded from the file when server is not running.
+    saved_history: Option<MiningHistory>,
+    /// Mining history of running server with time of the last update.
+    history: Option<(MiningHistory, i64)>,
+    /// Message after reward split between workers.
+    pplns_message: Option<String>,
 }
 
 impl Default for NetworkMining {
     fn default() -> Self {
         Self {
             stratum_server_setup: StratumSetup::default(),
+            show_history: false,
+            saved_history: None,
+            history: None,
+            pplns_message: None,
         }
     }
 }
@@ -55,6 +68,11 @@ impl NodeTab for NetworkMining {
         // Show stratum server setup when mining server is not running.
         let stratum_stats = Node::get_stratum_stats();
         if !stratum_stats.is_running {
+            // Load saved mining history once to show it after setup.
+            if self.saved_history.is_none() {
+                self.saved_history = Some(Node::get_mining_history());
+            }
+            let history = self.saved_history.as_ref().unwrap();
             ScrollArea::vertical()
                 .id_salt("stratum_setup_scroll")
                 .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
@@ -63,10 +81,21 @@ impl NodeTab for NetworkMining {
                     ui.add_space(1.0);
                     View::max_width_ui(ui, Content::SIDE_PANEL_WIDTH * 1.3, |ui| {
                         self.stratum_server_setup.ui(ui, cb);
+                        if !history.samples.is_empty() || !history.blocks.is_empty() {
+                            ui.add_space(4.0);
+                            history_content_ui(ui, history);
+                        }
                     });
                 });
             return;
         }
+        self.saved_history = None;
+
+        // Update mining history of running server on interval.
+        let now = chrono::Utc::now().timestamp();
+        if self.history.as_ref().map(|(_, t)| now - t >= HISTORY_UPDATE_INTERVAL).unwrap_or(true) {
+            self.history = Some((Node::get_mining_history(), now));
+        }
 
         ui.add_space(1.0);
 
@@ -148,8 +177,74 @@ impl NodeTab for NetworkMining {
                                 [false, true, false, true]);
             });
         });
+        ui.add_space(8.0);
+
+        // Show buttons to share report and payouts to split block reward between miners.
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                let report_text = format!("{} {}", FILE_CSV, t!("network_mining.pplns_report"));
+                View::button(ui, report_text, Colors::white_or_black(false), || {
+                    let report = Node::get_pplns_report();
+                    let name = format!("pplns_{}.{}",
+                                       stratum_stats.block_height,
+                                       PplnsReport::FILE_EXTENSION);
+                    cb.share_data(name, report.to_csv().into_bytes()).unwrap_or_default();
+                });
+            });
+            columns[1].vertical_centered_justified(|ui| {
+                let payouts_text = format!("{} {}", COINS, t!("network_mining.pplns_payouts"));
+                View::button(ui, payouts_text, Colors::white_or_black(false), || {
+                    self.pplns_message = match Node::pay_pplns_report() {
+                        Some(report) => {
+                            let name = format!("payouts_{}.{}",
+                                               stratum_stats.block_height,
+                                               PplnsReport::FILE_EXTENSION);
+                            let data = report.payouts_csv().into_bytes();
+                            cb.share_data(name, data).unwrap_or_default();
+                            if report.invalid.is_empty() {
+                                None
+                            } else {
+                                let logins = report.invalid.join(", ");
+                                Some(t!("network_mining.pplns_invalid", "logins" => logins))
+                            }
+                        }
+                        None => Some(t!("network_mining.pplns_no_payouts"))
+                    };
+                });
+            });
+        });
+        ui.add_space(8.0);
+
+        // Show message after reward split.
+        if let Some(msg) = &self.pplns_message {
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(msg).size(16.0).color(Colors::inactive_text()));
+            });
+            ui.add_space(8.0);
+        }
+
+        // Show selection of workers or mining history.
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered(|ui| {
+                View::radio_value(ui,
+                                  &mut self.show_history,
+                                  false,
+                                  t!("network_mining.workers"));
+            });
+            columns[1].vertical_centered(|ui| {
+                View::radio_value(ui,
+                                  &mut self.show_history,
+                                  true,
+                                  t!("network_mining.history"));
+            });
+        });
         ui.add_space(4.0);
 
+        if self.show_history {
+            history_ui(ui, &self.history.as_ref().unwrap().0);
+            return;
+        }
+
         // Show workers stats or info text when possible.
         let workers_size = stratum_stats.worker_stats.len();
         if workers_size != 0 && stratum_stats.num_workers > 0 {
@@ -272,4 +367,257 @@ fn worker_item_ui(ui: &mut egui::Ui, ws: &WorkerStats, rounding: CornerRadius) {
             });
         });
     });
-}
\ No newline at end of file
+}
+
+/// Height of hourly hashrate chart.
+const CHART_HEIGHT: f32 = 120.0;
+
+/// Amount of last hourly samples to show.
+const HISTORY_SAMPLES: usize = 24;
+
+/// Interval in seconds to update mining history of running server.
+const HISTORY_UPDATE_INTERVAL: i64 = 5;
+
+/// Draw mining history with hashrate chart, hourly shares and found blocks.
+fn history_ui(ui: &mut egui::Ui, history: &MiningHistory) {
+    if history.samples.is_empty() && history.blocks.is_empty() {
+        if ui.available_height() > 142.0 {
+            View::center_content(ui, 142.0, |ui| {
+                ui.label(RichText::new(t!("network_mining.no_history"))
+                    .size(16.0)
+                    .color(Colors::inactive_text())
+                );
+            });
+        }
+        return;
+    }
+    ui.add_space(4.0);
+    View::horizontal_line(ui, Colors::item_stroke());
+    ScrollArea::vertical()
+        .id_salt("stratum_history_scroll")
+        .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+        .auto_shrink([false; 2])
+        .show(ui, |ui| {
+            ui.add_space(8.0);
+            history_content_ui(ui, history);
+        });
+}
+
+/// Draw hashrate chart, hourly shares and found blocks of mining history.
+fn history_content_ui(ui: &mut egui::Ui, history: &MiningHistory) {
+    // Take samples of last hours including current one.
+    let now = chrono::Utc::now().timestamp();
+    let start = now - now % HistorySample::DURATION
+        - (HISTORY_SAMPLES as i64 - 1) * HistorySample::DURATION;
+    let samples: Vec<&HistorySample> = history.samples
+        .iter()
+        .filter(|s| s.time >= start)
+        .collect();
+
+    // Show hourly hashrate chart.
+    View::sub_title(ui, format!("{} {}", CHART_BAR, t!("network_mining.hashrate_chart")));
+    hashrate_chart_ui(ui, &samples, start, now);
+    ui.add_space(8.0);
+
+    // Show hourly shares from newest to oldest.
+    if !samples.is_empty() {
+        View::sub_title(ui, format!("{} {}", LIST, t!("network_mining.shares_history")));
+        ui.add_space(4.0);
+        let size = samples.len();
+        for (index, sample) in samples.iter().rev().enumerate() {
+            sample_item_ui(ui, sample, now, View::item_rounding(index, size, false));
+        }
+        ui.add_space(8.0);
+    }
+
+    // Show found blocks from newest to oldest.
+    View::sub_title(ui, format!("{} {}", CUBE, t!("network_mining.found_blocks")));
+    ui.add_space(4.0);
+    if history.blocks.is_empty() {
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("network_mining.no_blocks"))
+                .size(16.0)
+                .color(Colors::inactive_text()));
+        });
+    }
+    let size = history.blocks.len();
+    for (index, block) in history.blocks.iter().rev().enumerate() {
+        found_block_item_ui(ui, block, View::item_rounding(index, size, false));
+    }
+    ui.add_space(8.0);
+}
+
+/// Draw bar chart of hourly hashrate at provided time with the first hour at start time.
+fn hashrate_chart_ui(ui: &mut egui::Ui, samples: &[&HistorySample], start: i64, now: i64) {
+    ui.add_space(4.0);
+    let size = vec2(ui.available_width(), CHART_HEIGHT);
+    let (rect, _) = ui.allocate_exact_size(size, Sense::hover());
+    let painter = ui.painter();
+    painter.rect(rect,
+                 CornerRadius::same(8.0 as u8),
+                 Colors::white_or_black(false),
+                 View::item_stroke(),
+                 StrokeKind::Middle);
+
+    // Draw maximal hashrate label.
+    let max = samples.iter().map(|s| s.hashrate(now)).fold(0.0, f64::max);
+    painter.text(rect.min + vec2(8.0, 6.0),
+                 Align2::LEFT_TOP,
+                 format!("{:.*}", 2, max),
+                 FontId::proportional(14.0),
+                 Colors::gray());
+    if max <= 0.0 {
+        return;
+    }
+
+    // Draw bars at positions of their hours with the current hour at the right.
+    let area = Rect::from_min_max(rect.min + vec2(8.0, 26.0), rect.max - vec2(8.0, 8.0));
+    let bar_width = area.width() / HISTORY_SAMPLES as f32;
+    for sample in samples {
+        let hour = ((sample.time - start) / HistorySample::DURATION) as usize;
+        if hour >= HISTORY_SAMPLES {
+            continue;
+        }
+        let height = area.height() * (sample.hashrate(now) / max) as f32;
+        let left = area.left() + bar_width * hour as f32;
+        let bar = Rect::from_min_max(
+            egui::pos2(left + 1.0, area.bottom() - height),
+            egui::pos2(left + bar_width - 1.0, area.bottom())
+        );
+        painter.rect_filled(bar, CornerRadius::same(2.0 as u8), Colors::gold());
+    }
+}
+
+/// Height of mining history list item.
+const HISTORY_ITEM_HEIGHT: f32 = 54.0;
+
+/// Draw hourly shares sample item with hashrate at provided time.
+fn sample_item_ui(ui: &mut egui::Ui, sample: &HistorySample, now: i64, rounding: CornerRadius) {
+    ui.vertical_centered_justified(|ui| {
+        // Draw round background.
+        let mut rect = ui.available_rect_before_wrap();
+        rect.set_height(HISTORY_ITEM_HEIGHT);
+        ui.painter().rect(rect,
+                          rounding,
+                          Colors::white_or_black(false),
+                          View::item_stroke(),
+                          StrokeKind::Middle);
+
+        ui.add_space(2.0);
+        ui.horizontal(|ui| {
+            ui.add_space(6.0);
+
+            // Draw hashrate.
+            let hashrate_text = format!("{} {:.*}", CHART_BAR, 2, sample.hashrate(now));
+            ui.heading(RichText::new(hashrate_text)
+                .color(Colors::title(false))
+                .size(16.0));
+            ui.add_space(6.0);
+
+            // Draw accepted shares.
+            let accepted_text = format!("{} {}", FOLDER_SIMPLE_PLUS, sample.accepted);
+            ui.heading(RichText::new(accepted_text)
+                .color(Colors::green())
+                .size(16.0));
+            ui.add_space(6.0);
+
+            // Draw rejected shares.
+            let rejected_text = format!("{} {}", FOLDER_SIMPLE_MINUS, sample.rejected);
+            ui.heading(RichText::new(rejected_text)
+                .color(Colors::red())
+                .size(16.0));
+            ui.add_space(6.0);
+
+            // Draw stale shares.
+            let stale_text = format!("{} {}", FOLDER_DASHED, sample.stale);
+            ui.heading(RichText::new(stale_text)
+                .color(Colors::gray())
+                .size(16.0));
+        });
+        ui.horizontal(|ui| {
+            ui.add_space(6.0);
+
+            // Draw sample hour.
+            let time_text = format!("{} {}", CLOCK_AFTERNOON, View::format_time(sample.time));
+            ui.heading(RichText::new(time_text)
+                .color(Colors::gray())
+                .size(16.0));
+        });
+        ui.add_space(4.0);
+    });
+}
+
+/// Height of found block list item.
+const FOUND_BLOCK_ITEM_HEIGHT: f32 = 78.0;
+
+/// Draw found block item.
+fn found_block_item_ui(ui: &mut egui::Ui, block: &FoundBlock, rounding: CornerRadius) {
+    ui.vertical_centered_justified(|ui| {
+        // Draw round background.
+        let mut rect = ui.available_rect_before_wrap();
+        rect.set_height(FOUND_BLOCK_ITEM_HEIGHT);
+        ui.painter().rect(rect,
+                          rounding,
+                          Colors::white_or_black(false),
+                          View::item_stroke(),
+                          StrokeKind::Middle);
+
+        ui.add_space(2.0);
+        ui.horizontal(|ui| {
+            ui.add_space(5.0);
+
+            // Draw block height and status.
+            let (status_icon, status_text, status_color) = match block.status {
+                FoundBlockStatus::Pending => (
+                    SEAL_QUESTION,
+                    t!("network_mining.pending"),
+                    Colors::gray()
+                ),
+                FoundBlockStatus::Confirmed => (
+                    SEAL_CHECK,
+                    t!("network_mining.confirmed"),
+                    Colors::green()
+                ),
+                FoundBlockStatus::Orphan => (
+                    SEAL_WARNING,
+                    t!("network_mining.orphan"),
+                    Colors::red()
+                )
+            };
+            let height_text = format!("{} {} {}", status_icon, block.height, status_text);
+            ui.heading(RichText::new(height_text)
+                .color(status_color)
+                .size(17.0));
+        });
+        ui.horizontal(|ui| {
+            ui.add_space(6.0);
+
+            // Draw block reward.
+            let reward_text = format!("{} {}", COINS, amount_to_hr_string(block.reward, true));
+            ui.heading(RichText::new(reward_text)
+                .color(Colors::title(false))
+                .size(16.0));
+            ui.add_space(6.0);
+
+            // Draw worker.
+            let worker_text = format!("{} {}", USER, block.worker);
+            ui.heading(RichText::new(worker_text)
+                .color(Colors::title(false))
+                .size(16.0));
+        });
+        ui.horizontal(|ui| {
+            ui.add_space(6.0);
+
+            // Draw block hash and time.
+            let hash = block.hash.chars().take(12).collect::<String>();
+            let time_text = format!("{} {} {}",
+                                    hash,
+                                    CLOCK_AFTERNOON,
+                                    View::format_time(block.time));
+            ui.heading(RichText::new(time_text)
+                .color(Colors::gray())
+                .size(16.0));
+        });
+        ui.add_space(4.0);
+    });
+}
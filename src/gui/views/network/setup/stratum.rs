@@ -12,13 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use egui::{Id, RichText};
+use egui::{Align, Id, Layout, RichText, StrokeKind};
 use grin_chain::SyncStatus;
 
 use crate::gui::Colors;
-use crate::gui::icons::{BARBELL, HARD_DRIVES, PLUG, POWER, TIMER};
+use crate::gui::icons::{BARBELL, CERTIFICATE, GAUGE, HARD_DRIVES, KEY, LOCK_KEY, PLUG, PLUGS, POWER, TIMER, TRASH, USER, USER_PLUS};
 use crate::gui::platform::PlatformCallbacks;
-use crate::gui::views::{Modal, TextEdit, View};
+use crate::gui::views::{FilePickContent, FilePickContentType, Modal, TextEdit, View};
 use crate::gui::views::network::settings::NetworkSettings;
 use crate::gui::views::types::{ContentContainer, ModalPosition};
 use crate::gui::views::wallets::modals::WalletsModal;
@@ -43,6 +43,19 @@ pub struct StratumSetup {
     /// Flag to check if stratum port from saved config value is available.
     is_port_available: bool,
 
+    /// Stratum TLS port value.
+    tls_port_edit: String,
+    /// Flag to check if stratum TLS port is available.
+    tls_port_available_edit: bool,
+    /// Flag to check if stratum TLS port from saved config value is available.
+    is_tls_port_available: bool,
+    /// Error of TLS certificate and key from saved config.
+    tls_error: Option<String>,
+    /// Button to pick TLS certificate file.
+    tls_cert_pick: FilePickContent,
+    /// Button to pick TLS private key file.
+    tls_key_pick: FilePickContent,
+
     /// Wallet name to receive rewards.
     pub wallet_name: Option<String>,
 
@@ -51,21 +64,44 @@ pub struct StratumSetup {
 
     /// Minimum share difficulty value to request from miners.
     min_share_diff_edit: String,
+
+    /// Target shares per minute value to adjust difficulty of each miner.
+    shares_per_minute_edit: String,
+
+    /// Worker login value.
+    worker_login_edit: String,
+    /// Worker password value.
+    worker_pass_edit: String,
+    /// Flag to check if entered worker credentials are empty.
+    worker_empty_edit: bool,
+
+    /// Maximum amount of connections from single IP address value.
+    max_ip_connections_edit: String,
 }
 
 /// Identifier for wallet selection [`Modal`].
 const WALLET_SELECTION_MODAL: &'static str = "stratum_wallet_selection_modal";
 /// Identifier for stratum port [`Modal`].
 const STRATUM_PORT_MODAL: &'static str = "stratum_port";
+/// Identifier for stratum TLS port [`Modal`].
+const TLS_PORT_MODAL: &'static str = "stratum_tls_port";
 /// Identifier for attempt time [`Modal`].
 const ATTEMPT_TIME_MODAL: &'static str = "stratum_attempt_time";
 /// Identifier for minimum share difficulty [`Modal`].
 const MIN_SHARE_DIFF_MODAL: &'static str = "stratum_min_share_diff";
+/// Identifier for target shares per minute [`Modal`].
+const SHARES_PER_MINUTE_MODAL: &'static str = "stratum_shares_per_minute";
+/// Identifier for worker credentials [`Modal`].
+const WORKER_MODAL: &'static str = "stratum_worker";
+/// Identifier for maximum connections from single IP address [`Modal`].
+const MAX_IP_CONNECTIONS_MODAL: &'static str = "stratum_max_ip_connections";
 
 impl Default for StratumSetup {
     fn default() -> Self {
         let (ip, port) = NodeConfig::get_stratum_address();
         let is_port_available = NodeConfig::is_stratum_port_available(&ip, &port);
+        let tls_port = NodeConfig::get_stratum_tls_port();
+        let is_tls_port_available = NodeConfig::is_stratum_tls_port_available(&tls_port);
 
         // Setup mining rewards wallet name and identifier.
         let mut wallet_id = NodeConfig::get_stratum_wallet_id();
@@ -85,9 +121,20 @@ impl Default for StratumSetup {
             stratum_port_edit: port,
             stratum_port_available_edit: is_port_available,
             is_port_available,
+            tls_port_edit: tls_port.clone(),
+            tls_port_available_edit: is_tls_port_available,
+            is_tls_port_available,
+            tls_error: NodeConfig::stratum_config().tls_acceptor().err(),
+            tls_cert_pick: FilePickContent::new(FilePickContentType::Button).no_parse(),
+            tls_key_pick: FilePickContent::new(FilePickContentType::Button).no_parse(),
             wallet_name,
             attempt_time_edit: NodeConfig::get_stratum_attempt_time(),
             min_share_diff_edit: NodeConfig::get_stratum_min_share_diff(),
+            shares_per_minute_edit: NodeConfig::get_stratum_shares_per_minute(),
+            worker_login_edit: "".to_string(),
+            worker_pass_edit: "".to_string(),
+            worker_empty_edit: false,
+            max_ip_connections_edit: NodeConfig::get_stratum_max_ip_connections(),
         }
     }
 }
@@ -97,8 +144,12 @@ impl ContentContainer for StratumSetup {
         vec![
             WALLET_SELECTION_MODAL,
             STRATUM_PORT_MODAL,
+            TLS_PORT_MODAL,
             ATTEMPT_TIME_MODAL,
-            MIN_SHARE_DIFF_MODAL
+            MIN_SHARE_DIFF_MODAL,
+            SHARES_PER_MINUTE_MODAL,
+            WORKER_MODAL,
+            MAX_IP_CONNECTIONS_MODAL
         ]
     }
 
@@ -115,13 +166,17 @@ impl ContentContainer for StratumSetup {
                 })
             },
             STRATUM_PORT_MODAL => self.port_modal(ui, modal, cb),
+            TLS_PORT_MODAL => self.tls_port_modal(ui, modal, cb),
             ATTEMPT_TIME_MODAL => self.attempt_modal(ui, modal, cb),
             MIN_SHARE_DIFF_MODAL => self.min_diff_modal(ui, modal, cb),
+            SHARES_PER_MINUTE_MODAL => self.shares_modal(ui, modal, cb),
+            WORKER_MODAL => self.worker_modal(ui, modal, cb),
+            MAX_IP_CONNECTIONS_MODAL => self.max_ip_connections_modal(ui, modal, cb),
             _ => {}
         }
     }
 
-    fn container_ui(&mut self, ui: &mut egui::Ui, _: &dyn PlatformCallbacks) {
+    fn container_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
         View::sub_title(ui, format!("{} {}", HARD_DRIVES, t!("network_mining.server")));
         View::horizontal_line(ui, Colors::stroke());
         ui.add_space(6.0);
@@ -145,6 +200,13 @@ impl ContentContainer for StratumSetup {
                         self.is_port_available = NodeConfig::is_stratum_port_available(&ip, &port);
                     });
                     ui.add_space(6.0);
+                } else if NodeConfig::is_stratum_tls_enabled() && self.tls_error.is_some() {
+                    // Server can not be started without valid TLS certificate and key.
+                    ui.add_space(2.0);
+                    ui.label(RichText::new(t!("network_settings.tls_invalid"))
+                        .size(16.0)
+                        .color(Colors::red()));
+                    ui.add_space(6.0);
                 } else {
                     ui.add_space(6.0);
                     let enable_text = format!("{} {}", POWER, t!("network_settings.enable"));
@@ -223,6 +285,12 @@ impl ContentContainer for StratumSetup {
             View::horizontal_line(ui, Colors::item_stroke());
             ui.add_space(6.0);
 
+            // Show TLS listener setup.
+            self.tls_setup_ui(ui, cb);
+
+            View::horizontal_line(ui, Colors::item_stroke());
+            ui.add_space(6.0);
+
             // Show attempt time setup.
             self.attempt_time_ui(ui);
 
@@ -231,6 +299,24 @@ impl ContentContainer for StratumSetup {
 
             // Show minimum acceptable share difficulty setup.
             self.min_diff_ui(ui);
+
+            View::horizontal_line(ui, Colors::item_stroke());
+            ui.add_space(6.0);
+
+            // Show target shares per minute setup.
+            self.shares_ui(ui);
+
+            View::horizontal_line(ui, Colors::item_stroke());
+            ui.add_space(6.0);
+
+            // Show workers credentials setup.
+            self.workers_ui(ui);
+
+            View::horizontal_line(ui, Colors::item_stroke());
+            ui.add_space(6.0);
+
+            // Show maximum connections from single IP address setup.
+            self.max_ip_connections_ui(ui);
         });
     }
 }
@@ -346,6 +432,156 @@ impl StratumSetup {
         });
     }
 
+    /// Draw TLS listener setup content.
+    fn tls_setup_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        // Show checkbox to enable TLS listener.
+        let tls_enabled = NodeConfig::is_stratum_tls_enabled();
+        View::checkbox(ui, tls_enabled, t!("network_settings.stratum_tls"), || {
+            NodeConfig::toggle_stratum_tls();
+        });
+        ui.add_space(6.0);
+        ui.label(RichText::new(t!("network_settings.stratum_tls_desc"))
+            .size(16.0)
+            .color(Colors::inactive_text())
+        );
+        ui.add_space(12.0);
+        if !tls_enabled {
+            return;
+        }
+
+        // Show TLS port setup.
+        ui.label(RichText::new(t!("network_settings.stratum_tls_port"))
+            .size(16.0)
+            .color(Colors::gray())
+        );
+        ui.add_space(6.0);
+        let port = NodeConfig::get_stratum_tls_port();
+        View::button(ui, format!("{} {}", LOCK_KEY, &port), Colors::white_or_black(false), || {
+            // Setup values for modal.
+            self.tls_port_edit = port;
+            self.tls_port_available_edit = self.is_tls_port_available;
+            // Show TLS port modal.
+            Modal::new(TLS_PORT_MODAL)
+                .position(ModalPosition::CenterTop)
+                .title(t!("network_settings.change_value"))
+                .show();
+        });
+        ui.add_space(12.0);
+        if !self.is_tls_port_available {
+            ui.add_space(6.0);
+            ui.label(RichText::new(t!("network_settings.port_unavailable"))
+                .size(16.0)
+                .color(Colors::red()));
+            ui.add_space(12.0);
+        }
+
+        // Show certificate file setup.
+        let mut cert_path = None;
+        tls_file_ui(ui,
+                    format!("{} {}", CERTIFICATE, t!("network_settings.tls_cert")),
+                    NodeConfig::get_stratum_tls_cert_path(),
+                    |ui| {
+                        self.tls_cert_pick.ui(ui, cb, |path| cert_path = Some(path));
+                    });
+        if let Some(path) = cert_path {
+            NodeConfig::save_stratum_tls_cert_path(path);
+            self.tls_error = NodeConfig::stratum_config().tls_acceptor().err();
+        }
+
+        // Show private key file setup.
+        let mut key_path = None;
+        tls_file_ui(ui,
+                    format!("{} {}", KEY, t!("network_settings.tls_key")),
+                    NodeConfig::get_stratum_tls_key_path(),
+                    |ui| {
+                        self.tls_key_pick.ui(ui, cb, |path| key_path = Some(path));
+                    });
+        if let Some(path) = key_path {
+            NodeConfig::save_stratum_tls_key_path(path);
+            self.tls_error = NodeConfig::stratum_config().tls_acceptor().err();
+        }
+
+        // Show error when certificate or key is not valid.
+        if let Some(err) = &self.tls_error {
+            let text = format!("{}: {}", t!("network_settings.tls_invalid"), err);
+            ui.label(RichText::new(text).size(16.0).color(Colors::red()));
+            ui.add_space(12.0);
+        }
+
+        // Show error when TLS listener was not started.
+        if let Some(err) = Node::get_stratum_tls_error() {
+            let text = format!("{}: {}", t!("network_settings.tls_bind_error"), err);
+            ui.label(RichText::new(text).size(16.0).color(Colors::red()));
+            ui.add_space(12.0);
+        }
+    }
+
+    /// Draw stratum TLS port [`Modal`] content.
+    fn tls_port_modal(&mut self, ui: &mut egui::Ui, modal: &Modal, cb: &dyn PlatformCallbacks) {
+        let on_save = |c: &mut StratumSetup| {
+            // Check if port is available.
+            let available = NodeConfig::is_stratum_tls_port_available(&c.tls_port_edit);
+            c.tls_port_available_edit = available;
+
+            // Save port at config if it's available.
+            if available {
+                if let Ok(port) = c.tls_port_edit.parse::<u16>() {
+                    NodeConfig::save_stratum_tls_port(port);
+                    c.is_tls_port_available = true;
+                    Modal::close();
+                }
+            }
+        };
+
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("network_settings.stratum_tls_port"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+
+            // Draw TLS port text edit.
+            let mut edit = TextEdit::new(Id::from(modal.id)).h_center().numeric();
+            edit.ui(ui, &mut self.tls_port_edit, cb);
+            if edit.enter_pressed {
+                on_save(self);
+            }
+
+            // Show error when specified port is unavailable.
+            if !self.tls_port_available_edit {
+                ui.add_space(12.0);
+                ui.label(RichText::new(t!("network_settings.port_unavailable"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            } else {
+                server_restart_required_ui(ui);
+            }
+
+            ui.add_space(12.0);
+
+            // Show modal buttons.
+            ui.scope(|ui| {
+                // Setup spacing between buttons.
+                ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+                ui.columns(2, |columns| {
+                    columns[0].vertical_centered_justified(|ui| {
+                        View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                            // Close modal.
+                            Modal::close();
+                        });
+                    });
+                    columns[1].vertical_centered_justified(|ui| {
+                        View::button(ui, t!("modal.save"), Colors::white_or_black(false), || {
+                            on_save(self);
+                        });
+                    });
+                });
+                ui.add_space(6.0);
+            });
+        });
+    }
+
     /// Draw attempt time value setup content.
     fn attempt_time_ui(&mut self, ui: &mut egui::Ui) {
         ui.label(RichText::new(t!("network_settings.attempt_time"))
@@ -449,7 +685,7 @@ impl StratumSetup {
                 .title(t!("network_settings.change_value"))
                 .show();
         });
-        ui.add_space(6.0);
+        ui.add_space(12.0);
     }
 
     /// Draw minimum acceptable share difficulty [`Modal`] content.
@@ -508,6 +744,348 @@ impl StratumSetup {
             ui.add_space(6.0);
         });
     }
+
+    /// Draw target shares per minute value setup content.
+    fn shares_ui(&mut self, ui: &mut egui::Ui) {
+        ui.label(RichText::new(t!("network_settings.shares_per_minute"))
+            .size(16.0)
+            .color(Colors::gray())
+        );
+        ui.add_space(6.0);
+
+        let shares = NodeConfig::get_stratum_shares_per_minute();
+        View::button(ui, format!("{} {}", GAUGE, &shares), Colors::white_or_black(false), || {
+            // Setup values for modal.
+            self.shares_per_minute_edit = shares;
+
+            // Show target shares setup modal.
+            Modal::new(SHARES_PER_MINUTE_MODAL)
+                .position(ModalPosition::CenterTop)
+                .title(t!("network_settings.change_value"))
+                .show();
+        });
+        ui.add_space(6.0);
+        ui.label(RichText::new(t!("network_settings.shares_per_minute_desc"))
+            .size(16.0)
+            .color(Colors::inactive_text())
+        );
+        ui.add_space(6.0);
+    }
+
+    /// Draw target shares per minute [`Modal`] content.
+    fn shares_modal(&mut self, ui: &mut egui::Ui, modal: &Modal, cb: &dyn PlatformCallbacks) {
+        let on_save = |c: &mut StratumSetup| {
+            if let Ok(shares) = c.shares_per_minute_edit.parse::<u32>() {
+                NodeConfig::save_stratum_shares_per_minute(shares);
+                Modal::close();
+            }
+        };
+
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("network_settings.shares_per_minute"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+
+            // Draw target shares text edit.
+            let mut edit = TextEdit::new(Id::from(modal.id)).h_center().numeric();
+            edit.ui(ui, &mut self.shares_per_minute_edit, cb);
+            if edit.enter_pressed {
+                on_save(self);
+            }
+
+            // Show error when specified value is not valid or reminder to restart enabled node.
+            if self.shares_per_minute_edit.parse::<u32>().is_err() {
+                ui.add_space(12.0);
+                ui.label(RichText::new(t!("network_settings.not_valid_value"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            } else {
+                server_restart_required_ui(ui);
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        // Close modal.
+                        Modal::close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.save"), Colors::white_or_black(false), || {
+                        on_save(self);
+                    });
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+
+    /// Draw workers credentials setup content.
+    fn workers_ui(&mut self, ui: &mut egui::Ui) {
+        ui.label(RichText::new(t!("network_settings.stratum_workers"))
+            .size(16.0)
+            .color(Colors::gray())
+        );
+        ui.add_space(6.0);
+
+        // Show list of workers allowed to login.
+        let workers = NodeConfig::get_stratum_workers();
+        for (index, login) in workers.iter().enumerate() {
+            ui.horizontal_wrapped(|ui| {
+                worker_item_ui(ui, login, index, workers.len());
+            });
+        }
+        if !workers.is_empty() {
+            ui.add_space(12.0);
+        }
+        ui.label(RichText::new(t!("network_settings.stratum_workers_desc"))
+            .size(16.0)
+            .color(Colors::inactive_text())
+        );
+        ui.add_space(12.0);
+
+        // Show button to add worker.
+        let add_text = format!("{} {}", USER_PLUS, t!("network_settings.add_worker"));
+        View::button(ui, add_text, Colors::white_or_black(false), || {
+            // Setup values for modal.
+            self.worker_login_edit = "".to_string();
+            self.worker_pass_edit = "".to_string();
+            self.worker_empty_edit = false;
+            // Show worker credentials modal.
+            Modal::new(WORKER_MODAL)
+                .position(ModalPosition::CenterTop)
+                .title(t!("network_settings.add_worker"))
+                .show();
+        });
+        ui.add_space(12.0);
+    }
+
+    /// Draw worker credentials [`Modal`] content.
+    fn worker_modal(&mut self, ui: &mut egui::Ui, modal: &Modal, cb: &dyn PlatformCallbacks) {
+        let on_save = |c: &mut StratumSetup| {
+            let login = c.worker_login_edit.trim().to_string();
+            c.worker_empty_edit = login.is_empty() || c.worker_pass_edit.is_empty();
+            if !c.worker_empty_edit {
+                NodeConfig::save_stratum_worker(login, c.worker_pass_edit.as_str());
+                Modal::close();
+            }
+        };
+
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("network_settings.worker_login"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+
+            // Draw login text edit.
+            let mut login_edit = TextEdit::new(Id::from(modal.id).with("login")).focus(true);
+            login_edit.ui(ui, &mut self.worker_login_edit, cb);
+            ui.add_space(8.0);
+
+            ui.label(RichText::new(t!("network_settings.worker_pass"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+
+            // Draw password text edit.
+            let mut pass_edit = TextEdit::new(Id::from(modal.id).with("pass"))
+                .password()
+                .focus(false);
+            if login_edit.enter_pressed {
+                pass_edit.focus_request();
+            }
+            pass_edit.ui(ui, &mut self.worker_pass_edit, cb);
+            if pass_edit.enter_pressed {
+                on_save(self);
+            }
+
+            // Show error when credentials are empty or reminder to restart enabled node.
+            if self.worker_empty_edit {
+                ui.add_space(12.0);
+                ui.label(RichText::new(t!("network_settings.not_valid_value"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            } else {
+                server_restart_required_ui(ui);
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        // Close modal.
+                        Modal::close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.save"), Colors::white_or_black(false), || {
+                        on_save(self);
+                    });
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+
+    /// Draw maximum connections from single IP address setup content.
+    fn max_ip_connections_ui(&mut self, ui: &mut egui::Ui) {
+        ui.label(RichText::new(t!("network_settings.max_ip_connections"))
+            .size(16.0)
+            .color(Colors::gray())
+        );
+        ui.add_space(6.0);
+
+        let max = NodeConfig::get_stratum_max_ip_connections();
+        View::button(ui, format!("{} {}", PLUGS, &max), Colors::white_or_black(false), || {
+            // Setup values for modal.
+            self.max_ip_connections_edit = max;
+
+            // Show maximum connections setup modal.
+            Modal::new(MAX_IP_CONNECTIONS_MODAL)
+                .position(ModalPosition::CenterTop)
+                .title(t!("network_settings.change_value"))
+                .show();
+        });
+        ui.add_space(6.0);
+        ui.label(RichText::new(t!("network_settings.max_ip_connections_desc"))
+            .size(16.0)
+            .color(Colors::inactive_text())
+        );
+        ui.add_space(6.0);
+    }
+
+    /// Draw maximum connections from single IP address [`Modal`] content.
+    fn max_ip_connections_modal(&mut self,
+                                ui: &mut egui::Ui,
+                                modal: &Modal,
+                                cb: &dyn PlatformCallbacks) {
+        let on_save = |c: &mut StratumSetup| {
+            if let Ok(max) = c.max_ip_connections_edit.parse::<u32>() {
+                NodeConfig::save_stratum_max_ip_connections(max);
+                Modal::close();
+            }
+        };
+
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("network_settings.max_ip_connections"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+
+            // Draw maximum connections text edit.
+            let mut edit = TextEdit::new(Id::from(modal.id)).h_center().numeric();
+            edit.ui(ui, &mut self.max_ip_connections_edit, cb);
+            if edit.enter_pressed {
+                on_save(self);
+            }
+
+            // Show error when specified value is not valid or reminder to restart enabled node.
+            if self.max_ip_connections_edit.parse::<u32>().is_err() {
+                ui.add_space(12.0);
+                ui.label(RichText::new(t!("network_settings.not_valid_value"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            } else {
+                server_restart_required_ui(ui);
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        // Close modal.
+                        Modal::close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.save"), Colors::white_or_black(false), || {
+                        on_save(self);
+                    });
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+}
+
+/// Draw worker list item.
+fn worker_item_ui(ui: &mut egui::Ui, login: &String, index: usize, len: usize) {
+    // Setup layout size.
+    let mut rect = ui.available_rect_before_wrap();
+    rect.set_height(42.0);
+
+    // Draw round background.
+    let item_rounding = View::item_rounding(index, len, false);
+    ui.painter().rect(rect,
+                      item_rounding,
+                      Colors::white_or_black(false),
+                      View::item_stroke(),
+                      StrokeKind::Middle);
+
+    ui.vertical(|ui| {
+        ui.allocate_ui_with_layout(rect.size(), Layout::right_to_left(Align::Center), |ui| {
+            // Draw delete button.
+            View::item_button(ui, View::item_rounding(index, len, true), TRASH, None, || {
+                NodeConfig::remove_stratum_worker(login);
+            });
+
+            let layout_size = ui.available_size();
+            ui.allocate_ui_with_layout(layout_size, Layout::left_to_right(Align::Center), |ui| {
+                ui.add_space(6.0);
+                // Draw worker login.
+                let login_text = format!("{} {}", USER, login);
+                ui.label(RichText::new(login_text)
+                    .color(Colors::text_button())
+                    .size(16.0));
+            });
+        });
+    });
+}
+
+/// Draw TLS file path with provided title and button to pick the file.
+fn tls_file_ui(ui: &mut egui::Ui,
+               title: String,
+               path: String,
+               pick_ui: impl FnOnce(&mut egui::Ui)) {
+    ui.label(RichText::new(title)
+        .size(16.0)
+        .color(Colors::gray())
+    );
+    ui.add_space(6.0);
+    let path = if path.is_empty() {
+        "-".to_string()
+    } else {
+        path
+    };
+    ui.label(RichText::new(path)
+        .size(16.0)
+        .color(Colors::white_or_black(true)));
+    ui.add_space(8.0);
+    pick_ui(ui);
+    ui.add_space(12.0);
 }
 
 /// Reminder to restart enabled node to show on edit setting at [`Modal`].
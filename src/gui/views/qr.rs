@@ -21,6 +21,7 @@ use qrcodegen::QrCode;
 use std::mem::size_of;
 use std::sync::Arc;
 use std::thread;
+use zeroize::Zeroizing;
 
 use crate::gui::icons::{COPY, IMAGES_SQUARE};
 use crate::gui::platform::PlatformCallbacks;
@@ -32,6 +33,10 @@ use crate::gui::Colors;
 pub struct QrCodeContent {
     /// QR code text.
     text: String,
+    /// Binary data to encode instead of text.
+    binary: Option<Zeroizing<Vec<u8>>>,
+    /// Flag to not show text and buttons for secret data.
+    secret: bool,
 
     /// Maximum QR code size.
     max_size: f32,
@@ -56,6 +61,8 @@ impl QrCodeContent {
     pub fn new(text: String, animated: bool) -> Self {
         Self {
             text,
+            binary: None,
+            secret: false,
             max_size: DEFAULT_QR_SIZE as f32,
             animated,
             animated_index: None,
@@ -65,6 +72,18 @@ impl QrCodeContent {
         }
     }
 
+    /// Encode provided binary data instead of text, data is cleared from memory on drop.
+    pub fn with_binary(mut self, data: Zeroizing<Vec<u8>>) -> Self {
+        self.binary = Some(data);
+        self
+    }
+
+    /// Do not show text and buttons to copy or share secret data.
+    pub fn secret(mut self) -> Self {
+        self.secret = true;
+        self
+    }
+
     /// Setup maximum QR code size.
     pub fn with_max_size(mut self, max_size: f32) -> Self {
         self.max_size = max_size;
@@ -121,6 +140,9 @@ impl QrCodeContent {
 
             // Create images from SVG data.
             self.qr_image_ui(svg, ui);
+            if self.secret {
+                return;
+            }
 
             // Show QR code text.
             self.text_ui(ui);
@@ -230,8 +252,7 @@ impl QrCodeContent {
 
     /// Share static QR code image.
     fn share_static(&self, cb: &dyn PlatformCallbacks) {
-        let text = self.text.as_str();
-        if let Ok(qr) = QrCode::encode_text(text, qrcodegen::QrCodeEcc::Low) {
+        if let Some(qr) = Self::encode(&self.text, self.binary.as_ref().map(|b| b.as_slice())) {
             let size = DEFAULT_QR_SIZE as usize;
             if let Some(data) = Self::qr_to_image_data(qr, size) {
                 let mut png = vec![];
@@ -331,8 +352,9 @@ impl QrCodeContent {
     fn create_svg(&self) {
         let qr_state = self.qr_image_state.clone();
         let text = self.text.clone();
+        let binary = self.binary.clone();
         thread::spawn(move || {
-            if let Ok(qr) = QrCode::encode_text(text.as_str(), qrcodegen::QrCodeEcc::Low) {
+            if let Some(qr) = Self::encode(&text, binary.as_ref().map(|b| b.as_slice())) {
                 let svg = Self::qr_to_svg(qr, 0);
                 let mut w_state = qr_state.write();
                 w_state.loading = false;
@@ -341,6 +363,14 @@ impl QrCodeContent {
         });
     }
 
+    /// Encode static QR code from text or binary data.
+    fn encode(text: &str, binary: Option<&[u8]>) -> Option<QrCode> {
+        match binary {
+            Some(data) => QrCode::encode_binary(data, qrcodegen::QrCodeEcc::Low).ok(),
+            None => QrCode::encode_text(text, qrcodegen::QrCodeEcc::Low).ok()
+        }
+    }
+
     /// Convert QR code to SVG string.
     fn qr_to_svg(qr: QrCode, border: i32) -> String {
         let mut result = String::new();
@@ -15,6 +15,7 @@
 use grin_util::ZeroingString;
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::Modal;
+use crate::wallet::PaymentUri;
 
 /// Title type, can be single or dual title in the row.
 pub enum TitleType {
@@ -80,6 +81,8 @@ pub enum QrScanResult {
     Slatepack(String),
     /// Slatepack address.
     Address(ZeroingString),
+    /// Payment request URI with address, optional amount and memo.
+    PaymentUri(PaymentUri),
     /// Parsed text.
     Text(ZeroingString),
     /// Recovery phrase in standard or compact SeedQR format.
@@ -96,6 +99,7 @@ impl QrScanResult {
         match self {
             QrScanResult::Slatepack(text) => text.to_string(),
             QrScanResult::Address(text) => text.to_string(),
+            QrScanResult::PaymentUri(uri) => uri.to_string(),
             QrScanResult::Text(text) => text.to_string(),
             QrScanResult::SeedQR(text) => text.to_string(),
             QrScanResult::URPart(uri, _, _) => uri.to_string(),
@@ -16,7 +16,7 @@ use std::time::Duration;
 use egui::scroll_area::ScrollBarVisibility;
 use egui::{Align, CornerRadius, Id, Layout, Margin, RichText, ScrollArea, StrokeKind};
 use egui::os::OperatingSystem;
-use crate::gui::icons::{ARROW_LEFT, CARET_RIGHT, COMPUTER_TOWER, FOLDER_OPEN, FOLDER_PLUS, GEAR, GLOBE, GLOBE_SIMPLE, LOCK_KEY, PLUS, SIDEBAR_SIMPLE, SUITCASE};
+use crate::gui::icons::{ARROW_LEFT, CARET_RIGHT, COMPUTER_TOWER, EYE, FOLDER_OPEN, FOLDER_PLUS, GEAR, GLOBE, GLOBE_SIMPLE, LOCK_KEY, PLUS, SIDEBAR_SIMPLE, SUITCASE};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::settings::SettingsContent;
 use crate::gui::views::types::{ContentContainer, LinePosition, ModalPosition, TitleContentType, TitleType};
@@ -27,7 +27,7 @@ use crate::gui::views::wallets::WalletContent;
 use crate::gui::views::{Content, Modal, TitlePanel, View};
 use crate::gui::Colors;
 use crate::wallet::types::{ConnectionMethod, WalletTask};
-use crate::wallet::{Wallet, WalletList};
+use crate::wallet::{PaymentUri, Wallet, WalletList};
 use crate::AppConfig;
 
 /// Wallets content.
@@ -584,9 +584,14 @@ impl WalletsContent {
                     } else {
                         Colors::title(false)
                     };
+                    let name = if config.is_watch_only() {
+                        format!("{} {}", EYE, config.name)
+                    } else {
+                        config.name
+                    };
                     ui.with_layout(Layout::left_to_right(Align::Min), |ui| {
                             ui.add_space(1.0);
-                            View::ellipsize_text(ui, config.name, 18.0, name_color);
+                            View::ellipsize_text(ui, name, 18.0, name_color);
                     });
 
                     // Show wallet status text.
@@ -622,7 +627,11 @@ impl WalletsContent {
     fn select_wallet(&mut self, wallet: &Wallet, data: Option<String>, cb: &dyn PlatformCallbacks) {
         self.wallet_content.account_content.close_qr_scan(cb);
         if let Some(data) = data {
-            wallet.task(WalletTask::OpenMessage(data));
+            if let Some(uri) = PaymentUri::parse(data.as_str()) {
+                self.wallet_content.send_to_uri(uri);
+            } else {
+                let _ = wallet.task(WalletTask::OpenMessage(data));
+            }
         }
         self.wallets.select(Some(wallet.get_config().id));
     }
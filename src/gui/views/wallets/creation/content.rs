@@ -17,15 +17,15 @@ use egui::scroll_area::ScrollBarVisibility;
 use grin_util::ZeroingString;
 
 use crate::gui::Colors;
-use crate::gui::icons::{CHECK, CLIPBOARD_TEXT, COPY, SCAN};
+use crate::gui::icons::{CHECK, CLIPBOARD_TEXT, COPY, EYE, SCAN};
 use crate::gui::platform::PlatformCallbacks;
-use crate::gui::views::{Modal, Content, View, CameraScanContent};
+use crate::gui::views::{Modal, Content, View, CameraScanContent, TextEdit, FilePickContent, FilePickContentType};
 use crate::gui::views::types::{LinePosition, ContentContainer, ModalPosition, QrScanResult};
 use crate::gui::views::wallets::creation::MnemonicSetup;
 use crate::gui::views::wallets::creation::types::Step;
 use crate::gui::views::wallets::ConnectionSettings;
 use crate::node::Node;
-use crate::wallet::{ExternalConnection, Wallet};
+use crate::wallet::{ExternalConnection, Wallet, WatchOnlyData};
 use crate::wallet::types::PhraseMode;
 
 /// Wallet creation content.
@@ -43,6 +43,17 @@ pub struct WalletCreationContent {
 
     /// Mnemonic phrase setup content.
     mnemonic_setup: MnemonicSetup,
+    /// Flag to create watch-only wallet from view key.
+    watch_only: bool,
+    /// View key value for watch-only wallet.
+    rewind_hash_edit: String,
+
+    /// Button to pick wallet backup file.
+    backup_pick: FilePickContent,
+    /// Picked wallet backup file content.
+    backup_data: Option<String>,
+    /// Flag to check if an error occurred during wallet restoring from backup.
+    backup_error: bool,
     /// Network setup content.
     network_setup: ConnectionSettings,
 
@@ -69,7 +80,11 @@ impl ContentContainer for WalletCreationContent {
                     content.modal_ui(ui, cb, |result| {
                         match result {
                             QrScanResult::Text(text) => {
-                                self.mnemonic_setup.mnemonic.import(&text);
+                                if self.watch_only {
+                                    self.rewind_hash_edit = text.trim().to_string();
+                                } else {
+                                    self.mnemonic_setup.mnemonic.import(&text);
+                                }
                                 Modal::close();
                             }
                             QrScanResult::SeedQR(text) => {
@@ -99,6 +114,11 @@ impl WalletCreationContent {
             step: Step::EnterMnemonic,
             scan_modal_content: None,
             mnemonic_setup: MnemonicSetup::default(),
+            watch_only: false,
+            rewind_hash_edit: "".to_string(),
+            backup_pick: FilePickContent::new(FilePickContentType::Button),
+            backup_data: None,
+            backup_error: false,
             network_setup: ConnectionSettings::default(),
             creation_error: None,
         }
@@ -108,7 +128,18 @@ impl WalletCreationContent {
     pub fn content_ui(&mut self,
                       ui: &mut egui::Ui,
                       cb: &dyn PlatformCallbacks,
-                      on_create: impl FnMut(Wallet)) {
+                      mut on_create: impl FnMut(Wallet)) {
+        // Restore wallet from picked backup.
+        if let Some(data) = self.backup_data.take() {
+            match Wallet::restore_backup(&self.name, &self.pass, data.as_str()) {
+                Ok(w) => {
+                    on_create(w);
+                    return;
+                }
+                Err(_) => self.backup_error = true
+            }
+        }
+
         self.ui(ui, cb);
         egui::TopBottomPanel::bottom("wallet_creation_step_panel")
             .frame(egui::Frame {
@@ -175,6 +206,10 @@ impl WalletCreationContent {
         let step = &self.step;
         // Setup description and next step availability.
         let (step_text, mut next) = match step {
+            Step::EnterMnemonic if self.watch_only => {
+                let available = WatchOnlyData::is_valid_rewind_hash(self.rewind_hash_edit.trim());
+                (t!("wallets.watch_only_desc"), available)
+            }
             Step::EnterMnemonic => {
                 let mode = &self.mnemonic_setup.mnemonic.mode();
                 let (text, available) = match mode {
@@ -186,6 +221,9 @@ impl WalletCreationContent {
                 };
                 (text, available)
             }
+            Step::ConfirmMnemonic if self.mnemonic_setup.mnemonic.split().is_some() => {
+                (t!("wallets.shares_confirm_desc"), self.mnemonic_setup.shares_verified())
+            }
             Step::ConfirmMnemonic => {
                 let text = t!("wallets.restore_phrase_desc");
                 let available = !self.mnemonic_setup.mnemonic.has_empty_or_invalid();
@@ -197,10 +235,10 @@ impl WalletCreationContent {
         };
 
         // Show step description or error.
-        let generate_step = step == &Step::EnterMnemonic &&
-            self.mnemonic_setup.mnemonic.mode() == PhraseMode::Generate;
-        if (self.mnemonic_setup.mnemonic.valid() && self.creation_error.is_none()) ||
-            generate_step {
+        let generate_step = step == &Step::EnterMnemonic && (self.watch_only ||
+            self.mnemonic_setup.mnemonic.mode() == PhraseMode::Generate);
+        let valid = self.watch_only || self.mnemonic_setup.mnemonic.valid();
+        if (valid && self.creation_error.is_none()) || generate_step {
             ui.label(RichText::new(step_text).size(16.0).color(Colors::gray()));
             ui.add_space(6.0);
         } else {
@@ -225,11 +263,27 @@ impl WalletCreationContent {
         // Setup vertical padding inside button.
         ui.style_mut().spacing.button_padding = egui::vec2(10.0, 7.0);
 
+        let split = self.mnemonic_setup.mnemonic.split().is_some();
         match step {
+            Step::EnterMnemonic if split && !self.watch_only => {
+                // Do not allow to copy the phrase which will be split into shares.
+                self.next_step_button_ui(ui, on_create);
+            }
             Step::EnterMnemonic => {
                 ui.columns(2, |columns| {
                     // Show copy or paste button for mnemonic phrase step.
                     columns[0].vertical_centered_justified(|ui| {
+                        if self.watch_only {
+                            let p_t = format!("{} {}",
+                                              CLIPBOARD_TEXT,
+                                              t!("paste").to_uppercase());
+                            View::button(ui, p_t, Colors::white_or_black(false), || {
+                                self.rewind_hash_edit = cb.get_string_from_buffer()
+                                    .trim()
+                                    .to_string();
+                            });
+                            return;
+                        }
                         match self.mnemonic_setup.mnemonic.mode() {
                             PhraseMode::Generate => {
                                 let c_t = format!("{} {}",
@@ -278,7 +332,7 @@ impl WalletCreationContent {
                 // Show next step or paste button.
                 if next {
                     self.next_step_button_ui(ui, on_create);
-                } else {
+                } else if !split {
                     let paste_text = format!("{} {}", CLIPBOARD_TEXT, t!("paste").to_uppercase());
                     View::button(ui, paste_text, Colors::white_or_black(false), || {
                         let data = ZeroingString::from(cb.get_string_from_buffer());
@@ -310,7 +364,11 @@ impl WalletCreationContent {
         View::colored_text_button_ui(ui, next_text.to_uppercase(), text_color, bg_color, |ui| {
             self.step = match self.step {
                 Step::EnterMnemonic => {
-                    if self.mnemonic_setup.mnemonic.mode() == PhraseMode::Generate {
+                    if !self.watch_only &&
+                        self.mnemonic_setup.mnemonic.mode() == PhraseMode::Generate {
+                        if self.mnemonic_setup.mnemonic.split().is_some() {
+                            self.mnemonic_setup.setup_shares();
+                        }
                         Step::ConfirmMnemonic
                     } else {
                         Step::SetupConnection
@@ -321,12 +379,22 @@ impl WalletCreationContent {
                 },
                 Step::SetupConnection => {
                     // Create wallet at last step.
-                    match Wallet::create(&self.name,
-                                         &self.pass,
-                                         &self.mnemonic_setup.mnemonic,
-                                         &self.network_setup.method) {
+                    let res = if self.watch_only {
+                        Wallet::create_watch_only(&self.name,
+                                                  &self.pass,
+                                                  &self.rewind_hash_edit.trim().to_string(),
+                                                  &self.network_setup.method)
+                    } else {
+                        Wallet::create(&self.name,
+                                       &self.pass,
+                                       &self.mnemonic_setup.mnemonic,
+                                       &self.network_setup.method)
+                    };
+                    match res {
                         Ok(w) => {
                             self.mnemonic_setup.reset();
+                            self.watch_only = false;
+                            self.rewind_hash_edit = "".to_string();
                             // Pass created wallet to callback.
                             (on_create)(w);
                             Step::EnterMnemonic
@@ -349,7 +417,22 @@ impl WalletCreationContent {
     /// Draw wallet creation [`Step`] content.
     fn step_content_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
         match &self.step {
-            Step::EnterMnemonic => self.mnemonic_setup.enter_ui(ui, cb),
+            Step::EnterMnemonic => {
+                ui.add_space(6.0);
+                ui.vertical_centered(|ui| {
+                    let text = format!("{} {}", EYE, t!("wallets.watch_only"));
+                    View::checkbox(ui, self.watch_only, text, || {
+                        self.watch_only = !self.watch_only;
+                    });
+                });
+                if self.watch_only {
+                    self.view_key_ui(ui, cb);
+                } else {
+                    ui.add_space(4.0);
+                    self.mnemonic_setup.enter_ui(ui, cb);
+                }
+                self.backup_ui(ui, cb);
+            }
             Step::ConfirmMnemonic => self.mnemonic_setup.confirm_ui(ui, cb),
             Step::SetupConnection => {
                 // Redraw if node is running.
@@ -361,6 +444,64 @@ impl WalletCreationContent {
         }
     }
 
+    /// Draw view key input content for watch-only wallet.
+    fn view_key_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        ui.add_space(10.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.enter_view_key"))
+                .size(16.0)
+                .color(Colors::gray()));
+        });
+        ui.add_space(8.0);
+        let mut key_edit = TextEdit::new(Id::from("watch_only_view_key")).focus(false);
+        key_edit.ui(ui, &mut self.rewind_hash_edit, cb);
+
+        // Show error when view key is not valid.
+        let key = self.rewind_hash_edit.trim();
+        if !key.is_empty() && !WatchOnlyData::is_valid_rewind_hash(key) {
+            ui.add_space(8.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.invalid_view_key"))
+                    .size(16.0)
+                    .color(Colors::red()));
+            });
+        }
+        ui.add_space(8.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.view_key_desc"))
+                .size(16.0)
+                .color(Colors::inactive_text()));
+        });
+    }
+
+    /// Draw content to restore wallet from backup file.
+    fn backup_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        ui.add_space(12.0);
+        View::horizontal_line(ui, Colors::item_stroke());
+        ui.add_space(8.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.restore_backup_desc"))
+                .size(16.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            View::max_width_ui(ui, Content::SIDE_PANEL_WIDTH, |ui| {
+                ui.vertical_centered_justified(|ui| {
+                    self.backup_pick.ui(ui, cb, |data| {
+                        self.backup_error = false;
+                        self.backup_data = Some(data);
+                    });
+                });
+            });
+            if self.backup_error {
+                ui.add_space(8.0);
+                ui.label(RichText::new(t!("wallets.restore_backup_err"))
+                    .size(16.0)
+                    .color(Colors::red()));
+            }
+        });
+        ui.add_space(8.0);
+    }
+
     /// Back to previous wallet creation [`Step`], return `true` to close creation.
     pub fn on_back(&mut self) -> bool {
         match &self.step {
@@ -13,13 +13,14 @@
 // limitations under the License.
 
 use egui::{Id, RichText};
+use grin_util::ZeroingString;
 
 use crate::gui::Colors;
-use crate::gui::icons::PENCIL;
+use crate::gui::icons::{COPY, PENCIL};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::{Modal, Content, View, TextEdit};
 use crate::gui::views::types::{ContentContainer, ModalPosition};
-use crate::wallet::Mnemonic;
+use crate::wallet::{Mnemonic, SeedShares};
 use crate::wallet::types::{PhraseMode, PhraseSize, PhraseWord};
 
 /// Mnemonic phrase setup content.
@@ -33,6 +34,17 @@ pub struct MnemonicSetup {
     word_edit: String,
     /// Flag to check if entered word is valid at [`Modal`].
     valid_word_edit: bool,
+
+    /// Generated SLIP-39 shares of the phrase.
+    shares: Vec<ZeroingString>,
+    /// Current share index to show and verify.
+    share_index: usize,
+    /// Flag to check if current share is verifying.
+    share_verify: bool,
+    /// Entered share value to verify.
+    share_edit: String,
+    /// Flag to check if entered share is not matching.
+    share_error: bool,
 }
 
 /// Identifier for word input [`Modal`].
@@ -45,6 +57,11 @@ impl Default for MnemonicSetup {
             word_index_edit: 0,
             word_edit: String::from(""),
             valid_word_edit: true,
+            shares: vec![],
+            share_index: 0,
+            share_verify: false,
+            share_edit: "".to_string(),
+            share_error: false,
         }
     }
 }
@@ -121,13 +138,100 @@ impl MnemonicSetup {
         View::horizontal_line(ui, Colors::item_stroke());
         ui.add_space(6.0);
 
-        // Show words setup.
-        self.word_list_ui(ui, self.mnemonic.mode() == PhraseMode::Import);
+        match self.mnemonic.mode() {
+            PhraseMode::Generate => {
+                self.split_setup_ui(ui);
+                // Do not show the phrase when it will be split into shares.
+                if self.mnemonic.split().is_none() {
+                    self.word_list_ui(ui, false);
+                }
+            }
+            PhraseMode::Import => {
+                self.word_list_ui(ui, true);
+                // Show entered shares info.
+                ui.vertical_centered(|ui| {
+                    let text = if self.mnemonic.shares_count() > 0 {
+                        t!("wallets.shares_entered", "count" => self.mnemonic.shares_count())
+                    } else {
+                        t!("wallets.shares_import_desc")
+                    };
+                    ui.label(RichText::new(text).size(16.0).color(Colors::inactive_text()));
+                });
+                ui.add_space(6.0);
+            }
+        }
+    }
+
+    /// Draw setup to split generated phrase into SLIP-39 shares.
+    fn split_setup_ui(&mut self, ui: &mut egui::Ui) {
+        let mut split = self.mnemonic.split();
+        ui.add_space(4.0);
+        ui.vertical_centered(|ui| {
+            View::checkbox(ui, split.is_some(), t!("wallets.split_shares"), || {
+                split = match split {
+                    None => Some((2, 3)),
+                    Some(_) => None
+                };
+            });
+        });
+        if let Some((threshold, count)) = split.as_mut() {
+            ui.add_space(8.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.shares_count"))
+                    .size(16.0)
+                    .color(Colors::gray()));
+            });
+            ui.add_space(6.0);
+            let values = (2..=SeedShares::MAX_COUNT).collect::<Vec<u8>>();
+            ui.columns(values.len(), |columns| {
+                for (index, value) in values.iter().enumerate() {
+                    columns[index].vertical_centered(|ui| {
+                        View::radio_value(ui, count, *value, value.to_string());
+                    });
+                }
+            });
+            *threshold = (*threshold).min(*count);
+
+            ui.add_space(8.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.shares_threshold"))
+                    .size(16.0)
+                    .color(Colors::gray()));
+            });
+            ui.add_space(6.0);
+            ui.columns(values.len(), |columns| {
+                for (index, value) in values.iter().enumerate() {
+                    if *value > *count {
+                        break;
+                    }
+                    columns[index].vertical_centered(|ui| {
+                        View::radio_value(ui, threshold, *value, value.to_string());
+                    });
+                }
+            });
+            ui.add_space(8.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.split_shares_desc"))
+                    .size(16.0)
+                    .color(Colors::inactive_text()));
+            });
+        }
+        if split != self.mnemonic.split() {
+            self.mnemonic.set_split(split);
+        }
+        ui.add_space(6.0);
     }
+
     /// Draw content for phrase confirmation step.
     pub fn confirm_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
         self.ui(ui, cb);
 
+        // Verify shares instead of the phrase.
+        if self.mnemonic.split().is_some() {
+            self.shares_confirm_ui(ui, cb);
+            return;
+        }
+
         ui.add_space(4.0);
         ui.vertical_centered(|ui| {
             let text = format!("{}:", t!("wallets.recovery_phrase"));
@@ -137,16 +241,139 @@ impl MnemonicSetup {
         self.word_list_ui(ui, true);
     }
 
+    /// Generate SLIP-39 shares of the phrase to show and verify.
+    pub fn setup_shares(&mut self) {
+        self.shares = self.mnemonic.generate_shares().unwrap_or_default();
+        self.share_index = 0;
+        self.share_verify = false;
+        self.share_edit = "".to_string();
+        self.share_error = false;
+    }
+
+    /// Check if all generated shares were verified.
+    pub fn shares_verified(&self) -> bool {
+        !self.shares.is_empty() && self.share_index >= self.shares.len()
+    }
+
+    /// Draw content to show and verify generated shares one by one.
+    fn shares_confirm_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        ui.add_space(4.0);
+        if self.shares.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.shares_err"))
+                    .size(16.0)
+                    .color(Colors::red()));
+            });
+            return;
+        }
+        if self.shares_verified() {
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.shares_verified"))
+                    .size(16.0)
+                    .color(Colors::green()));
+            });
+            return;
+        }
+
+        let (threshold, count) = self.mnemonic.split().unwrap();
+        let share = self.shares[self.share_index].clone();
+        ui.vertical_centered(|ui| {
+            let text = t!("wallets.share_number",
+                "number" => self.share_index + 1,
+                "count" => count,
+                "threshold" => threshold);
+            ui.label(RichText::new(text).size(16.0).color(Colors::gray()));
+        });
+        ui.add_space(4.0);
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        if !self.share_verify {
+            // Show share words.
+            let words = share.split_whitespace()
+                .map(|w| PhraseWord { text: w.to_string(), valid: true })
+                .collect::<Vec<PhraseWord>>();
+            self.words_grid_ui(ui, words, false);
+            ui.add_space(6.0);
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    let copy_text = format!("{} {}", COPY, t!("copy"));
+                    View::button(ui, copy_text, Colors::white_or_black(false), || {
+                        cb.copy_string_to_buffer(share.to_string());
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    let verify_text = t!("wallets.verify_share");
+                    View::button(ui, verify_text, Colors::white_or_black(false), || {
+                        self.share_edit = "".to_string();
+                        self.share_error = false;
+                        self.share_verify = true;
+                    });
+                });
+            });
+            return;
+        }
+
+        // Show share verification input.
+        let on_verify = |c: &mut MnemonicSetup| {
+            if SeedShares::normalize(&c.share_edit) == SeedShares::normalize(&share) {
+                c.share_index += 1;
+                c.share_verify = false;
+                c.share_edit = "".to_string();
+                c.share_error = false;
+            } else {
+                c.share_error = true;
+            }
+        };
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.enter_share"))
+                .size(16.0)
+                .color(Colors::gray()));
+            ui.add_space(6.0);
+            let share_id = Id::from("share_verify_edit").with(self.share_index);
+            // Share should be entered manually to check that it was written down.
+            let mut share_edit = TextEdit::new(share_id).no_paste();
+            share_edit.ui(ui, &mut self.share_edit, cb);
+            if share_edit.enter_pressed {
+                on_verify(self);
+            }
+            if self.share_error {
+                ui.add_space(8.0);
+                ui.label(RichText::new(t!("wallets.share_not_match"))
+                    .size(16.0)
+                    .color(Colors::red()));
+            }
+        });
+        ui.add_space(8.0);
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                View::button(ui, t!("wallets.show_share"), Colors::white_or_black(false), || {
+                    self.share_verify = false;
+                });
+            });
+            columns[1].vertical_centered_justified(|ui| {
+                View::button(ui, t!("continue"), Colors::white_or_black(false), || {
+                    on_verify(self);
+                });
+            });
+        });
+    }
+
     /// Draw grid of words for mnemonic phrase.
     fn word_list_ui(&mut self, ui: &mut egui::Ui, edit: bool) {
+        // Select list of words based on current mode and edit flag.
+        let words = self.mnemonic.words(edit);
+        self.words_grid_ui(ui, words, edit);
+    }
+
+    /// Draw grid of provided words.
+    fn words_grid_ui(&mut self, ui: &mut egui::Ui, words: Vec<PhraseWord>, edit: bool) {
         ui.add_space(6.0);
         ui.scope(|ui| {
             // Setup spacing between columns.
             ui.spacing_mut().item_spacing = egui::Vec2::new(6.0, 6.0);
 
-            // Select list of words based on current mode and edit flag.
-            let words = self.mnemonic.words(edit);
-
             let mut word_number = 0;
             let cols = list_columns_count(ui);
             let _ = words.chunks(cols).map(|chunk| {
@@ -227,6 +454,9 @@ impl MnemonicSetup {
     /// Reset mnemonic phrase state to default values.
     pub fn reset(&mut self) {
         self.mnemonic = Mnemonic::default();
+        self.shares = vec![];
+        self.share_index = 0;
+        self.share_verify = false;
     }
 
     /// Draw word input [`Modal`] content.
@@ -15,7 +15,7 @@
 use egui::{Align, Layout, RichText, StrokeKind};
 use grin_core::core::amount_to_hr_string;
 
-use crate::gui::icons::{FOLDER_USER, PACKAGE, SCAN, SPINNER, USERS_THREE, USER_PLUS};
+use crate::gui::icons::{EYE, FOLDER_USER, PACKAGE, SCAN, SPINNER, USERS_THREE, USER_PLUS};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::types::{ModalPosition, QrScanResult};
 use crate::gui::views::wallets::wallet::account::create::CreateAccountContent;
@@ -23,7 +23,7 @@ use crate::gui::views::wallets::wallet::account::list::WalletAccountsContent;
 use crate::gui::views::wallets::wallet::types::{WalletContentContainer, GRIN};
 use crate::gui::views::{CameraContent, CameraScanContent, Content, Modal, View};
 use crate::gui::Colors;
-use crate::wallet::{Wallet, WalletConfig};
+use crate::wallet::{PaymentUri, Wallet, WalletConfig};
 use crate::wallet::types::WalletTask;
 
 /// Wallet account panel content.
@@ -37,6 +37,8 @@ pub struct AccountContent {
     qr_scan_content: Option<CameraContent>,
     /// QR code scan result
     qr_scan_result: Option<QrScanResult>,
+    /// Scanned payment URI to send funds.
+    send_uri: Option<PaymentUri>,
 }
 
 /// Account creation [`Modal`] identifier.
@@ -83,6 +85,7 @@ impl Default for AccountContent {
             create_account_content: CreateAccountContent::default(),
             qr_scan_content: None,
             qr_scan_result: None,
+            send_uri: None,
         }
     }
 }
@@ -103,6 +106,11 @@ impl AccountContent {
         self.qr_scan_result = None;
     }
 
+    /// Take scanned payment URI to send funds.
+    pub fn take_send_uri(&mut self) -> Option<PaymentUri> {
+        self.send_uri.take()
+    }
+
     /// Check if it's possible to go back at navigation stack.
     pub fn can_back(&self) -> bool {
         self.qr_scan_showing() || self.list_content.is_some()
@@ -141,32 +149,35 @@ impl AccountContent {
                           StrokeKind::Outside);
 
         ui.allocate_ui_with_layout(rect.size(), Layout::right_to_left(Align::Center), |ui| {
-            // Draw button to show QR code scanner.
-            View::item_button(ui, View::item_rounding(0, 2, true), SCAN, None, || {
-                self.qr_scan_content = Some(CameraContent::default());
-                cb.start_camera();
-            });
+            // Draw buttons to scan QR code and show accounts if wallet is not watch-only.
+            if !wallet.is_watch_only() {
+                // Draw button to show QR code scanner.
+                View::item_button(ui, View::item_rounding(0, 2, true), SCAN, None, || {
+                    self.qr_scan_content = Some(CameraContent::default());
+                    cb.start_camera();
+                });
 
-            // Draw button to show list of accounts.
-            let accounts = wallet.accounts();
-            let accounts_icon = if accounts.len() > 1 {
-                USERS_THREE
-            } else {
-                USER_PLUS
-            };
-            View::item_button(ui, View::item_rounding(1, 3, true), accounts_icon, None, || {
-                if accounts.len() == 1 {
-                    self.create_account_content = CreateAccountContent::default();
-                    Modal::new(CREATE_MODAL_ID)
-                        .position(ModalPosition::CenterTop)
-                        .title(t!("wallets.accounts"))
-                        .show();
+                // Draw button to show list of accounts.
+                let accounts = wallet.accounts();
+                let accounts_icon = if accounts.len() > 1 {
+                    USERS_THREE
                 } else {
-                    self.list_content = Some(
-                        WalletAccountsContent::new(accounts, wallet.get_config().account)
-                    );
-                }
-            });
+                    USER_PLUS
+                };
+                View::item_button(ui, View::item_rounding(1, 3, true), accounts_icon, None, || {
+                    if accounts.len() == 1 {
+                        self.create_account_content = CreateAccountContent::default();
+                        Modal::new(CREATE_MODAL_ID)
+                            .position(ModalPosition::CenterTop)
+                            .title(t!("wallets.accounts"))
+                            .show();
+                    } else {
+                        self.list_content = Some(
+                            WalletAccountsContent::new(accounts, wallet.get_config().account)
+                        );
+                    }
+                });
+            }
 
             let layout_size = ui.available_size();
             ui.allocate_ui_with_layout(layout_size, Layout::left_to_right(Align::Center), |ui| {
@@ -192,7 +203,11 @@ impl AccountContent {
                     } else {
                         account.to_owned()
                     };
-                    let acc_text = format!("{} {}", FOLDER_USER, acc_label);
+                    let acc_text = if wallet.is_watch_only() {
+                        format!("{} {}", EYE, t!("wallets.watch_only"))
+                    } else {
+                        format!("{} {}", FOLDER_USER, acc_label)
+                    };
                     View::ellipsize_text(ui, acc_text, 15.0, Colors::text(false));
 
                     // Show confirmed height or sync progress.
@@ -285,11 +300,14 @@ impl AccountContent {
                     cb.stop_camera();
                     self.qr_scan_content = None;
                     match result {
-                        QrScanResult::Address(_) => {
-                            //TODO: send with address
+                        QrScanResult::Address(a) => {
+                            self.send_uri = Some(PaymentUri::new(a.to_string(), None, None));
+                        }
+                        QrScanResult::PaymentUri(uri) => {
+                            self.send_uri = Some(uri);
                         }
                         QrScanResult::Slatepack(m) => {
-                            wallet.task(WalletTask::OpenMessage(m));
+                            let _ = wallet.task(WalletTask::OpenMessage(m));
                         }
                         _ => {
                             self.qr_scan_result = Some(result);
@@ -15,6 +15,7 @@
 use egui::scroll_area::ScrollBarVisibility;
 use egui::{Id, Margin, RichText, ScrollArea};
 use grin_chain::SyncStatus;
+use grin_core::core::amount_to_hr_string;
 
 use crate::gui::icons::{ARROWS_CLOCKWISE, FILE_ARROW_DOWN, FILE_ARROW_UP, GEAR_FINE, POWER, STACK};
 use crate::gui::platform::PlatformCallbacks;
@@ -30,7 +31,7 @@ use crate::gui::views::{Content, FilePickContent, FilePickContentType, Modal, Vi
 use crate::gui::Colors;
 use crate::node::Node;
 use crate::wallet::types::{ConnectionMethod, WalletTask};
-use crate::wallet::{ExternalConnection, Wallet};
+use crate::wallet::{ExternalConnection, PaymentUri, RecurringPayment, Wallet};
 use crate::AppConfig;
 
 /// Wallet content.
@@ -50,18 +51,26 @@ pub struct WalletContent {
 
     /// Tab button to pick file for parsing.
     file_pick_tab_button: FilePickContent,
+
+    /// Due recurring payment to confirm at [`Modal`].
+    pending_payment: Option<RecurringPayment>,
+    /// Payment URI to open sending [`Modal`] with.
+    send_uri: Option<PaymentUri>,
 }
 
 /// Identifier for invoice creation [`Modal`].
 const INVOICE_MODAL_ID: &'static str = "invoice_request_modal";
 /// Identifier for sending request creation [`Modal`].
 const SEND_MODAL_ID: &'static str = "send_request_modal";
+/// Identifier for recurring payment confirmation [`Modal`].
+const PAYMENT_CONFIRMATION_MODAL_ID: &'static str = "payment_confirmation_modal";
 
 impl WalletContentContainer for WalletContent {
     fn modal_ids(&self) -> Vec<&'static str> {
         vec![
             INVOICE_MODAL_ID,
-            SEND_MODAL_ID
+            SEND_MODAL_ID,
+            PAYMENT_CONFIRMATION_MODAL_ID
         ]
     }
 
@@ -77,6 +86,9 @@ impl WalletContentContainer for WalletContent {
                     c.modal_ui(ui, w, m, cb);
                 }
             }
+            PAYMENT_CONFIRMATION_MODAL_ID => {
+                self.payment_confirmation_modal_ui(ui, w);
+            }
             _ => {}
         }
     }
@@ -89,6 +101,29 @@ impl WalletContentContainer for WalletContent {
         let data = wallet.get_data();
         let block_nav = self.block_navigation_on_sync(wallet);
 
+        // Take payment URI scanned at account panel.
+        if let Some(uri) = self.account_content.take_send_uri() {
+            self.send_uri = Some(uri);
+        }
+
+        // Ask to confirm due recurring payment or show sending to payment URI.
+        if Modal::opened().is_none() && !block_nav {
+            if let Some(p) = wallet.pending_payment() {
+                self.pending_payment = Some(p);
+                Modal::new(PAYMENT_CONFIRMATION_MODAL_ID)
+                    .position(ModalPosition::Center)
+                    .title(t!("wallets.recurring_payments"))
+                    .show();
+            } else if data.is_some() && self.send_uri.is_some() && !wallet.is_watch_only() {
+                let uri = self.send_uri.take().unwrap();
+                self.send_request_content = Some(SendRequestContent::from_uri(uri));
+                Modal::new(SEND_MODAL_ID)
+                    .position(ModalPosition::CenterTop)
+                    .title(t!("wallets.send"))
+                    .show();
+            }
+        }
+
         // Show wallet account panel not on settings tab when navigation is not blocked and QR code
         // scanner is not showing and wallet data is not empty.
         let mut show_account = self.current_tab.get_type() != WalletTabType::Settings && !block_nav
@@ -178,7 +213,7 @@ impl WalletContentContainer for WalletContent {
         }
 
         // Show wallet transport content.
-        if !self.account_content.can_back() && show_account {
+        if !self.account_content.can_back() && show_account && !wallet.is_watch_only() {
             egui::TopBottomPanel::top(Id::from("wallet_transport").with(wallet.identifier()))
                 .frame(egui::Frame {
                     inner_margin: Margin {
@@ -282,11 +317,63 @@ impl Default for WalletContent {
             invoice_request_content: None,
             send_request_content: None,
             file_pick_tab_button: FilePickContent::new(FilePickContentType::Tab),
+            pending_payment: None,
+            send_uri: None,
         }
     }
 }
 
 impl WalletContent {
+    /// Draw recurring payment confirmation [`Modal`] content.
+    fn payment_confirmation_modal_ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet) {
+        let payment = match self.pending_payment.as_ref() {
+            Some(p) => p.clone(),
+            None => return
+        };
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            let amount = amount_to_hr_string(payment.amount, true);
+            let text = t!("wallets.payment_due", "amount" => amount);
+            ui.label(RichText::new(text)
+                .size(17.0)
+                .color(Colors::text(false)));
+            ui.add_space(8.0);
+            ui.label(RichText::new(&payment.address)
+                .size(16.0)
+                .color(Colors::white_or_black(true)));
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("wallets.payment_skip"), Colors::white_or_black(false), || {
+                        wallet.confirm_payment(payment.id, false);
+                        self.pending_payment = None;
+                        Modal::close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("wallets.send"), Colors::white_or_black(false), || {
+                        wallet.confirm_payment(payment.id, true);
+                        self.pending_payment = None;
+                        Modal::close();
+                    });
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+
+    /// Open sending [`Modal`] for payment URI when wallet will be ready.
+    pub fn send_to_uri(&mut self, uri: PaymentUri) {
+        self.send_uri = Some(uri);
+    }
+
     /// Get title based on current navigation state.
     pub fn title(&self) -> String {
         if self.account_content.qr_scan_showing() {
@@ -342,57 +429,62 @@ impl WalletContent {
             let can_send = has_wallet_data &&
                 wallet.get_data().unwrap().info.amount_currently_spendable > 0;
 
+            // Show only transactions and settings tabs for watch-only wallet.
+            let watch_only = wallet.is_watch_only();
             let current_type = self.current_tab.get_type();
-            let tabs_amount = if can_send { 5 } else { 4 };
+            let tabs_amount = if watch_only { 2 } else if can_send { 5 } else { 4 };
             ui.columns(tabs_amount, |columns| {
                 columns[0].vertical_centered_justified(|ui| {
                     View::tab_button(ui, STACK, None, Some(current_type == WalletTabType::Txs), |_| {
                         self.current_tab = Box::new(WalletTransactions::new(None));
                     });
                 });
-                let active = if has_wallet_data { Some(false) } else { None };
-                columns[1].vertical_centered_justified(|ui| {
-                    if wallet.invoice_creating() {
-                        ui.add_space(4.0);
-                        View::small_loading_spinner(ui);
-                    } else {
-                        let (icon, color) = (FILE_ARROW_DOWN, Some(Colors::green()));
-                        View::tab_button(ui, icon, color, active, |_| {
-                            self.invoice_request_content = Some(InvoiceRequestContent::default());
-                            Modal::new(INVOICE_MODAL_ID)
-                                .position(ModalPosition::CenterTop)
-                                .title(t!("wallets.receive"))
-                                .show();
-                        });
-                    }
-                });
-                columns[2].vertical_centered_justified(|ui| {
-                    if wallet.message_opening() {
-                        ui.add_space(4.0);
-                        View::small_loading_spinner(ui);
-                    } else {
-                        self.file_pick_tab_button.set_active(active.is_some());
-                        self.file_pick_tab_button.ui(ui, cb, |m| {
-                            wallet.task(WalletTask::OpenMessage(m));
-                        });
-                    }
-                });
-                if can_send {
-                    columns[3].vertical_centered_justified(|ui| {
-                        if wallet.send_creating() {
+                if !watch_only {
+                    let active = if has_wallet_data { Some(false) } else { None };
+                    columns[1].vertical_centered_justified(|ui| {
+                        if wallet.invoice_creating() {
                             ui.add_space(4.0);
                             View::small_loading_spinner(ui);
                         } else {
-                            let (icon, color) = (FILE_ARROW_UP, Some(Colors::red()));
-                            View::tab_button(ui, icon, color, Some(false), |_| {
-                                self.send_request_content = Some(SendRequestContent::new(None));
-                                Modal::new(SEND_MODAL_ID)
+                            let (icon, color) = (FILE_ARROW_DOWN, Some(Colors::green()));
+                            View::tab_button(ui, icon, color, active, |_| {
+                                let content = InvoiceRequestContent::default();
+                                self.invoice_request_content = Some(content);
+                                Modal::new(INVOICE_MODAL_ID)
                                     .position(ModalPosition::CenterTop)
-                                    .title(t!("wallets.send"))
+                                    .title(t!("wallets.receive"))
                                     .show();
                             });
                         }
                     });
+                    columns[2].vertical_centered_justified(|ui| {
+                        if wallet.message_opening() {
+                            ui.add_space(4.0);
+                            View::small_loading_spinner(ui);
+                        } else {
+                            self.file_pick_tab_button.set_active(active.is_some());
+                            self.file_pick_tab_button.ui(ui, cb, |m| {
+                                let _ = wallet.task(WalletTask::OpenMessage(m));
+                            });
+                        }
+                    });
+                    if can_send {
+                        columns[3].vertical_centered_justified(|ui| {
+                            if wallet.send_creating() {
+                                ui.add_space(4.0);
+                                View::small_loading_spinner(ui);
+                            } else {
+                                let (icon, color) = (FILE_ARROW_UP, Some(Colors::red()));
+                                View::tab_button(ui, icon, color, Some(false), |_| {
+                                    self.send_request_content = Some(SendRequestContent::new(None));
+                                    Modal::new(SEND_MODAL_ID)
+                                        .position(ModalPosition::CenterTop)
+                                        .title(t!("wallets.send"))
+                                        .show();
+                                });
+                            }
+                        });
+                    }
                 }
                 columns[tabs_amount - 1].vertical_centered_justified(|ui| {
                     let active = Some(current_type == WalletTabType::Settings);
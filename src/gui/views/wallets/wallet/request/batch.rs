@@ -0,0 +1,217 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::scroll_area::ScrollBarVisibility;
+use egui::{Align, Id, Layout, RichText, ScrollArea, StrokeKind};
+use grin_core::core::amount_to_hr_string;
+
+use crate::gui::icons::{ARROWS_CLOCKWISE, EXPORT, PAPER_PLANE, TRASH};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{FilePickContent, FilePickContentType, View};
+use crate::gui::Colors;
+use crate::wallet::{BatchPayout, BatchPayoutRow, BatchRowStatus, Wallet};
+
+/// Content to import, send and check status of batch payout from CSV file.
+pub struct BatchPayoutContent {
+    /// Button to import payout file.
+    import_button: FilePickContent,
+    /// Number of incorrect line at imported file, `0` if file is empty.
+    line_error: Option<usize>,
+    /// Flag to check if there are not enough funds to send.
+    funds_error: bool,
+}
+
+/// Height of payout row list item.
+const ROW_ITEM_HEIGHT: f32 = 78.0;
+
+impl Default for BatchPayoutContent {
+    fn default() -> Self {
+        Self {
+            import_button: FilePickContent::new(FilePickContentType::Button),
+            line_error: None,
+            funds_error: false,
+        }
+    }
+}
+
+impl BatchPayoutContent {
+    /// Draw content.
+    pub fn ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        match wallet.batch_payout() {
+            None => self.import_ui(ui, wallet, cb),
+            Some(batch) => self.batch_ui(ui, wallet, batch, cb)
+        }
+    }
+
+    /// Draw content to import payout file.
+    fn import_ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.batch_desc"))
+                .size(17.0)
+                .color(Colors::text(false)));
+            // Show import error.
+            if let Some(line) = self.line_error {
+                ui.add_space(8.0);
+                let err = if line == 0 {
+                    t!("wallets.batch_empty_err")
+                } else {
+                    t!("wallets.batch_line_err", "line" => line)
+                };
+                ui.label(RichText::new(err).size(17.0).color(Colors::red()));
+            }
+        });
+        ui.add_space(12.0);
+        ui.vertical_centered_justified(|ui| {
+            self.import_button.ui(ui, cb, |data| {
+                match BatchPayout::from_csv(&data) {
+                    Ok(batch) => {
+                        self.line_error = None;
+                        self.funds_error = false;
+                        wallet.set_batch_payout(Some(batch));
+                    }
+                    Err(line) => {
+                        self.line_error = Some(line);
+                    }
+                }
+            });
+        });
+    }
+
+    /// Draw imported payout content.
+    fn batch_ui(&mut self,
+                ui: &mut egui::Ui,
+                wallet: &Wallet,
+                batch: BatchPayout,
+                cb: &dyn PlatformCallbacks) {
+        let size = batch.rows.len();
+        ScrollArea::vertical()
+            .id_salt(Id::from("batch_payout_scroll").with(wallet.get_config().id))
+            .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+            .max_height(ROW_ITEM_HEIGHT * 4.0)
+            .auto_shrink([true; 2])
+            .show_rows(ui, ROW_ITEM_HEIGHT, size, |ui, row_range| {
+                for index in row_range {
+                    let row = batch.rows.get(index).unwrap();
+                    row_item_ui(ui, row, index, size);
+                }
+            });
+        ui.add_space(8.0);
+
+        // Show sending progress.
+        if wallet.batch_sending() {
+            ui.vertical_centered(|ui| {
+                View::small_loading_spinner(ui);
+                ui.add_space(8.0);
+                ui.label(RichText::new(t!("wallets.batch_sending"))
+                    .size(17.0)
+                    .color(Colors::text(false)));
+            });
+            return;
+        }
+
+        // Show amount to send or error.
+        let spendable = wallet.get_data().map(|d| d.info.amount_currently_spendable).unwrap_or(0);
+        let to_send = batch.amount_to_send();
+        if batch.can_send() {
+            ui.vertical_centered(|ui| {
+                let amount = amount_to_hr_string(to_send, true);
+                if self.funds_error {
+                    let balance = amount_to_hr_string(spendable, true);
+                    ui.label(RichText::new(t!("wallets.batch_funds_err", "amount" => balance))
+                        .size(17.0)
+                        .color(Colors::red()));
+                } else {
+                    ui.label(RichText::new(t!("wallets.batch_total", "amount" => amount))
+                        .size(17.0)
+                        .color(Colors::gray()));
+                }
+            });
+            ui.add_space(8.0);
+        }
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        ui.columns(2, |cols| {
+            cols[0].vertical_centered_justified(|ui| {
+                let clear_text = format!("{} {}", TRASH, t!("clear"));
+                View::button(ui, clear_text, Colors::white_or_black(false), || {
+                    self.funds_error = false;
+                    wallet.set_batch_payout(None);
+                });
+            });
+            cols[1].vertical_centered_justified(|ui| {
+                let export_text = format!("{} {}", EXPORT, t!("wallets.batch_report"));
+                View::button(ui, export_text, Colors::white_or_black(false), || {
+                    let name = format!("{}-payout.csv", wallet.get_config().name);
+                    cb.share_data(name, batch.report_csv()).unwrap_or_default();
+                });
+            });
+        });
+
+        // Show button to send or retry failed rows.
+        if batch.can_send() {
+            ui.add_space(8.0);
+            ui.vertical_centered_justified(|ui| {
+                let retry = batch.rows.iter().any(|r| match r.status {
+                    BatchRowStatus::Failed(_) => true,
+                    _ => false
+                });
+                let send_text = if retry {
+                    format!("{} {}", ARROWS_CLOCKWISE, t!("wallets.batch_retry"))
+                } else {
+                    format!("{} {}", PAPER_PLANE, t!("wallets.send"))
+                };
+                View::button(ui, send_text, Colors::white_or_black(false), || {
+                    self.funds_error = to_send + batch.fee_to_send() > spendable;
+                    if !self.funds_error {
+                        wallet.send_batch();
+                    }
+                });
+            });
+        }
+    }
+}
+
+/// Draw payout row item content.
+fn row_item_ui(ui: &mut egui::Ui, row: &BatchPayoutRow, index: usize, size: usize) {
+    // Setup layout size.
+    let mut rect = ui.available_rect_before_wrap();
+    rect.set_height(ROW_ITEM_HEIGHT);
+
+    // Draw round background.
+    let item_rounding = View::item_rounding(index, size, false);
+    ui.painter().rect(rect, item_rounding, Colors::fill(), View::item_stroke(), StrokeKind::Middle);
+
+    ui.allocate_ui_with_layout(rect.size(), Layout::left_to_right(Align::Center), |ui| {
+        ui.add_space(6.0);
+        ui.vertical(|ui| {
+            ui.add_space(3.0);
+            let amount = format!("{} ツ", amount_to_hr_string(row.amount, true));
+            View::ellipsize_text(ui, amount, 18.0, Colors::white_or_black(true));
+            View::ellipsize_text(ui, row.address.clone(), 15.0, Colors::text(false));
+            let (status, color) = match &row.status {
+                BatchRowStatus::Pending => (t!("wallets.batch_pending"), Colors::gray()),
+                BatchRowStatus::Sending => (t!("wallets.batch_row_sending"), Colors::gray()),
+                BatchRowStatus::Sent(_) => (t!("wallets.batch_sent"), Colors::green()),
+                BatchRowStatus::Failed(e) => (format!("{}: {}", t!("error"), e), Colors::red()),
+                BatchRowStatus::NotPosted(_, e) => {
+                    (format!("{}: {}", t!("wallets.batch_not_posted"), e), Colors::red())
+                }
+            };
+            View::ellipsize_text(ui, status, 15.0, color);
+            ui.add_space(3.0);
+        });
+    });
+}
@@ -0,0 +1,197 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::scroll_area::ScrollBarVisibility;
+use egui::{Id, RichText, ScrollArea};
+use grin_core::core::amount_to_hr_string;
+
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::wallets::wallet::{output_item_ui, WalletOutputsContent};
+use crate::gui::views::{Modal, TextEdit, View};
+use crate::gui::Colors;
+use crate::wallet::types::{CoinControl, WalletOutput};
+use crate::wallet::Wallet;
+
+/// Content to select outputs and inputs policy for sending.
+pub struct CoinControlContent {
+    /// Spendable outputs of current account.
+    outputs: Vec<WalletOutput>,
+    /// Commitments of selected outputs.
+    selected: Vec<String>,
+    /// Maximum amount of inputs input.
+    max_inputs_edit: String,
+    /// Amount of change outputs input.
+    change_outputs_edit: String,
+    /// Flag to check if entered values are incorrect.
+    policy_error: bool,
+}
+
+impl CoinControlContent {
+    /// Create new content instance from current inputs selection policy.
+    pub fn new(wallet: &Wallet, coin_control: Option<CoinControl>) -> Self {
+        let account = wallet.get_config().account;
+        let outputs = wallet.outputs()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|o| o.spendable && o.account == account)
+            .collect::<Vec<WalletOutput>>();
+        let coin_control = coin_control.unwrap_or_default();
+        Self {
+            outputs,
+            selected: coin_control.outputs,
+            max_inputs_edit: coin_control.max_inputs.map(|m| m.to_string()).unwrap_or_default(),
+            change_outputs_edit: coin_control.change_outputs.to_string(),
+            policy_error: false,
+        }
+    }
+
+    /// Get total value of selected outputs.
+    pub fn selected_amount(&self) -> u64 {
+        self.outputs.iter().filter(|o| self.selected.contains(&o.commit)).map(|o| o.value).sum()
+    }
+
+    /// Draw content with callback on policy applying, `None` to let the wallet select inputs.
+    pub fn ui(&mut self,
+              ui: &mut egui::Ui,
+              wallet: &Wallet,
+              modal: &Modal,
+              cb: &dyn PlatformCallbacks,
+              on_apply: impl FnOnce(Option<CoinControl>)) {
+        // Show spendable outputs to select.
+        if self.outputs.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.outputs_empty"))
+                    .size(17.0)
+                    .color(Colors::inactive_text()));
+            });
+        } else {
+            let size = self.outputs.len();
+            let item_height = WalletOutputsContent::OUTPUT_ITEM_HEIGHT;
+            ScrollArea::vertical()
+                .id_salt(Id::from("coin_control_scroll").with(wallet.get_config().id))
+                .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+                .max_height(item_height * 4.0)
+                .auto_shrink([true; 2])
+                .show_rows(ui, item_height, size, |ui, row_range| {
+                    for index in row_range {
+                        let output = self.outputs.get(index).unwrap();
+                        let selected = self.selected.contains(&output.commit);
+                        output_item_ui(ui, output, index, size, Some(selected), || {
+                            if selected {
+                                self.selected.retain(|c| c != &output.commit);
+                            } else {
+                                self.selected.push(output.commit.clone());
+                            }
+                        });
+                    }
+                });
+            ui.add_space(8.0);
+            ui.vertical_centered(|ui| {
+                let text = if self.selected.is_empty() {
+                    t!("wallets.outputs_auto")
+                } else {
+                    let amount = self.selected_amount();
+                    t!("wallets.outputs_selected",
+                        "count" => self.selected.len(),
+                        "amount" => amount_to_hr_string(amount, true))
+                };
+                ui.label(RichText::new(text).size(16.0).color(Colors::gray()));
+            });
+        }
+        ui.add_space(8.0);
+
+        // Show inputs policy values.
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.max_inputs"))
+                    .size(16.0)
+                    .color(Colors::gray()));
+            });
+            columns[1].vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.change_outputs"))
+                    .size(16.0)
+                    .color(Colors::gray()));
+            });
+        });
+        ui.add_space(6.0);
+        ui.scope(|ui| {
+            // Setup spacing between inputs.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+            ui.columns(2, |columns| {
+                let max_id = Id::from(modal.id).with("max_inputs").with(wallet.get_config().id);
+                let mut max_edit = TextEdit::new(max_id).h_center().numeric().focus(false);
+                max_edit.ui(&mut columns[0], &mut self.max_inputs_edit, cb);
+                let change_id = Id::from(modal.id)
+                    .with("change_outputs")
+                    .with(wallet.get_config().id);
+                let mut change_edit = TextEdit::new(change_id).h_center().numeric().focus(false);
+                change_edit.ui(&mut columns[1], &mut self.change_outputs_edit, cb);
+            });
+        });
+
+        // Show error on incorrect values.
+        if self.policy_error {
+            ui.add_space(8.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.coin_control_err"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            });
+        }
+        ui.add_space(12.0);
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        let mut on_apply = Some(on_apply);
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                View::button(ui, t!("wallets.tx_filter_reset"), Colors::white_or_black(false), || {
+                    if let Some(on_apply) = on_apply.take() {
+                        on_apply(None);
+                    }
+                });
+            });
+            columns[1].vertical_centered_justified(|ui| {
+                View::button(ui, t!("modal.save"), Colors::white_or_black(false), || {
+                    match self.coin_control() {
+                        Some(c) => {
+                            if let Some(on_apply) = on_apply.take() {
+                                on_apply(Some(c));
+                            }
+                        }
+                        None => self.policy_error = true
+                    }
+                });
+            });
+        });
+    }
+
+    /// Get inputs selection policy from entered values.
+    fn coin_control(&self) -> Option<CoinControl> {
+        let max_inputs = match self.max_inputs_edit.trim() {
+            "" => None,
+            m => Some(m.parse::<u32>().ok().filter(|m| *m > 0)?)
+        };
+        let change_outputs = match self.change_outputs_edit.trim() {
+            "" => 1,
+            c => c.parse::<usize>().ok().filter(|c| *c > 0 && *c <= 100)?
+        };
+        Some(CoinControl {
+            outputs: self.selected.clone(),
+            max_inputs,
+            change_outputs,
+        })
+    }
+}
@@ -0,0 +1,302 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::scroll_area::ScrollBarVisibility;
+use egui::{Align, Id, Layout, RichText, ScrollArea, StrokeKind};
+
+use crate::gui::icons::{CHECK, EXPORT, TRASH, USER, USER_PLUS};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{FilePickContent, FilePickContentType, Modal, TextEdit, View};
+use crate::gui::Colors;
+use crate::wallet::{Wallet, WalletContact};
+
+/// Address book content to pick, add, import and export contacts.
+pub struct ContactsContent {
+    /// Contact name input.
+    name_edit: String,
+    /// Contact address input.
+    address_edit: String,
+    /// Flag to check if contact adding is shown.
+    adding: bool,
+    /// Flag to check if entered contact is incorrect.
+    contact_error: bool,
+
+    /// Button to import contacts from file.
+    import_button: FilePickContent,
+    /// Flag to check if imported file is incorrect.
+    import_error: bool,
+
+    /// Contact to confirm deletion.
+    delete_confirm: Option<WalletContact>,
+}
+
+/// Height of contact list item.
+const CONTACT_ITEM_HEIGHT: f32 = 56.0;
+
+impl ContactsContent {
+    /// Create new content instance with optional address to add.
+    pub fn new(address: Option<String>) -> Self {
+        Self {
+            name_edit: "".to_string(),
+            address_edit: address.unwrap_or_default(),
+            adding: false,
+            contact_error: false,
+            import_button: FilePickContent::new(FilePickContentType::Button),
+            import_error: false,
+            delete_confirm: None,
+        }
+    }
+
+    /// Check if contact adding or deletion confirmation is shown.
+    pub fn adding(&self) -> bool {
+        self.adding || self.delete_confirm.is_some()
+    }
+
+    /// Draw content with callback on contact selection.
+    pub fn ui(&mut self,
+              ui: &mut egui::Ui,
+              wallet: &Wallet,
+              modal: &Modal,
+              cb: &dyn PlatformCallbacks,
+              mut on_select: impl FnMut(WalletContact)) {
+        if self.adding {
+            self.add_ui(ui, wallet, modal, cb);
+            return;
+        }
+        if self.delete_confirm.is_some() {
+            self.delete_confirm_ui(ui, wallet);
+            return;
+        }
+
+        let contacts = wallet.contacts();
+        if contacts.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("contacts.empty"))
+                    .size(17.0)
+                    .color(Colors::inactive_text()));
+            });
+        } else {
+            let size = contacts.len();
+            ScrollArea::vertical()
+                .id_salt(Id::from("contacts_list_scroll").with(wallet.get_config().id))
+                .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+                .max_height(CONTACT_ITEM_HEIGHT * 5.0)
+                .auto_shrink([true; 2])
+                .show_rows(ui, CONTACT_ITEM_HEIGHT, size, |ui, row_range| {
+                    for index in row_range {
+                        let contact = contacts.get(index).unwrap();
+                        contact_item_ui(ui, contact, index, size, || {
+                            self.delete_confirm = Some(contact.clone());
+                        }, || {
+                            on_select(contact.clone());
+                        });
+                    }
+                });
+        }
+        ui.add_space(8.0);
+
+        // Show import error.
+        if self.import_error {
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("contacts.import_err"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            });
+            ui.add_space(8.0);
+        }
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        // Show buttons to import or export contacts.
+        ui.columns(2, |cols| {
+            cols[0].vertical_centered_justified(|ui| {
+                self.import_button.ui(ui, cb, |data| {
+                    self.import_error = !wallet.import_contacts(&data);
+                });
+            });
+            cols[1].vertical_centered_justified(|ui| {
+                let export_text = format!("{} {}", EXPORT, t!("contacts.export"));
+                View::button(ui, export_text, Colors::white_or_black(false), || {
+                    let name = format!("{}-contacts.json", wallet.get_config().name);
+                    cb.share_data(name, wallet.export_contacts()).unwrap_or_default();
+                });
+            });
+        });
+        ui.add_space(8.0);
+
+        // Show button to add new contact.
+        ui.vertical_centered_justified(|ui| {
+            let add_text = format!("{} {}", USER_PLUS, t!("contacts.add"));
+            View::button(ui, add_text, Colors::white_or_black(false), || {
+                self.import_error = false;
+                self.adding = true;
+            });
+        });
+    }
+
+    /// Draw contact adding content.
+    fn add_ui(&mut self,
+              ui: &mut egui::Ui,
+              wallet: &Wallet,
+              modal: &Modal,
+              cb: &dyn PlatformCallbacks) {
+        // Show name input.
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("contacts.name"))
+                .size(17.0)
+                .color(Colors::gray()));
+        });
+        ui.add_space(6.0);
+        let name_id = Id::from(modal.id).with("contact_name").with(wallet.get_config().id);
+        let mut name_edit = TextEdit::new(name_id).focus(true);
+        name_edit.ui(ui, &mut self.name_edit, cb);
+        ui.add_space(8.0);
+
+        // Show address input or error.
+        ui.vertical_centered(|ui| {
+            if self.contact_error {
+                ui.label(RichText::new(t!("contacts.incorrect_err"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            } else {
+                ui.label(RichText::new(t!("transport.receiver_address"))
+                    .size(17.0)
+                    .color(Colors::gray()));
+            }
+        });
+        ui.add_space(6.0);
+        let addr_id = Id::from(modal.id).with("contact_address").with(wallet.get_config().id);
+        let mut address_edit = TextEdit::new(addr_id).paste().focus(false);
+        if name_edit.enter_pressed {
+            address_edit.focus_request();
+        }
+        address_edit.ui(ui, &mut self.address_edit, cb);
+        ui.add_space(12.0);
+
+        // Save contact on Enter press.
+        if address_edit.enter_pressed {
+            self.on_save(wallet);
+        }
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        ui.columns(2, |cols| {
+            cols[0].vertical_centered_justified(|ui| {
+                View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                    self.close_adding();
+                });
+            });
+            cols[1].vertical_centered_justified(|ui| {
+                View::button(ui, t!("modal.save"), Colors::white_or_black(false), || {
+                    self.on_save(wallet);
+                });
+            });
+        });
+    }
+
+    /// Draw contact deletion confirmation content.
+    fn delete_confirm_ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet) {
+        let contact = self.delete_confirm.clone().unwrap();
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("contacts.delete_conf"))
+                .size(17.0)
+                .color(Colors::text(false)));
+            ui.add_space(8.0);
+            let name = format!("{} {}", USER, contact.name);
+            ui.label(RichText::new(name).size(17.0).color(Colors::title(false)));
+            ui.label(RichText::new(&contact.address).size(15.0).color(Colors::gray()));
+        });
+        ui.add_space(12.0);
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        ui.columns(2, |cols| {
+            cols[0].vertical_centered_justified(|ui| {
+                View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                    self.delete_confirm = None;
+                });
+            });
+            cols[1].vertical_centered_justified(|ui| {
+                View::button(ui, t!("delete"), Colors::white_or_black(false), || {
+                    wallet.delete_contact(&contact.address);
+                    self.delete_confirm = None;
+                });
+            });
+        });
+    }
+
+    /// Save entered contact.
+    fn on_save(&mut self, wallet: &Wallet) {
+        let contact = WalletContact {
+            name: self.name_edit.trim().to_string(),
+            address: self.address_edit.trim().to_string(),
+        };
+        if !contact.is_valid() {
+            self.contact_error = true;
+            return;
+        }
+        wallet.save_contact(contact);
+        self.close_adding();
+    }
+
+    /// Close contact adding content.
+    fn close_adding(&mut self) {
+        self.name_edit = "".to_string();
+        self.address_edit = "".to_string();
+        self.contact_error = false;
+        self.adding = false;
+    }
+}
+
+/// Draw contact item content.
+fn contact_item_ui(ui: &mut egui::Ui,
+                   contact: &WalletContact,
+                   index: usize,
+                   size: usize,
+                   on_delete: impl FnOnce(),
+                   on_select: impl FnOnce()) {
+    // Setup layout size.
+    let mut rect = ui.available_rect_before_wrap();
+    rect.set_height(CONTACT_ITEM_HEIGHT);
+
+    // Draw round background.
+    let item_rounding = View::item_rounding(index, size, false);
+    ui.painter().rect(rect, item_rounding, Colors::fill(), View::item_stroke(), StrokeKind::Middle);
+
+    ui.allocate_ui_with_layout(rect.size(), Layout::right_to_left(Align::Center), |ui| {
+        // Draw buttons to select or delete contact.
+        View::item_button(ui, View::item_rounding(index, size, true), CHECK, None, || {
+            on_select();
+        });
+        View::item_button(ui, Default::default(), TRASH, Some(Colors::red()), || {
+            on_delete();
+        });
+
+        let layout_size = ui.available_size();
+        ui.allocate_ui_with_layout(layout_size, Layout::left_to_right(Align::Center), |ui| {
+            ui.add_space(6.0);
+            ui.vertical(|ui| {
+                ui.add_space(3.0);
+                let name = format!("{} {}", USER, contact.name);
+                View::ellipsize_text(ui, name, 17.0, Colors::title(false));
+                View::ellipsize_text(ui, contact.address.clone(), 15.0, Colors::gray());
+                ui.add_space(3.0);
+            });
+        });
+    });
+}
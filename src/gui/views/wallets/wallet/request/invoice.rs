@@ -15,22 +15,39 @@
 use egui::{Id, RichText};
 use grin_core::core::amount_from_hr_string;
 
+use crate::gui::icons::QR_CODE;
 use crate::gui::platform::PlatformCallbacks;
-use crate::gui::views::{Modal, TextEdit, View};
+use crate::gui::views::{Modal, QrCodeContent, TextEdit, View};
 use crate::gui::Colors;
 use crate::wallet::types::WalletTask;
-use crate::wallet::Wallet;
+use crate::wallet::{InvoiceRequest, PaymentUri, Wallet};
 
 /// Invoice request creation content.
 pub struct InvoiceRequestContent {
     /// Amount to receive.
     amount_edit: String,
+    /// Invoice memo.
+    memo_edit: String,
+    /// Address invoice is requested by, main wallet address if empty.
+    requested_by: String,
+    /// Amount of days before invoice expiration, never expires if empty.
+    expiry_edit: String,
+    /// Flag to check if entered expiration is incorrect.
+    expiry_error: bool,
+
+    /// Payment URI QR code content.
+    qr_code_content: Option<QrCodeContent>,
 }
 
 impl Default for InvoiceRequestContent {
     fn default() -> Self {
         Self {
             amount_edit: "".to_string(),
+            memo_edit: "".to_string(),
+            requested_by: "".to_string(),
+            expiry_edit: "".to_string(),
+            expiry_error: false,
+            qr_code_content: None,
         }
     }
 }
@@ -47,15 +64,55 @@ impl InvoiceRequestContent {
             if m.amount_edit.is_empty() {
                 return;
             }
+            let expires = match m.expiry_edit.trim() {
+                "" => None,
+                d => match d.parse::<i64>() {
+                    Ok(d) if d > 0 => Some(chrono::Utc::now().timestamp() + d * 86400),
+                    _ => {
+                        m.expiry_error = true;
+                        return;
+                    }
+                }
+            };
             if let Ok(a) = amount_from_hr_string(m.amount_edit.as_str()) {
-                m.amount_edit = "".to_string();
-                wallet.task(WalletTask::Receive(a));
+                let request = InvoiceRequest {
+                    memo: m.memo_edit.trim().to_string(),
+                    requested_by: m.requested_by.clone(),
+                    expires,
+                };
+                *m = InvoiceRequestContent::default();
+                let _ = wallet.task(WalletTask::Receive(a, request));
                 Modal::close();
             }
         };
 
         ui.add_space(6.0);
 
+        // Draw payment URI QR code content if requested.
+        if let Some(content) = self.qr_code_content.as_mut() {
+            content.ui(ui, cb);
+
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            // Show buttons to close modal or come back to invoice input.
+            ui.columns(2, |cols| {
+                cols[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                        *self = InvoiceRequestContent::default();
+                        Modal::close();
+                    });
+                });
+                cols[1].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("back"), Colors::white_or_black(false), || {
+                        self.qr_code_content = None;
+                    });
+                });
+            });
+            ui.add_space(6.0);
+            return;
+        }
+
         // Draw amount input content.
         ui.vertical_centered(|ui| {
             ui.label(RichText::new(t!("wallets.enter_amount_receive"))
@@ -104,15 +161,81 @@ impl InvoiceRequestContent {
             }
         }
 
+        ui.add_space(8.0);
+
+        // Draw invoice details inputs.
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.invoice_memo"))
+                .size(17.0)
+                .color(Colors::gray()));
+        });
+        ui.add_space(8.0);
+        let memo_id = Id::from(modal.id).with("memo").with(wallet.get_config().id);
+        let mut memo_edit = TextEdit::new(memo_id).focus(false);
+        memo_edit.ui(ui, &mut self.memo_edit, cb);
+        ui.add_space(8.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.invoice_expiry"))
+                .size(17.0)
+                .color(Colors::gray()));
+        });
+        ui.add_space(8.0);
+        let expiry_id = Id::from(modal.id).with("expiry").with(wallet.get_config().id);
+        let mut expiry_edit = TextEdit::new(expiry_id).h_center().numeric().focus(false);
+        expiry_edit.ui(ui, &mut self.expiry_edit, cb);
+        if self.expiry_error {
+            ui.add_space(8.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.invoice_expiry_err"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            });
+        }
+
+        // Draw address selection when wallet has additional addresses.
+        let addresses = wallet.receive_addresses();
+        if !addresses.is_empty() {
+            ui.add_space(8.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.invoice_requested_by"))
+                    .size(17.0)
+                    .color(Colors::gray()));
+                ui.add_space(4.0);
+                View::radio_value(ui, &mut self.requested_by, "".to_string(), t!("wallets.invoice_main_address"));
+                for a in addresses {
+                    View::radio_value(ui, &mut self.requested_by, a.address, a.label);
+                }
+            });
+        }
         ui.add_space(12.0);
 
+        // Show button to share payment URI as QR code.
+        let amount = amount_from_hr_string(self.amount_edit.as_str()).ok().filter(|a| *a > 0);
+        let address = if self.requested_by.is_empty() {
+            wallet.slatepack_address()
+        } else {
+            Some(self.requested_by.clone())
+        };
+        if let (Some(amount), Some(address)) = (amount, address) {
+            ui.vertical_centered_justified(|ui| {
+                let qr_text = format!("{} {}", QR_CODE, t!("wallets.payment_qr"));
+                View::button(ui, qr_text, Colors::fill_lite(), || {
+                    let memo = Some(self.memo_edit.trim().to_string());
+                    let uri = PaymentUri::new(address, Some(amount), memo);
+                    self.qr_code_content = Some(QrCodeContent::new(uri.to_string(), false)
+                        .with_max_size(320.0));
+                });
+            });
+            ui.add_space(8.0);
+        }
+
         // Setup spacing between buttons.
         ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
 
         ui.columns(2, |columns| {
             columns[0].vertical_centered_justified(|ui| {
                 View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
-                    self.amount_edit = "".to_string();
+                    *self = InvoiceRequestContent::default();
                     Modal::close();
                 });
             });
@@ -16,4 +16,13 @@ mod invoice;
 pub use invoice::*;
 
 mod send;
-pub use send::*;
\ No newline at end of file
+pub use send::*;
+
+mod contacts;
+pub use contacts::*;
+
+mod batch;
+pub use batch::*;
+
+mod coins;
+pub use coins::*;
@@ -16,11 +16,14 @@ use egui::{Id, RichText};
 use grin_core::core::{amount_from_hr_string, amount_to_hr_string};
 use grin_wallet_libwallet::SlatepackAddress;
 
+use crate::gui::icons::{ADDRESS_BOOK, ARROW_LINE_UP, CHECK, COINS, FILE_CSV, NOTE, USER};
 use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::wallets::wallet::request::{BatchPayoutContent, CoinControlContent, ContactsContent};
+use crate::gui::views::types::QrScanResult;
 use crate::gui::views::{CameraContent, Modal, TextEdit, View};
 use crate::gui::Colors;
-use crate::wallet::types::WalletTask;
-use crate::wallet::Wallet;
+use crate::wallet::types::{CoinControl, SendEstimate, WalletTask};
+use crate::wallet::{PaymentUri, Wallet};
 
 /// Content to create a request to send funds.
 pub struct SendRequestContent {
@@ -30,9 +33,31 @@ pub struct SendRequestContent {
     address_edit: String,
     /// Flag to check if entered address is incorrect.
     address_error: bool,
+    /// Flag to request payment proof from receiver.
+    proof_request: bool,
+    /// Payment description from payment URI.
+    memo: Option<String>,
 
     /// Address QR code scanner content.
     address_scan_content: Option<CameraContent>,
+
+    /// Address book content.
+    contacts_content: Option<ContactsContent>,
+
+    /// Batch payout content.
+    batch_content: Option<BatchPayoutContent>,
+
+    /// Inputs selection policy.
+    coin_control: Option<CoinControl>,
+    /// Inputs selection policy content.
+    coin_control_content: Option<CoinControlContent>,
+
+    /// Transaction estimation for entered amount.
+    estimate: Option<SendEstimate>,
+    /// Flag to check if there are not enough funds to send entered amount with fee.
+    estimate_error: bool,
+    /// Amount and inputs selection policy of current estimation.
+    estimate_values: Option<(u64, Option<CoinControl>)>,
 }
 
 impl SendRequestContent {
@@ -42,8 +67,34 @@ impl SendRequestContent {
             amount_edit: "".to_string(),
             address_edit: addr.unwrap_or("".to_string()),
             address_error: false,
+            proof_request: true,
+            memo: None,
             address_scan_content: None,
+            contacts_content: None,
+            batch_content: None,
+            coin_control: None,
+            coin_control_content: None,
+            estimate: None,
+            estimate_error: false,
+            estimate_values: None,
+        }
+    }
+
+    /// Create new content instance from payment URI.
+    pub fn from_uri(uri: PaymentUri) -> Self {
+        let mut content = Self::new(None);
+        content.apply_uri(uri);
+        content
+    }
+
+    /// Setup receiver address, amount and description from payment URI.
+    fn apply_uri(&mut self, uri: PaymentUri) {
+        self.address_edit = uri.address;
+        self.address_error = false;
+        if let Some(amount) = uri.amount {
+            self.amount_edit = amount_to_hr_string(amount, true);
         }
+        self.memo = uri.memo;
     }
 
     /// Draw [`Modal`] content.
@@ -62,7 +113,10 @@ impl SendRequestContent {
             };
 
             if let Some(result) = scanner.qr_scan_result() {
-                self.address_edit = result.text();
+                match result {
+                    QrScanResult::PaymentUri(uri) => self.apply_uri(uri),
+                    _ => self.address_edit = result.text()
+                }
                 on_stop();
                 self.address_scan_content = None;
             } else {
@@ -93,6 +147,74 @@ impl SendRequestContent {
             return;
         }
 
+        // Draw address book content if requested.
+        if let Some(contacts) = self.contacts_content.as_mut() {
+            let mut selected = None;
+            contacts.ui(ui, wallet, modal, cb, |c| {
+                selected = Some(c.address);
+            });
+            if let Some(addr) = selected {
+                self.address_edit = addr;
+                self.address_error = false;
+                self.contacts_content = None;
+                return;
+            }
+            if !contacts.adding() {
+                ui.add_space(8.0);
+                ui.vertical_centered_justified(|ui| {
+                    View::button(ui, t!("back"), Colors::white_or_black(false), || {
+                        self.contacts_content = None;
+                    });
+                });
+            }
+            ui.add_space(6.0);
+            return;
+        }
+
+        // Draw inputs selection policy content if requested.
+        if let Some(content) = self.coin_control_content.as_mut() {
+            let mut applied = None;
+            content.ui(ui, wallet, modal, cb, |c| {
+                applied = Some(c);
+            });
+            if let Some(coin_control) = applied {
+                self.coin_control = coin_control;
+                self.coin_control_content = None;
+                return;
+            }
+            ui.add_space(8.0);
+            ui.vertical_centered_justified(|ui| {
+                View::button(ui, t!("back"), Colors::white_or_black(false), || {
+                    self.coin_control_content = None;
+                });
+            });
+            ui.add_space(6.0);
+            return;
+        }
+
+        // Draw batch payout content if requested.
+        if let Some(batch) = self.batch_content.as_mut() {
+            batch.ui(ui, wallet, cb);
+            ui.add_space(8.0);
+            ui.vertical_centered_justified(|ui| {
+                View::button(ui, t!("back"), Colors::white_or_black(false), || {
+                    self.batch_content = None;
+                });
+            });
+            ui.add_space(6.0);
+            return;
+        }
+
+        // Show payment description.
+        if let Some(memo) = &self.memo {
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(format!("{} {}", NOTE, memo))
+                    .size(16.0)
+                    .color(Colors::text(false)));
+            });
+            ui.add_space(8.0);
+        }
+
         ui.vertical_centered(|ui| {
             let data = wallet.get_data().unwrap();
             let amount = amount_to_hr_string(data.info.amount_currently_spendable, true);
@@ -113,6 +235,16 @@ impl SendRequestContent {
         amount_edit.ui(ui, &mut self.amount_edit, cb);
         ui.add_space(8.0);
 
+        // Show button to send all available funds.
+        ui.vertical_centered(|ui| {
+            let max_text = format!("{} {}", ARROW_LINE_UP, t!("wallets.send_max"));
+            View::button(ui, max_text, Colors::fill_lite(), || {
+                let max = wallet.max_send_amount(self.coin_control.clone());
+                self.amount_edit = amount_to_hr_string(max, true);
+            });
+        });
+        ui.add_space(8.0);
+
         // Check value if input was changed.
         if amount_edit_before != self.amount_edit {
             if !self.amount_edit.is_empty() {
@@ -148,6 +280,36 @@ impl SendRequestContent {
             }
         }
 
+        // Show transaction estimation.
+        self.update_estimate(wallet);
+        if self.estimate_error {
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.estimate_err"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            });
+            ui.add_space(8.0);
+        } else if self.estimate_values.is_some() && self.estimate.is_none() {
+            // Show loading while estimation is running.
+            ui.vertical_centered(|ui| {
+                View::small_loading_spinner(ui);
+            });
+            ui.add_space(8.0);
+        } else if let Some(e) = &self.estimate {
+            ui.vertical_centered(|ui| {
+                let inputs = e.inputs.map(|i| i.to_string()).unwrap_or("?".to_string());
+                let text = t!("wallets.send_estimate",
+                    "fee" => amount_to_hr_string(e.fee, true),
+                    "inputs" => inputs,
+                    "change" => e.change_outputs);
+                ui.label(RichText::new(text).size(16.0).color(Colors::gray()));
+                let receive_text = t!("wallets.receiver_gets",
+                    "amount" => amount_to_hr_string(e.amount, true));
+                ui.label(RichText::new(receive_text).size(16.0).color(Colors::gray()));
+            });
+            ui.add_space(8.0);
+        }
+
         // Show address error or input description.
         ui.vertical_centered(|ui| {
             if self.address_error {
@@ -178,11 +340,71 @@ impl SendRequestContent {
             modal.disable_closing();
             self.address_scan_content = Some(CameraContent::default());
         }
-        ui.add_space(12.0);
+        ui.add_space(8.0);
+
+        // Show contact name for entered address or button to open address book.
+        let contact = wallet.contact(&self.address_edit.trim().to_string());
+        ui.vertical_centered_justified(|ui| {
+            if let Some(c) = contact {
+                ui.label(RichText::new(format!("{} {}", USER, c.name))
+                    .size(17.0)
+                    .color(Colors::text(false)));
+            } else {
+                let contacts_text = format!("{} {}", ADDRESS_BOOK, t!("contacts.title"));
+                View::button(ui, contacts_text, Colors::fill_lite(), || {
+                    let addr = self.address_edit.trim().to_string();
+                    self.contacts_content = Some(ContactsContent::new(Some(addr)));
+                });
+            }
+        });
+        ui.add_space(8.0);
+
+        // Show buttons to setup inputs selection and open batch payout.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    let coins_text = if self.coin_control.is_some() {
+                        format!("{} {} {}", COINS, t!("wallets.coin_control"), CHECK)
+                    } else {
+                        format!("{} {}", COINS, t!("wallets.coin_control"))
+                    };
+                    View::button(ui, coins_text, Colors::fill_lite(), || {
+                        let content = CoinControlContent::new(wallet, self.coin_control.clone());
+                        self.coin_control_content = Some(content);
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    let batch_text = format!("{} {}", FILE_CSV, t!("wallets.batch_payout"));
+                    View::button(ui, batch_text, Colors::fill_lite(), || {
+                        self.batch_content = Some(BatchPayoutContent::default());
+                    });
+                });
+            });
+        });
+        ui.add_space(8.0);
+
+
+        // Show checkbox to request payment proof when address was entered.
+        if !self.address_edit.trim().is_empty() {
+            ui.vertical_centered(|ui| {
+                View::checkbox(ui, self.proof_request, t!("wallets.request_proof"), || {
+                    self.proof_request = !self.proof_request;
+                });
+            });
+            ui.add_space(8.0);
+        }
+        ui.add_space(4.0);
 
         // Check value if input was changed.
         if addr_edit_before != self.address_edit {
             self.address_error = false;
+            // Setup values from pasted payment URI.
+            if let Some(uri) = PaymentUri::parse(self.address_edit.as_str()) {
+                self.apply_uri(uri);
+            }
         }
 
         // Continue on Enter press.
@@ -214,26 +436,69 @@ impl SendRequestContent {
         if self.amount_edit.is_empty() {
             return;
         }
+        // Check if there are enough funds to send amount with fee.
+        if self.estimate_error {
+            return;
+        }
+        let amount = amount_from_hr_string(self.amount_edit.as_str());
         // Check address to send over Tor if enabled.
         let addr_str = self.address_edit.as_str();
+        let coin_control = self.coin_control.clone();
         if let Ok(r) = SlatepackAddress::try_from(addr_str.trim()) {
-            if let Ok(a) = amount_from_hr_string(self.amount_edit.as_str()) {
-                wallet.task(WalletTask::Send(a, Some(r)));
+            if let Ok(a) = amount {
+                let _ = wallet.task(WalletTask::Send(a, Some(r), self.proof_request, coin_control));
                 Modal::close();
             }
         } else if !addr_str.is_empty() {
             self.address_error = true;
-        } else if let Ok(a) = amount_from_hr_string(self.amount_edit.as_str()) {
-            wallet.task(WalletTask::Send(a, None));
+        } else if let Ok(a) = amount {
+            let _ = wallet.task(WalletTask::Send(a, None, false, coin_control));
             Modal::close();
         }
     }
 
+    /// Start transaction estimation on amount or inputs selection policy change
+    /// and show its result when ready.
+    fn update_estimate(&mut self, wallet: &Wallet) {
+        let values = amount_from_hr_string(self.amount_edit.as_str())
+            .ok()
+            .filter(|a| *a > 0)
+            .map(|a| (a, self.coin_control.clone()));
+        if values != self.estimate_values {
+            self.estimate_values = values.clone();
+            self.estimate = None;
+            self.estimate_error = false;
+            if let Some((a, c)) = values {
+                let _ = wallet.task(WalletTask::EstimateSend(a, c));
+            }
+            return;
+        }
+        if let Some((a, c)) = &self.estimate_values {
+            if self.estimate.is_some() || self.estimate_error {
+                return;
+            }
+            match wallet.send_estimate(*a, c) {
+                Some(Ok(e)) => self.estimate = Some(e),
+                Some(Err(_)) => self.estimate_error = true,
+                None => {}
+            }
+        }
+    }
+
     /// Close modal and clear data.
     fn close(&mut self) {
         self.amount_edit = "".to_string();
         self.address_edit = "".to_string();
+        self.proof_request = true;
+        self.memo = None;
         self.address_scan_content = None;
+        self.contacts_content = None;
+        self.batch_content = None;
+        self.coin_control = None;
+        self.coin_control_content = None;
+        self.estimate = None;
+        self.estimate_error = false;
+        self.estimate_values = None;
         Modal::close();
     }
 }
\ No newline at end of file
@@ -14,7 +14,7 @@
 
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::types::ContentContainer;
-use crate::gui::views::wallets::{CommonSettings, ConnectionSettings, RecoverySettings};
+use crate::gui::views::wallets::{CommonSettings, ConnectionSettings, PaymentsSettings, RecoverySettings};
 use crate::gui::views::wallets::types::{WalletTab, WalletTabType};
 use crate::gui::views::wallets::wallet::types::WalletContentContainer;
 use crate::wallet::Wallet;
@@ -25,6 +25,8 @@ pub struct WalletSettings {
     common_setup: CommonSettings,
     /// Connection setup content.
     conn_setup: ConnectionSettings,
+    /// Recurring payments setup content.
+    payments_setup: PaymentsSettings,
     /// Recovery setup content.
     recovery_setup: RecoverySettings
 }
@@ -34,6 +36,7 @@ impl Default for WalletSettings {
         Self {
             common_setup: CommonSettings::default(),
             conn_setup: ConnectionSettings::default(),
+            payments_setup: PaymentsSettings::default(),
             recovery_setup: RecoverySettings::default()
         }
     }
@@ -64,6 +67,11 @@ impl WalletTab for WalletSettings {
             }
         }
 
+        // Show recurring payments setup if wallet is not watch-only.
+        if !wallet.is_watch_only() {
+            self.payments_setup.ui(ui, wallet, cb);
+        }
+
         // Show wallet recovery setup.
         self.recovery_setup.ui(ui, wallet, cb);
     }
@@ -22,4 +22,7 @@ mod common;
 pub use common::CommonSettings;
 
 mod recovery;
-pub use recovery::RecoverySettings;
\ No newline at end of file
+pub use recovery::RecoverySettings;
+
+mod payments;
+pub use payments::PaymentsSettings;
\ No newline at end of file
@@ -0,0 +1,305 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{Days, Local, NaiveDate};
+use egui::{Align, Id, Layout, RichText, StrokeKind};
+use grin_core::core::{amount_from_hr_string, amount_to_hr_string};
+
+use crate::gui::icons::{CALENDAR, PLUS_CIRCLE, REPEAT, TRASH};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::types::ModalPosition;
+use crate::gui::views::wallets::wallet::types::WalletContentContainer;
+use crate::gui::views::{Modal, TextEdit, View};
+use crate::gui::Colors;
+use crate::wallet::{PaymentInterval, PaymentRunStatus, RecurringPayment, Wallet};
+
+/// Recurring payments setup content.
+pub struct PaymentsSettings {
+    /// Payment amount [`Modal`] value.
+    amount_edit: String,
+    /// Payment receiver address [`Modal`] value.
+    address_edit: String,
+    /// Payment interval [`Modal`] value.
+    interval: PaymentInterval,
+    /// First payment date [`Modal`] value.
+    date_edit: String,
+    /// Flag to check if entered values are incorrect.
+    payment_error: bool,
+}
+
+/// Identifier for recurring payment creation [`Modal`].
+const ADD_PAYMENT_MODAL: &'static str = "wallet_add_payment_modal";
+
+/// Format of first payment date.
+const DATE_FORMAT: &'static str = "%d/%m/%Y";
+
+/// Amount of run log entries to show.
+const LOG_SIZE: usize = 5;
+
+impl Default for PaymentsSettings {
+    fn default() -> Self {
+        Self {
+            amount_edit: "".to_string(),
+            address_edit: "".to_string(),
+            interval: PaymentInterval::Month,
+            date_edit: "".to_string(),
+            payment_error: false,
+        }
+    }
+}
+
+impl WalletContentContainer for PaymentsSettings {
+    fn modal_ids(&self) -> Vec<&'static str> {
+        vec![
+            ADD_PAYMENT_MODAL
+        ]
+    }
+
+    fn modal_ui(&mut self,
+                ui: &mut egui::Ui,
+                wallet: &Wallet,
+                modal: &Modal,
+                cb: &dyn PlatformCallbacks) {
+        match modal.id {
+            ADD_PAYMENT_MODAL => {
+                self.add_modal_ui(ui, wallet, modal, cb);
+            }
+            _ => {}
+        }
+    }
+
+    fn container_ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, _: &dyn PlatformCallbacks) {
+        ui.add_space(10.0);
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(6.0);
+        View::sub_title(ui, format!("{} {}", REPEAT, t!("wallets.recurring_payments")));
+        View::horizontal_line(ui, Colors::stroke());
+        ui.add_space(6.0);
+
+        // Show list of payments.
+        let payments = wallet.recurring_payments();
+        for (index, p) in payments.iter().enumerate() {
+            payment_item_ui(ui, p, index, payments.len(), || {
+                wallet.delete_recurring_payment(p.id);
+            });
+        }
+        if !payments.is_empty() {
+            ui.add_space(8.0);
+        }
+
+        ui.vertical_centered(|ui| {
+            // Show button to add new payment.
+            let add_text = format!("{} {}", PLUS_CIRCLE, t!("wallets.add_payment"));
+            View::button(ui, add_text, Colors::white_or_black(false), || {
+                self.amount_edit = "".to_string();
+                self.address_edit = "".to_string();
+                self.interval = PaymentInterval::Month;
+                let tomorrow = Local::now().checked_add_days(Days::new(1)).unwrap_or(Local::now());
+                self.date_edit = tomorrow.format(DATE_FORMAT).to_string();
+                self.payment_error = false;
+                Modal::new(ADD_PAYMENT_MODAL)
+                    .position(ModalPosition::CenterTop)
+                    .title(t!("wallets.recurring_payments"))
+                    .show();
+            });
+
+            // Show last runs.
+            let log = wallet.payments_log();
+            if !log.is_empty() {
+                ui.add_space(8.0);
+                ui.label(RichText::new(t!("wallets.payments_log"))
+                    .size(16.0)
+                    .color(Colors::gray()));
+                ui.add_space(4.0);
+                for run in log.iter().take(LOG_SIZE) {
+                    let status = match &run.status {
+                        PaymentRunStatus::Sent(_) => t!("wallets.payment_sent"),
+                        PaymentRunStatus::Skipped => t!("wallets.payment_skipped"),
+                        PaymentRunStatus::Failed(e) => format!("{}: {}", t!("error"), e)
+                    };
+                    let text = format!("{} {} ツ {}",
+                                       View::format_time(run.time),
+                                       amount_to_hr_string(run.amount, true),
+                                       status);
+                    ui.label(RichText::new(text)
+                        .size(15.0)
+                        .color(Colors::inactive_text()));
+                }
+            }
+        });
+    }
+}
+
+impl PaymentsSettings {
+    /// Draw recurring payment creation [`Modal`] content.
+    fn add_modal_ui(&mut self,
+                    ui: &mut egui::Ui,
+                    wallet: &Wallet,
+                    modal: &Modal,
+                    cb: &dyn PlatformCallbacks) {
+        let id = Id::from(modal.id).with(wallet.get_config().id);
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.payment_amount"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let mut amount_edit = TextEdit::new(id.with("amount"))
+                .h_center()
+                .numeric()
+                .focus(Modal::first_draw());
+            amount_edit.ui(ui, &mut self.amount_edit, cb);
+            ui.add_space(8.0);
+
+            ui.label(RichText::new(t!("transport.receiver_address"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let mut address_edit = TextEdit::new(id.with("address")).paste().focus(false);
+            if amount_edit.enter_pressed {
+                address_edit.focus_request();
+            }
+            address_edit.ui(ui, &mut self.address_edit, cb);
+            ui.add_space(8.0);
+
+            ui.label(RichText::new(t!("wallets.payment_interval"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+        });
+
+        // Show interval selection.
+        ui.columns(3, |columns| {
+            columns[0].vertical_centered(|ui| {
+                let day = PaymentInterval::Day;
+                View::radio_value(ui, &mut self.interval, day, t!("wallets.payment_daily"));
+            });
+            columns[1].vertical_centered(|ui| {
+                let week = PaymentInterval::Week;
+                View::radio_value(ui, &mut self.interval, week, t!("wallets.payment_weekly"));
+            });
+            columns[2].vertical_centered(|ui| {
+                let month = PaymentInterval::Month;
+                View::radio_value(ui, &mut self.interval, month, t!("wallets.payment_monthly"));
+            });
+        });
+        ui.add_space(8.0);
+
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.payment_first_date"))
+                .size(17.0)
+                .color(Colors::gray()));
+            ui.add_space(8.0);
+            let mut date_edit = TextEdit::new(id.with("date")).h_center().focus(false);
+            date_edit.ui(ui, &mut self.date_edit, cb);
+
+            // Show error on incorrect values.
+            if self.payment_error {
+                ui.add_space(8.0);
+                ui.label(RichText::new(t!("wallets.payment_err"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            }
+            ui.add_space(12.0);
+        });
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                        Modal::close();
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    View::button(ui, t!("modal.save"), Colors::white_or_black(false), || {
+                        self.on_save(wallet);
+                    });
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+
+    /// Save entered recurring payment.
+    fn on_save(&mut self, wallet: &Wallet) {
+        let amount = amount_from_hr_string(self.amount_edit.trim().replace(",", ".").as_str());
+        let next_run = NaiveDate::parse_from_str(self.date_edit.trim(), DATE_FORMAT)
+            .ok()
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .and_then(|d| d.and_local_timezone(Local).single())
+            .map(|d| d.timestamp());
+        if let (Ok(amount), Some(next_run)) = (amount, next_run) {
+            let payment = RecurringPayment {
+                id: chrono::Utc::now().timestamp_millis(),
+                amount,
+                address: self.address_edit.trim().to_string(),
+                interval: self.interval.clone(),
+                next_run,
+                start: next_run,
+            };
+            // First payment should not be due already.
+            if payment.is_valid() && !payment.is_due(chrono::Utc::now().timestamp()) {
+                wallet.save_recurring_payment(payment);
+                Modal::close();
+                return;
+            }
+        }
+        self.payment_error = true;
+    }
+}
+
+/// Draw recurring payment item content.
+fn payment_item_ui(ui: &mut egui::Ui,
+                   payment: &RecurringPayment,
+                   index: usize,
+                   size: usize,
+                   on_delete: impl FnOnce()) {
+    // Setup layout size.
+    let mut rect = ui.available_rect_before_wrap();
+    rect.set_height(78.0);
+
+    // Draw round background.
+    let item_rounding = View::item_rounding(index, size, false);
+    ui.painter().rect(rect, item_rounding, Colors::fill(), View::item_stroke(), StrokeKind::Middle);
+
+    ui.allocate_ui_with_layout(rect.size(), Layout::right_to_left(Align::Center), |ui| {
+        // Draw button to delete payment.
+        View::item_button(ui, View::item_rounding(index, size, true), TRASH, Some(Colors::red()), || {
+            on_delete();
+        });
+
+        let layout_size = ui.available_size();
+        ui.allocate_ui_with_layout(layout_size, Layout::left_to_right(Align::Center), |ui| {
+            ui.add_space(6.0);
+            ui.vertical(|ui| {
+                ui.add_space(3.0);
+                let interval = match payment.interval {
+                    PaymentInterval::Day => t!("wallets.payment_daily"),
+                    PaymentInterval::Week => t!("wallets.payment_weekly"),
+                    PaymentInterval::Month => t!("wallets.payment_monthly")
+                };
+                let amount = format!("{} ツ {}", amount_to_hr_string(payment.amount, true), interval);
+                View::ellipsize_text(ui, amount, 18.0, Colors::white_or_black(true));
+                View::ellipsize_text(ui, payment.address.clone(), 15.0, Colors::text(false));
+                let next = format!("{} {}", CALENDAR, View::format_time(payment.next_run));
+                ui.label(RichText::new(next).size(15.0).color(Colors::gray()));
+                ui.add_space(3.0);
+            });
+        });
+    });
+}
@@ -16,15 +16,26 @@ use egui::{Id, RichText};
 use grin_chain::SyncStatus;
 use grin_util::ZeroingString;
 
-use crate::gui::icons::{EYE, KEY, LIFEBUOY, STETHOSCOPE, TRASH};
+use crate::gui::icons::{COPY, EYE, FLOPPY_DISK, KEY, LIFEBUOY, QR_CODE, STETHOSCOPE, TRASH, WARNING};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::types::ModalPosition;
 use crate::gui::views::wallets::wallet::types::WalletContentContainer;
-use crate::gui::views::{Modal, TextEdit, View};
+use crate::gui::views::{Modal, QrCodeContent, TextEdit, View};
 use crate::gui::Colors;
 use crate::node::Node;
-use crate::wallet::types::ConnectionMethod;
-use crate::wallet::Wallet;
+use crate::wallet::types::{BackupStatus, ConnectionMethod, WalletTask};
+use crate::wallet::{Wallet, WalletBackup, WalletUtils};
+
+/// Wallet secret to access with password at [`Modal`].
+#[derive(PartialEq)]
+enum RecoverySecret {
+    /// Recovery phrase.
+    Phrase,
+    /// View key to create watch-only wallet.
+    ViewKey,
+    /// Encrypted backup of wallet files.
+    Backup
+}
 
 /// Wallet recovery settings content.
 pub struct RecoverySettings {
@@ -32,9 +43,18 @@ pub struct RecoverySettings {
     pass_edit: String,
     /// Flag to check if wrong password was entered.
     wrong_pass: bool,
+    /// Backup creation error.
+    backup_error: Option<String>,
 
-    /// Recovery phrase value.
+    /// Secret to access at [`Modal`].
+    secret: RecoverySecret,
+    /// Recovery phrase or view key value.
     recovery_phrase: Option<ZeroingString>,
+
+    /// Flag to show warning before Compact or Standard SeedQR code.
+    seed_qr_warning: Option<bool>,
+    /// Recovery phrase SeedQR code content.
+    seed_qr_content: Option<QrCodeContent>,
 }
 
 /// Identifier for recovery phrase [`Modal`].
@@ -101,32 +121,67 @@ impl WalletContentContainer for RecoverySettings {
             View::horizontal_line(ui, Colors::item_stroke());
             ui.add_space(6.0);
 
-            // Draw button to restore the wallet.
-            ui.add_space(4.0);
-            View::colored_text_button(ui,
-                                      format!("{} {}", LIFEBUOY, t!("wallets.recover")),
-                                      Colors::green(),
-                                      Colors::white_or_black(false), || {
-                    wallet.delete_db(true);
+            // Watch-only wallet has no funds to restore.
+            if !wallet.is_watch_only() {
+                // Draw button to restore the wallet.
+                ui.add_space(4.0);
+                View::colored_text_button(ui,
+                                          format!("{} {}", LIFEBUOY, t!("wallets.recover")),
+                                          Colors::green(),
+                                          Colors::white_or_black(false), || {
+                        wallet.delete_db(true);
+                    });
+                ui.add_space(6.0);
+                ui.label(RichText::new(t!("wallets.restore_wallet_desc"))
+                    .size(16.0)
+                    .color(Colors::inactive_text()));
+
+                ui.add_space(6.0);
+                View::horizontal_line(ui, Colors::item_stroke());
+                ui.add_space(6.0);
+
+                let recovery_text = format!("{}:", t!("wallets.recovery_phrase"));
+                ui.label(RichText::new(recovery_text).size(16.0).color(Colors::gray()));
+                ui.add_space(6.0);
+
+                // Draw button to show recovery phrase.
+                let show_text = format!("{} {}", EYE, t!("show"));
+                View::button(ui, show_text, Colors::white_or_black(false), || {
+                    self.show_recovery_phrase_modal(RecoverySecret::Phrase);
                 });
+
+                ui.add_space(12.0);
+                View::horizontal_line(ui, Colors::item_stroke());
+                ui.add_space(6.0);
+            }
+
+            let view_key_text = format!("{}:", t!("wallets.view_key"));
+            ui.label(RichText::new(view_key_text).size(16.0).color(Colors::gray()));
+            ui.add_space(6.0);
+
+            // Draw button to show view key.
+            let show_text = format!("{} {}", EYE, t!("show"));
+            View::button(ui, show_text, Colors::white_or_black(false), || {
+                self.show_recovery_phrase_modal(RecoverySecret::ViewKey);
+            });
             ui.add_space(6.0);
-            ui.label(RichText::new(t!("wallets.restore_wallet_desc"))
+            ui.label(RichText::new(t!("wallets.view_key_desc"))
                 .size(16.0)
                 .color(Colors::inactive_text()));
 
-            ui.add_space(6.0);
+            ui.add_space(12.0);
             View::horizontal_line(ui, Colors::item_stroke());
             ui.add_space(6.0);
 
-            let recovery_text = format!("{}:", t!("wallets.recovery_phrase"));
-            ui.label(RichText::new(recovery_text).size(16.0).color(Colors::gray()));
-            ui.add_space(6.0);
-
-            // Draw button to show recovery phrase.
-            let show_text = format!("{} {}", EYE, t!("show"));
-            View::button(ui, show_text, Colors::white_or_black(false), || {
-                self.show_recovery_phrase_modal();
+            // Draw button to create wallet backup.
+            let backup_text = format!("{} {}", FLOPPY_DISK, t!("wallets.create_backup"));
+            View::button(ui, backup_text, Colors::white_or_black(false), || {
+                self.show_recovery_phrase_modal(RecoverySecret::Backup);
             });
+            ui.add_space(6.0);
+            ui.label(RichText::new(t!("wallets.backup_desc"))
+                .size(16.0)
+                .color(Colors::inactive_text()));
 
             ui.add_space(12.0);
             View::horizontal_line(ui, Colors::item_stroke());
@@ -153,23 +208,36 @@ impl Default for RecoverySettings {
     fn default() -> Self {
         Self {
             wrong_pass: false,
+            backup_error: None,
             pass_edit: "".to_string(),
+            secret: RecoverySecret::Phrase,
             recovery_phrase: None,
+            seed_qr_warning: None,
+            seed_qr_content: None,
         }
     }
 }
 
 impl RecoverySettings {
-    /// Show recovery phrase [`Modal`].
-    fn show_recovery_phrase_modal(&mut self) {
+    /// Show [`Modal`] to access provided wallet secret.
+    fn show_recovery_phrase_modal(&mut self, secret: RecoverySecret) {
         // Setup modal values.
         self.pass_edit = "".to_string();
         self.wrong_pass = false;
+        self.backup_error = None;
         self.recovery_phrase = None;
+        self.seed_qr_warning = None;
+        self.seed_qr_content = None;
         // Show recovery phrase modal.
+        let title = match secret {
+            RecoverySecret::Phrase => t!("wallets.recovery_phrase"),
+            RecoverySecret::ViewKey => t!("wallets.view_key"),
+            RecoverySecret::Backup => t!("wallets.backup"),
+        };
+        self.secret = secret;
         Modal::new(RECOVERY_PHRASE_MODAL)
             .position(ModalPosition::CenterTop)
-            .title(t!("wallets.recovery_phrase"))
+            .title(title)
             .show();
     }
 
@@ -180,7 +248,20 @@ impl RecoverySettings {
                                 modal: &Modal,
                                 cb: &dyn PlatformCallbacks) {
         let on_next = |c: &mut RecoverySettings| {
-            match wallet.get_recovery(c.pass_edit.clone()) {
+            let res = match c.secret {
+                RecoverySecret::Phrase => wallet.get_recovery(c.pass_edit.clone()),
+                RecoverySecret::ViewKey => {
+                    wallet.get_rewind_hash(c.pass_edit.clone()).map(ZeroingString::from)
+                }
+                RecoverySecret::Backup => {
+                    c.wrong_pass = false;
+                    c.backup_error = None;
+                    let password = ZeroingString::from(c.pass_edit.clone());
+                    let _ = wallet.task(WalletTask::CreateBackup(password));
+                    return;
+                }
+            };
+            match res {
                 Ok(phrase) => {
                     c.wrong_pass = false;
                     c.recovery_phrase = Some(phrase);
@@ -192,19 +273,97 @@ impl RecoverySettings {
         };
 
         ui.add_space(6.0);
-        if self.recovery_phrase.is_some() {
+        if self.secret == RecoverySecret::Backup {
+            match wallet.backup_status() {
+                Some(BackupStatus::Creating) => {
+                    ui.vertical_centered(|ui| {
+                        View::small_loading_spinner(ui);
+                        ui.add_space(8.0);
+                        ui.label(RichText::new(t!("wallets.backup_creating"))
+                            .size(17.0)
+                            .color(Colors::text(false)));
+                    });
+                    ui.add_space(12.0);
+                    return;
+                }
+                Some(BackupStatus::Created(data)) => {
+                    // Share backup file and close the modal.
+                    wallet.clear_backup_status();
+                    let name = format!("{}.{}",
+                                       wallet.get_config().name,
+                                       WalletBackup::FILE_EXTENSION);
+                    cb.share_data(name, data.into_bytes()).unwrap_or_default();
+                    Modal::close();
+                    return;
+                }
+                Some(BackupStatus::WrongPassword) => {
+                    wallet.clear_backup_status();
+                    self.wrong_pass = true;
+                }
+                Some(BackupStatus::Failed(e)) => {
+                    wallet.clear_backup_status();
+                    self.backup_error = Some(e);
+                }
+                None => {}
+            }
+        }
+        if self.seed_qr_content.is_some() || self.seed_qr_warning.is_some() {
+            self.seed_qr_ui(ui, cb);
+        } else if self.recovery_phrase.is_some() {
             ui.vertical_centered(|ui| {
                 ui.label(RichText::new(self.recovery_phrase.clone().unwrap().to_string())
                     .size(17.0)
                     .color(Colors::white_or_black(true)));
             });
             ui.add_space(10.0);
-            ui.vertical_centered_justified(|ui| {
-                View::button(ui, t!("close"), Colors::white_or_black(false), || {
-                    self.recovery_phrase = None;
-                    Modal::close();
+            if self.secret == RecoverySecret::ViewKey {
+                // Setup spacing between buttons.
+                ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+                ui.columns(2, |columns| {
+                    columns[0].vertical_centered_justified(|ui| {
+                        View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                            self.recovery_phrase = None;
+                            Modal::close();
+                        });
+                    });
+                    columns[1].vertical_centered_justified(|ui| {
+                        let copy_text = format!("{} {}", COPY, t!("copy"));
+                        View::button(ui, copy_text, Colors::white_or_black(false), || {
+                            let key = self.recovery_phrase.clone().unwrap().to_string();
+                            cb.copy_string_to_buffer(key);
+                        });
+                    });
                 });
-            });
+            } else {
+                // Setup spacing between buttons.
+                ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+                // Show buttons to show phrase as SeedQR code for supported words amount.
+                if WalletUtils::is_seed_qr_supported(self.recovery_phrase.as_ref().unwrap()) {
+                    ui.columns(2, |columns| {
+                        columns[0].vertical_centered_justified(|ui| {
+                            let text = format!("{} {}", QR_CODE, t!("wallets.seed_qr"));
+                            View::button(ui, text, Colors::white_or_black(false), || {
+                                self.seed_qr_warning = Some(false);
+                            });
+                        });
+                        columns[1].vertical_centered_justified(|ui| {
+                            let text = format!("{} {}", QR_CODE, t!("wallets.compact_seed_qr"));
+                            View::button(ui, text, Colors::white_or_black(false), || {
+                                self.seed_qr_warning = Some(true);
+                            });
+                        });
+                    });
+                    ui.add_space(8.0);
+                }
+                ui.vertical_centered_justified(|ui| {
+                    View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                        self.recovery_phrase = None;
+                        Modal::close();
+                    });
+                });
+            }
         } else {
             ui.vertical_centered(|ui| {
                 ui.label(RichText::new(t!("wallets.pass"))
@@ -232,6 +391,11 @@ impl RecoverySettings {
                     ui.label(RichText::new(t!("wallets.wrong_pass"))
                         .size(17.0)
                         .color(Colors::red()));
+                } else if let Some(err) = &self.backup_error {
+                    ui.add_space(12.0);
+                    ui.label(RichText::new(format!("{}: {}", t!("error"), err))
+                        .size(17.0)
+                        .color(Colors::red()));
                 }
             });
             ui.add_space(12.0);
@@ -259,6 +423,55 @@ impl RecoverySettings {
         ui.add_space(6.0);
     }
 
+    /// Draw recovery phrase SeedQR code content with warning before showing.
+    fn seed_qr_ui(&mut self, ui: &mut egui::Ui, cb: &dyn PlatformCallbacks) {
+        if let Some(content) = self.seed_qr_content.as_mut() {
+            content.ui(ui, cb);
+            ui.vertical_centered_justified(|ui| {
+                View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                    self.seed_qr_content = None;
+                });
+            });
+            return;
+        }
+
+        // Show warning.
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(format!("{} {}", WARNING, t!("wallets.seed_qr_warning")))
+                .size(17.0)
+                .color(Colors::red()));
+        });
+        ui.add_space(12.0);
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                    self.seed_qr_warning = None;
+                });
+            });
+            columns[1].vertical_centered_justified(|ui| {
+                View::button(ui, t!("show"), Colors::white_or_black(false), || {
+                    let compact = self.seed_qr_warning.take().unwrap_or(false);
+                    let phrase = self.recovery_phrase.clone().unwrap_or(ZeroingString::from(""));
+                    self.seed_qr_content = if compact {
+                        WalletUtils::compact_seed_qr(&phrase).map(|data| {
+                            QrCodeContent::new("".to_string(), false)
+                                .with_binary(data)
+                                .secret()
+                        })
+                    } else {
+                        WalletUtils::standard_seed_qr(&phrase).map(|digits| {
+                            QrCodeContent::new(digits, false).secret()
+                        })
+                    };
+                });
+            });
+        });
+    }
+
     /// Draw wallet deletion [`Modal`] content.
     fn deletion_modal_ui(&mut self,
                          ui: &mut egui::Ui,
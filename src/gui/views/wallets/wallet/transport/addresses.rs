@@ -0,0 +1,137 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::{Align, CornerRadius, Id, Layout, RichText, StrokeKind};
+
+use crate::gui::icons::{AT, COPY, PLUS_CIRCLE, TRASH};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{TextEdit, View};
+use crate::gui::Colors;
+use crate::tor::Tor;
+use crate::wallet::{ReceiveAddress, Wallet};
+
+/// Additional receive addresses setup content.
+pub struct WalletAddressesContent {
+    /// New address label value.
+    label_edit: String,
+    /// Flag to check if address creation failed.
+    add_error: bool,
+}
+
+impl Default for WalletAddressesContent {
+    fn default() -> Self {
+        Self {
+            label_edit: "".to_string(),
+            add_error: false,
+        }
+    }
+}
+
+impl WalletAddressesContent {
+    /// Draw content.
+    pub fn ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(format!("{} {}", AT, t!("transport.receive_addresses")))
+                .size(16.0)
+                .color(Colors::gray()));
+            ui.add_space(4.0);
+            ui.label(RichText::new(t!("transport.receive_addresses_desc"))
+                .size(16.0)
+                .color(Colors::inactive_text()));
+        });
+        ui.add_space(8.0);
+
+        // Show list of addresses.
+        let addresses = wallet.receive_addresses();
+        for (index, a) in addresses.iter().enumerate() {
+            let service_id = wallet.receive_service_id(a.index);
+            address_item_ui(ui, a, &service_id, index, addresses.len(), cb, || {
+                wallet.delete_receive_address(a.index);
+            });
+        }
+        if !addresses.is_empty() {
+            ui.add_space(8.0);
+        }
+
+        // Show new address label input.
+        let id = Id::from("receive_address_label").with(wallet.get_config().id);
+        let mut label_edit = TextEdit::new(id).focus(false);
+        label_edit.ui(ui, &mut self.label_edit, cb);
+        if self.add_error {
+            ui.add_space(8.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("transport.receive_address_err"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            });
+        }
+        ui.add_space(8.0);
+        ui.vertical_centered_justified(|ui| {
+            let add_text = format!("{} {}", PLUS_CIRCLE, t!("transport.add_address"));
+            View::button(ui, add_text, Colors::white_or_black(false), || {
+                let label = self.label_edit.trim().to_string();
+                self.add_error = label.is_empty() || wallet.add_receive_address(label).is_err();
+                if !self.add_error {
+                    self.label_edit = "".to_string();
+                }
+            });
+        });
+    }
+}
+
+/// Draw additional receive address item content.
+fn address_item_ui(ui: &mut egui::Ui,
+                   address: &ReceiveAddress,
+                   service_id: &String,
+                   index: usize,
+                   size: usize,
+                   cb: &dyn PlatformCallbacks,
+                   on_delete: impl FnOnce()) {
+    // Setup layout size.
+    let mut rect = ui.available_rect_before_wrap();
+    rect.set_height(56.0);
+
+    // Draw round background.
+    let item_rounding = View::item_rounding(index, size, false);
+    ui.painter().rect(rect, item_rounding, Colors::fill(), View::item_stroke(), StrokeKind::Middle);
+
+    ui.allocate_ui_with_layout(rect.size(), Layout::right_to_left(Align::Center), |ui| {
+        // Draw button to delete address.
+        View::item_button(ui, View::item_rounding(index, size, true), TRASH, Some(Colors::red()), || {
+            on_delete();
+        });
+        // Draw button to copy address.
+        View::item_button(ui, CornerRadius::default(), COPY, None, || {
+            cb.copy_string_to_buffer(address.address.clone());
+        });
+
+        let layout_size = ui.available_size();
+        ui.allocate_ui_with_layout(layout_size, Layout::left_to_right(Align::Center), |ui| {
+            ui.add_space(6.0);
+            ui.vertical(|ui| {
+                ui.add_space(3.0);
+                View::ellipsize_text(ui, address.label.clone(), 17.0, Colors::white_or_black(true));
+                let color = if Tor::is_service_running(service_id) {
+                    Colors::green()
+                } else if Tor::is_service_failed(service_id) {
+                    Colors::red()
+                } else {
+                    Colors::inactive_text()
+                };
+                View::ellipsize_text(ui, address.address.clone(), 15.0, color);
+                ui.add_space(3.0);
+            });
+        });
+    });
+}
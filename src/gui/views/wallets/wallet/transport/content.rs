@@ -134,11 +134,13 @@ impl WalletTransportContent {
                                 let api_port = wallet.foreign_api_port().unwrap();
                                 Tor::start_service(api_port, key, service_id);
                             }
+                            wallet.start_receive_services();
                         });
                     } else {
                         let r = CornerRadius::default();
                         View::item_button(ui, r, POWER, Some(Colors::red()), || {
                             Tor::stop_service(service_id);
+                            wallet.stop_receive_services();
                         });
                     }
                 }
@@ -15,4 +15,5 @@
 mod content;
 pub use content::*;
 
-mod settings;
\ No newline at end of file
+mod settings;
+mod addresses;
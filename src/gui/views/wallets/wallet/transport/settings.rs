@@ -18,6 +18,7 @@ use egui::RichText;
 use crate::gui::Colors;
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::settings::TorSettingsContent;
+use crate::gui::views::wallets::wallet::transport::addresses::WalletAddressesContent;
 use crate::gui::views::types::ContentContainer;
 use crate::gui::views::View;
 use crate::tor::Tor;
@@ -30,6 +31,8 @@ pub struct WalletTransportSettingsContent {
 
     /// Tor transport content settings.
     tor_settings_content: TorSettingsContent,
+    /// Additional receive addresses content.
+    addresses_content: WalletAddressesContent,
 }
 
 impl Default for WalletTransportSettingsContent {
@@ -37,6 +40,7 @@ impl Default for WalletTransportSettingsContent {
         Self {
             settings_changed: false,
             tor_settings_content: TorSettingsContent::default(),
+            addresses_content: WalletAddressesContent::default(),
         }
     }
 }
@@ -71,7 +75,12 @@ impl WalletTransportSettingsContent {
                 wallet.update_auto_start_tor_listener(!autorun);
                 self.settings_changed = true;
             });
+            ui.add_space(4.0);
+            View::horizontal_line(ui, Colors::item_stroke());
+            ui.add_space(8.0);
         });
+        // Show additional receive addresses.
+        self.addresses_content.ui(ui, wallet, cb);
         ui.add_space(8.0);
         ui.vertical_centered_justified(|ui| {
             View::button(ui, t!("close"), Colors::white_or_black(false), || {
@@ -84,6 +93,7 @@ impl WalletTransportSettingsContent {
                             let api_port = wallet.foreign_api_port().unwrap();
                             Tor::restart_service(api_port, key, service_id);
                         }
+                        wallet.restart_receive_services();
                     } else {
                         Tor::rebuild_client();
                     }
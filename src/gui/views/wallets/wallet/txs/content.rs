@@ -21,15 +21,15 @@ use grin_wallet_libwallet::TxLogEntryType;
 use std::ops::Range;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::gui::icons::{ARCHIVE_BOX, ARROWS_CLOCKWISE, ARROW_CIRCLE_DOWN, ARROW_CIRCLE_UP, CALENDAR_CHECK, DOTS_THREE_CIRCLE, FILE_ARROW_DOWN, FILE_TEXT, GEAR_FINE, PROHIBIT, WARNING, X_CIRCLE};
+use crate::gui::icons::{ARCHIVE_BOX, ARROWS_CLOCKWISE, AT, ARROW_CIRCLE_DOWN, ARROW_CIRCLE_UP, CALENDAR_CHECK, DOTS_THREE_CIRCLE, EXPORT, FILE_ARROW_DOWN, FILE_CSV, FILE_TEXT, FUNNEL, GEAR_FINE, MAGNIFYING_GLASS, PROHIBIT, RECEIPT, SEAL_CHECK, STACK, TAG, WARNING, X_CIRCLE};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::types::{LinePosition, ModalPosition};
 use crate::gui::views::wallets::types::WalletTab;
 use crate::gui::views::wallets::wallet::types::{WalletTabType, GRIN};
-use crate::gui::views::wallets::wallet::WalletTransactionContent;
-use crate::gui::views::{Content, Modal, PullToRefresh, View};
+use crate::gui::views::wallets::wallet::{output_item_ui, PaymentProofContent, WalletInvoicesContent, WalletOutputsContent, WalletTransactionContent};
+use crate::gui::views::{Content, Modal, PullToRefresh, TextEdit, View};
 use crate::gui::Colors;
-use crate::wallet::types::{WalletData, WalletTask, WalletTransaction, WalletTransactionAction};
+use crate::wallet::types::{TxExportFormat, WalletData, WalletTask, WalletTransaction, WalletTransactionAction, WalletTxDirection, WalletTxFilter, WalletTxStatus};
 use crate::wallet::Wallet;
 
 /// Wallet transactions tab content.
@@ -41,7 +41,22 @@ pub struct WalletTransactions {
     confirm_cancel_tx_id: Option<u32>,
 
     /// Flag to check if sync of wallet was initiated manually at time.
-    manual_sync: Option<u128>
+    manual_sync: Option<u128>,
+
+    /// Transactions filter values to edit at [`Modal`].
+    filter_edit: Option<WalletTxFilter>,
+
+    /// Transaction history export error.
+    export_error: Option<String>,
+
+    /// Payment proof verification [`Modal`] content.
+    proof_content: PaymentProofContent,
+
+    /// Wallet outputs [`Modal`] content.
+    outputs_content: WalletOutputsContent,
+
+    /// Issued invoices [`Modal`] content.
+    invoices_content: WalletInvoicesContent,
 }
 
 impl WalletTab for WalletTransactions {
@@ -54,13 +69,19 @@ impl WalletTab for WalletTransactions {
             // Show transaction modal on task result.
             if let Some(id) = wallet.consume_tx_task_result() {
                 let tx = wallet.get_data().unwrap().tx_by_slate_id(id);
+                let contact_prompt = wallet.consume_contact_prompt();
                 if let Some(tx) = tx {
                     self.show_tx_info_modal(tx.data.id);
+                    // Offer to save new receiver at address book.
+                    if let Some(address) = contact_prompt {
+                        let content = self.tx_info_content.take().unwrap();
+                        self.tx_info_content = Some(content.with_contact_prompt(address));
+                    }
                 }
             }
         }
         self.modal_content_ui(ui, wallet, cb);
-        self.txs_ui(ui, wallet);
+        self.txs_ui(ui, wallet, cb);
     }
 }
 
@@ -68,6 +89,16 @@ impl WalletTab for WalletTransactions {
 const TX_INFO_MODAL: &'static str = "tx_info_modal";
 /// Identifier for transaction cancellation confirmation [`Modal`].
 const CANCEL_TX_CONFIRMATION_MODAL: &'static str = "cancel_tx_conf_modal";
+/// Identifier for transaction history export [`Modal`].
+const EXPORT_TXS_MODAL: &'static str = "export_txs_modal";
+/// Identifier for transactions filter [`Modal`].
+const FILTER_TXS_MODAL: &'static str = "filter_txs_modal";
+/// Identifier for payment proof verification [`Modal`].
+const VERIFY_PROOF_MODAL: &'static str = "verify_proof_modal";
+/// Identifier for wallet outputs [`Modal`].
+const OUTPUTS_MODAL: &'static str = "wallet_outputs_modal";
+/// Identifier for issued invoices [`Modal`].
+const INVOICES_MODAL: &'static str = "wallet_invoices_modal";
 
 impl WalletTransactions {
     /// Height of transaction list item.
@@ -79,6 +110,11 @@ impl WalletTransactions {
             tx_info_content: None,
             confirm_cancel_tx_id: None,
             manual_sync: None,
+            filter_edit: None,
+            export_error: None,
+            proof_content: PaymentProofContent::default(),
+            outputs_content: WalletOutputsContent::default(),
+            invoices_content: WalletInvoicesContent::default(),
         };
         if let Some(tx) = &tx {
             content.show_tx_info_modal(tx.data.id);
@@ -87,7 +123,7 @@ impl WalletTransactions {
     }
 
     /// Draw transactions content.
-    fn txs_ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet) {
+    fn txs_ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
         let data = wallet.get_data().unwrap();
         let config = wallet.get_config();
         if data.txs.is_none() {
@@ -96,10 +132,15 @@ impl WalletTransactions {
             });
             return;
         }
-        let txs = data.txs.as_ref().unwrap();
+        if wallet.is_watch_only() {
+            self.watch_only_ui(ui, wallet, &data);
+            return;
+        }
+        let all_txs = data.txs.as_ref().unwrap();
+        let mut filter = wallet.txs_filter();
         View::max_width_ui(ui, Content::SIDE_PANEL_WIDTH * 1.3, |ui| {
             // Show message when txs are empty.
-            if txs.is_empty() {
+            if all_txs.is_empty() {
                 View::center_content(ui, 96.0, |ui| {
                     let empty_text = t!(
                             "wallets.txs_empty",
@@ -115,9 +156,107 @@ impl WalletTransactions {
             }
             // Draw awaiting amount info if exists.
             self.awaiting_info_ui(ui, &data);
+
+            // Draw buttons to export, filter transaction history, verify payment proof,
+            // show outputs and invoices.
+            ui.add_space(6.0);
+            ui.scope(|ui| {
+                // Setup spacing between buttons.
+                ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+                ui.columns(2, |columns| {
+                    columns[0].vertical_centered_justified(|ui| {
+                        let export_text = format!("{} {}", EXPORT, t!("wallets.export_txs"));
+                        View::button(ui, export_text, Colors::fill_lite(), || {
+                            self.export_error = None;
+                            Modal::new(EXPORT_TXS_MODAL)
+                                .position(ModalPosition::Center)
+                                .title(t!("wallets.txs"))
+                                .show();
+                        });
+                    });
+                    columns[1].vertical_centered_justified(|ui| {
+                        let count = filter.count();
+                        let filter_text = if count != 0 {
+                            format!("{} {} ({})", FUNNEL, t!("wallets.tx_filter"), count)
+                        } else {
+                            format!("{} {}", FUNNEL, t!("wallets.tx_filter"))
+                        };
+                        View::button(ui, filter_text, Colors::fill_lite(), || {
+                            self.filter_edit = Some(filter.clone());
+                            Modal::new(FILTER_TXS_MODAL)
+                                .position(ModalPosition::Center)
+                                .title(t!("wallets.tx_filter"))
+                                .show();
+                        });
+                    });
+                });
+                ui.add_space(8.0);
+                ui.columns(2, |columns| {
+                    columns[0].vertical_centered_justified(|ui| {
+                        let proof_text = format!("{} {}", SEAL_CHECK, t!("wallets.verify_proof"));
+                        View::button(ui, proof_text, Colors::fill_lite(), || {
+                            wallet.clear_proof_status();
+                            Modal::new(VERIFY_PROOF_MODAL)
+                                .position(ModalPosition::Center)
+                                .title(t!("wallets.payment_proof"))
+                                .show();
+                        });
+                    });
+                    columns[1].vertical_centered_justified(|ui| {
+                        let outputs_text = format!("{} {}", STACK, t!("wallets.outputs"));
+                        View::button(ui, outputs_text, Colors::fill_lite(), || {
+                            self.outputs_content.load(wallet);
+                            Modal::new(OUTPUTS_MODAL)
+                                .position(ModalPosition::CenterTop)
+                                .title(t!("wallets.outputs"))
+                                .show();
+                        });
+                    });
+                });
+                ui.add_space(8.0);
+                ui.vertical_centered_justified(|ui| {
+                    let invoices_text = format!("{} {}", RECEIPT, t!("wallets.invoices"));
+                    View::button(ui, invoices_text, Colors::fill_lite(), || {
+                        Modal::new(INVOICES_MODAL)
+                            .position(ModalPosition::CenterTop)
+                            .title(t!("wallets.invoices"))
+                            .show();
+                    });
+                });
+            });
+
+            // Draw search input.
+            ui.add_space(6.0);
+            ui.label(RichText::new(format!("{} {}", MAGNIFYING_GLASS, t!("wallets.tx_search")))
+                .size(16.0)
+                .color(Colors::gray()));
+            ui.add_space(4.0);
+            let search_id = Id::from("wallet_tx_search").with(config.id);
+            let mut search_edit = TextEdit::new(search_id).focus(false);
+            let mut search = filter.search.clone();
+            search_edit.ui(ui, &mut search, cb);
+            if search != filter.search {
+                filter.search = search;
+                wallet.set_txs_filter(filter.clone());
+            }
         });
+        let txs = &all_txs.iter()
+            .filter(|tx| filter.matches(tx))
+            .cloned()
+            .collect::<Vec<WalletTransaction>>();
         ui.add_space(4.0);
 
+        // Show message when filtered txs are empty.
+        if !all_txs.is_empty() && txs.is_empty() {
+            ui.add_space(6.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.txs_not_found"))
+                    .size(16.0)
+                    .color(Colors::inactive_text()));
+            });
+        }
+
         // Show list of transactions.
         let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
         let refresh = self.manual_sync.unwrap_or(0) + 1600 > now;
@@ -146,6 +285,46 @@ impl WalletTransactions {
         }
     }
 
+    /// Draw received outputs of watch-only wallet.
+    fn watch_only_ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, data: &WalletData) {
+        let outputs = wallet.outputs().unwrap_or_default();
+        View::max_width_ui(ui, Content::SIDE_PANEL_WIDTH * 1.3, |ui| {
+            // Draw awaiting amount info if exists.
+            self.awaiting_info_ui(ui, data);
+            ui.add_space(6.0);
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.watch_only_desc"))
+                    .size(16.0)
+                    .color(Colors::inactive_text()));
+            });
+            ui.add_space(6.0);
+            if outputs.is_empty() {
+                ui.vertical_centered(|ui| {
+                    ui.label(RichText::new(t!("wallets.outputs_empty"))
+                        .size(16.0)
+                        .color(Colors::inactive_text()));
+                });
+            }
+        });
+        ui.add_space(4.0);
+
+        // Show list of outputs.
+        let size = outputs.len();
+        ScrollArea::vertical()
+            .id_salt(Id::from("wallet_watch_outputs_scroll").with(wallet.get_config().id))
+            .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+            .auto_shrink([false; 2])
+            .show_rows(ui, WalletOutputsContent::OUTPUT_ITEM_HEIGHT, size, |ui, row_range| {
+                ui.add_space(1.0);
+                View::max_width_ui(ui, Content::SIDE_PANEL_WIDTH * 1.3, |ui| {
+                    for index in row_range {
+                        let output = outputs.get(index).unwrap();
+                        output_item_ui(ui, output, index, size, None, || {});
+                    }
+                });
+            });
+    }
+
     /// Draw transaction list content.
     fn tx_list_ui(&mut self,
                   ui: &mut egui::Ui,
@@ -262,6 +441,37 @@ impl WalletTransactions {
                             self.cancel_confirmation_modal(ui, wallet);
                         });
                     }
+                    EXPORT_TXS_MODAL => {
+                        Modal::ui(ui.ctx(), cb, |ui, _, cb| {
+                            self.export_modal_ui(ui, wallet, cb);
+                        });
+                    }
+                    VERIFY_PROOF_MODAL => {
+                        Modal::ui(ui.ctx(), cb, |ui, _, cb| {
+                            self.proof_content.ui(ui, wallet, cb);
+                        });
+                    }
+                    FILTER_TXS_MODAL => {
+                        Modal::ui(ui.ctx(), cb, |ui, modal, cb| {
+                            self.filter_modal_ui(ui, wallet, modal, cb);
+                        });
+                    }
+                    OUTPUTS_MODAL => {
+                        Modal::ui(ui.ctx(), cb, |ui, modal, cb| {
+                            self.outputs_content.ui(ui, wallet, modal, cb);
+                        });
+                    }
+                    INVOICES_MODAL => {
+                        let mut selected_tx = None;
+                        Modal::ui(ui.ctx(), cb, |ui, _, _| {
+                            selected_tx = self.invoices_content.ui(ui, wallet);
+                        });
+                        // Show transaction of selected invoice.
+                        if let Some(id) = selected_tx {
+                            Modal::close();
+                            self.show_tx_info_modal(id);
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -465,7 +675,17 @@ impl WalletTransactions {
 
                     // Setup transaction time.
                     let tx_time = View::format_time(tx.data.creation_ts.timestamp());
-                    let tx_time_text = format!("{} {}", CALENDAR_CHECK, tx_time);
+                    let mut tx_time_text = format!("{} {}", CALENDAR_CHECK, tx_time);
+                    // Show note label.
+                    if let Some(label) = tx.note.as_ref().map(|n| &n.label) {
+                        if !label.is_empty() {
+                            tx_time_text = format!("{}  {} {}", tx_time_text, TAG, label);
+                        }
+                    }
+                    // Show additional address label.
+                    if let Some(addr) = &tx.receive_address {
+                        tx_time_text = format!("{}  {} {}", tx_time_text, AT, addr.label);
+                    }
                     ui.label(RichText::new(tx_time_text).size(15.0).color(Colors::gray()));
                     ui.add_space(3.0);
                 });
@@ -482,21 +702,21 @@ impl WalletTransactions {
         let (icon, color) = (ARROWS_CLOCKWISE, Some(Colors::green()));
         View::item_button(ui, rounding, icon, color, || {
             if repost {
-                wallet.task(WalletTask::Post(None, tx.data.id));
+                let _ = wallet.task(WalletTask::Post(None, tx.data.id));
             } else {
                 match tx.action.as_ref().unwrap() {
                     WalletTransactionAction::Cancelling => {
-                        wallet.task(WalletTask::Cancel(tx.clone()));
+                        let _ = wallet.task(WalletTask::Cancel(tx.clone()));
                     }
                     WalletTransactionAction::Finalizing => {
-                        wallet.task(WalletTask::Finalize(None, tx.data.id));
+                        let _ = wallet.task(WalletTask::Finalize(None, tx.data.id));
                     }
                     WalletTransactionAction::Posting => {
-                        wallet.task(WalletTask::Post(None, tx.data.id));
+                        let _ = wallet.task(WalletTask::Post(None, tx.data.id));
                     }
                     WalletTransactionAction::SendingTor => {
                         if let Some(a) = &tx.receiver {
-                            wallet.task(WalletTask::SendTor(tx.data.id, a.clone()));
+                            let _ = wallet.task(WalletTask::SendTor(tx.data.id, a.clone()));
                         }
                     }
                 }
@@ -559,7 +779,7 @@ impl WalletTransactions {
                 });
                 columns[1].vertical_centered_justified(|ui| {
                     View::button(ui, "OK".to_string(), Colors::white_or_black(false), || {
-                        wallet.task(WalletTask::Cancel(tx.clone()));
+                        let _ = wallet.task(WalletTask::Cancel(tx.clone()));
                         self.confirm_cancel_tx_id = None;
                         Modal::close();
                     });
@@ -568,6 +788,204 @@ impl WalletTransactions {
             ui.add_space(6.0);
         });
     }
+
+    /// Draw transaction history export [`Modal`] content.
+    fn export_modal_ui(&mut self,
+                       ui: &mut egui::Ui,
+                       wallet: &Wallet,
+                       cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            if let Some(err) = &self.export_error {
+                ui.label(RichText::new(format!("{}: {}", t!("error"), err))
+                    .size(17.0)
+                    .color(Colors::red()));
+            } else {
+                ui.label(RichText::new(t!("wallets.export_txs_desc"))
+                    .size(17.0)
+                    .color(Colors::text(false)));
+            }
+            ui.add_space(8.0);
+        });
+
+        // Export history to the file in provided format.
+        let mut export = |format: TxExportFormat| {
+            let res = wallet.export_txs(&format).map_err(|e| e.to_string()).and_then(|data| {
+                let config = wallet.get_config();
+                let name = format!("{}-{}-txs.{}", config.name, config.account, format.extension());
+                cb.share_data(name, data).map_err(|e| e.to_string())
+            });
+            match res {
+                Ok(_) => Modal::close(),
+                Err(e) => self.export_error = Some(e)
+            }
+        };
+
+        // Show modal buttons.
+        ui.scope(|ui| {
+            // Setup spacing between buttons.
+            ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+            ui.columns(2, |columns| {
+                columns[0].vertical_centered_justified(|ui| {
+                    let csv_text = format!("{} CSV", FILE_CSV);
+                    View::button(ui, csv_text, Colors::white_or_black(false), || {
+                        export(TxExportFormat::Csv);
+                    });
+                });
+                columns[1].vertical_centered_justified(|ui| {
+                    let json_text = format!("{} JSON", FILE_TEXT);
+                    View::button(ui, json_text, Colors::white_or_black(false), || {
+                        export(TxExportFormat::Json);
+                    });
+                });
+            });
+            ui.add_space(8.0);
+            ui.vertical_centered_justified(|ui| {
+                View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                    Modal::close();
+                });
+            });
+            ui.add_space(6.0);
+        });
+    }
+
+    /// Draw transactions filter [`Modal`] content.
+    fn filter_modal_ui(&mut self,
+                       ui: &mut egui::Ui,
+                       wallet: &Wallet,
+                       modal: &Modal,
+                       cb: &dyn PlatformCallbacks) {
+        let filter = match self.filter_edit.as_mut() {
+            Some(f) => f,
+            None => return
+        };
+        let id = Id::from(modal.id).with(wallet.get_config().id);
+        ui.add_space(6.0);
+
+        // Show direction selection.
+        filter_label_ui(ui, t!("wallets.tx_direction"));
+        ui.columns(3, |columns| {
+            columns[0].vertical_centered(|ui| {
+                View::radio_value(ui, &mut filter.direction, None, t!("wallets.tx_all"));
+            });
+            columns[1].vertical_centered(|ui| {
+                let sent = Some(WalletTxDirection::Sent);
+                View::radio_value(ui, &mut filter.direction, sent, t!("wallets.tx_sent"));
+            });
+            columns[2].vertical_centered(|ui| {
+                let received = Some(WalletTxDirection::Received);
+                View::radio_value(ui, &mut filter.direction, received, t!("wallets.tx_received"));
+            });
+        });
+        ui.add_space(10.0);
+
+        // Show status selection.
+        filter_label_ui(ui, t!("wallets.tx_status"));
+        ui.columns(3, |columns| {
+            columns[0].vertical_centered(|ui| {
+                View::radio_value(ui, &mut filter.status, None, t!("wallets.tx_all"));
+            });
+            columns[1].vertical_centered(|ui| {
+                let confirmed = Some(WalletTxStatus::Confirmed);
+                View::radio_value(ui, &mut filter.status, confirmed, t!("wallets.tx_confirmed"));
+            });
+            columns[2].vertical_centered(|ui| {
+                let cancelled = Some(WalletTxStatus::Cancelled);
+                View::radio_value(ui, &mut filter.status, cancelled, t!("wallets.tx_canceled"));
+            });
+        });
+        ui.add_space(6.0);
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered(|ui| {
+                let awaiting = Some(WalletTxStatus::AwaitingFinalization);
+                let text = t!("wallets.await_fin_amount");
+                View::radio_value(ui, &mut filter.status, awaiting, text);
+            });
+            columns[1].vertical_centered(|ui| {
+                let broadcasting = Some(WalletTxStatus::Broadcasting);
+                let text = t!("wallets.tx_broadcasting");
+                View::radio_value(ui, &mut filter.status, broadcasting, text);
+            });
+        });
+        ui.add_space(10.0);
+
+        // Setup spacing between inputs and buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        // Show date range inputs.
+        filter_label_ui(ui, t!("wallets.tx_date_range"));
+        ui.columns(2, |columns| {
+            let mut from_edit = TextEdit::new(id.with("date_from")).h_center().focus(false);
+            from_edit.ui(&mut columns[0], &mut filter.date_from, cb);
+            let mut to_edit = TextEdit::new(id.with("date_to")).h_center().focus(false);
+            to_edit.ui(&mut columns[1], &mut filter.date_to, cb);
+        });
+        ui.add_space(10.0);
+
+        // Show amount range inputs.
+        filter_label_ui(ui, t!("wallets.tx_amount_range"));
+        ui.columns(2, |columns| {
+            let mut from_edit = TextEdit::new(id.with("amount_from"))
+                .h_center()
+                .numeric()
+                .focus(false);
+            from_edit.ui(&mut columns[0], &mut filter.amount_from, cb);
+            let mut to_edit = TextEdit::new(id.with("amount_to"))
+                .h_center()
+                .numeric()
+                .focus(false);
+            to_edit.ui(&mut columns[1], &mut filter.amount_to, cb);
+        });
+        ui.add_space(8.0);
+
+        // Show error on incorrect range values.
+        let valid = filter.is_valid();
+        if !valid {
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.tx_filter_err"))
+                    .size(16.0)
+                    .color(Colors::red()));
+            });
+            ui.add_space(8.0);
+        }
+        ui.add_space(4.0);
+
+        // Show modal buttons.
+        let mut apply = None;
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                View::button(ui, t!("wallets.tx_filter_reset"), Colors::white_or_black(false), || {
+                    let mut reset = WalletTxFilter::default();
+                    reset.search = filter.search.clone();
+                    apply = Some(reset);
+                });
+            });
+            columns[1].vertical_centered_justified(|ui| {
+                View::button(ui, t!("modal.save"), Colors::white_or_black(false), || {
+                    if valid {
+                        apply = Some(filter.clone());
+                    }
+                });
+            });
+        });
+        ui.add_space(6.0);
+        if let Some(f) = apply {
+            wallet.set_txs_filter(f);
+            self.filter_edit = None;
+            Modal::close();
+        }
+    }
+}
+
+/// Draw transactions filter section label.
+fn filter_label_ui(ui: &mut egui::Ui, text: String) {
+    ui.vertical_centered(|ui| {
+        ui.label(RichText::new(text)
+            .size(16.0)
+            .color(Colors::gray()));
+    });
+    ui.add_space(6.0);
 }
 
 /// Draw awaiting balance item content.
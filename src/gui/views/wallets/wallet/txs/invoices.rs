@@ -0,0 +1,159 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::scroll_area::ScrollBarVisibility;
+use egui::{Align, Id, Layout, RichText, ScrollArea, StrokeKind};
+use grin_core::core::amount_to_hr_string;
+
+use crate::gui::icons::{CALENDAR_X, FILE_TEXT};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, View};
+use crate::gui::Colors;
+use crate::wallet::types::WalletTransaction;
+use crate::wallet::{InvoiceStatus, Wallet, WalletInvoice};
+
+/// Issued invoices [`Modal`] content.
+pub struct WalletInvoicesContent {
+    /// Status of invoices to show.
+    status: InvoiceStatus,
+}
+
+impl Default for WalletInvoicesContent {
+    fn default() -> Self {
+        Self {
+            status: InvoiceStatus::Open,
+        }
+    }
+}
+
+/// Height of invoice list item.
+const INVOICE_ITEM_HEIGHT: f32 = 78.0;
+
+impl WalletInvoicesContent {
+    /// Draw [`Modal`] content, returns local transaction identifier to show on invoice selection.
+    pub fn ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet) -> Option<u32> {
+        ui.add_space(6.0);
+
+        // Show invoice status selection.
+        ui.columns(3, |columns| {
+            columns[0].vertical_centered(|ui| {
+                let open = InvoiceStatus::Open;
+                View::radio_value(ui, &mut self.status, open, t!("wallets.invoices_open"));
+            });
+            columns[1].vertical_centered(|ui| {
+                let paid = InvoiceStatus::Paid;
+                View::radio_value(ui, &mut self.status, paid, t!("wallets.invoices_paid"));
+            });
+            columns[2].vertical_centered(|ui| {
+                let expired = InvoiceStatus::Expired;
+                View::radio_value(ui, &mut self.status, expired, t!("wallets.invoices_expired"));
+            });
+        });
+        ui.add_space(8.0);
+
+        // Setup invoices with selected status.
+        let data = wallet.get_data();
+        let invoices = wallet.invoices()
+            .into_iter()
+            .map(|i| {
+                let tx = data.as_ref().and_then(|d| d.tx_by_slate_id(i.slate_id.clone()));
+                (i, tx)
+            })
+            .filter(|(i, tx)| i.status(tx.as_ref()) == self.status)
+            .collect::<Vec<(WalletInvoice, Option<WalletTransaction>)>>();
+
+        let mut selected_tx = None;
+        if invoices.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.label(RichText::new(t!("wallets.invoices_empty"))
+                    .size(17.0)
+                    .color(Colors::inactive_text()));
+            });
+        } else {
+            let size = invoices.len();
+            ScrollArea::vertical()
+                .id_salt(Id::from("wallet_invoices_scroll").with(wallet.get_config().id))
+                .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+                .max_height(INVOICE_ITEM_HEIGHT * 5.0)
+                .auto_shrink([true; 2])
+                .show_rows(ui, INVOICE_ITEM_HEIGHT, size, |ui, row_range| {
+                    for index in row_range {
+                        let (invoice, tx) = invoices.get(index).unwrap();
+                        invoice_item_ui(ui, invoice, tx.as_ref(), index, size, |id| {
+                            selected_tx = Some(id);
+                        });
+                    }
+                });
+        }
+        ui.add_space(12.0);
+
+        ui.vertical_centered_justified(|ui| {
+            View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                Modal::close();
+            });
+        });
+        ui.add_space(6.0);
+        selected_tx
+    }
+}
+
+/// Draw invoice item content with callback to show its transaction.
+fn invoice_item_ui(ui: &mut egui::Ui,
+                   invoice: &WalletInvoice,
+                   tx: Option<&WalletTransaction>,
+                   index: usize,
+                   size: usize,
+                   on_show: impl FnOnce(u32)) {
+    // Setup layout size.
+    let mut rect = ui.available_rect_before_wrap();
+    rect.set_height(INVOICE_ITEM_HEIGHT);
+
+    // Draw round background.
+    let item_rounding = View::item_rounding(index, size, false);
+    ui.painter().rect(rect, item_rounding, Colors::fill(), View::item_stroke(), StrokeKind::Middle);
+
+    ui.allocate_ui_with_layout(rect.size(), Layout::right_to_left(Align::Center), |ui| {
+        // Draw button to show transaction.
+        if let Some(tx) = tx {
+            let r = View::item_rounding(index, size, true);
+            View::item_button(ui, r, FILE_TEXT, None, || {
+                on_show(tx.data.id);
+            });
+        }
+
+        let layout_size = ui.available_size();
+        ui.allocate_ui_with_layout(layout_size, Layout::left_to_right(Align::Center), |ui| {
+            ui.add_space(6.0);
+            ui.vertical(|ui| {
+                ui.add_space(3.0);
+                let amount = format!("{} ツ", amount_to_hr_string(invoice.amount, true));
+                View::ellipsize_text(ui, amount, 18.0, Colors::white_or_black(true));
+                let memo = if !invoice.request.memo.is_empty() {
+                    invoice.request.memo.clone()
+                } else if invoice.request.requested_by.is_empty() {
+                    t!("wallets.invoice_main_address").to_string()
+                } else {
+                    invoice.request.requested_by.clone()
+                };
+                View::ellipsize_text(ui, memo, 15.0, Colors::text(false));
+                let time = match invoice.request.expires {
+                    Some(e) => format!("{} {}", CALENDAR_X, View::format_time(e)),
+                    None => View::format_time(invoice.created)
+                };
+                ui.label(RichText::new(time).size(15.0).color(Colors::gray()));
+                ui.add_space(3.0);
+            });
+        });
+    });
+}
@@ -16,4 +16,13 @@ mod content;
 pub use content::*;
 
 mod tx;
-pub use tx::*;
\ No newline at end of file
+pub use tx::*;
+
+mod proof;
+pub use proof::*;
+
+mod outputs;
+pub use outputs::*;
+
+mod invoices;
+pub use invoices::*;
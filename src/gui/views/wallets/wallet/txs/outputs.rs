@@ -0,0 +1,304 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::scroll_area::ScrollBarVisibility;
+use egui::{Align, Id, Layout, RichText, ScrollArea, StrokeKind};
+use grin_core::core::amount_to_hr_string;
+use grin_wallet_libwallet::OutputStatus;
+
+use crate::gui::icons::{CHECK_SQUARE, SQUARE, STACK_SIMPLE};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::{Modal, TextEdit, View};
+use crate::gui::Colors;
+use crate::wallet::types::{WalletOutput, WalletTask};
+use crate::wallet::Wallet;
+
+/// Wallet outputs [`Modal`] content.
+pub struct WalletOutputsContent {
+    /// Loaded outputs.
+    outputs: Vec<WalletOutput>,
+    /// Flag to check if outputs loading failed.
+    load_error: bool,
+    /// Account label to show outputs for, all accounts if `None`.
+    account: Option<String>,
+
+    /// Flag to check if outputs consolidation setup is shown.
+    consolidation: bool,
+    /// Maximum amount of outputs to consolidate input.
+    batch_edit: String,
+}
+
+impl Default for WalletOutputsContent {
+    fn default() -> Self {
+        Self {
+            outputs: vec![],
+            load_error: false,
+            account: None,
+            consolidation: false,
+            batch_edit: "".to_string(),
+        }
+    }
+}
+
+impl WalletOutputsContent {
+    /// Height of output list item.
+    pub const OUTPUT_ITEM_HEIGHT: f32 = 78.0;
+
+    /// Load outputs from the wallet.
+    pub fn load(&mut self, wallet: &Wallet) {
+        self.consolidation = false;
+        self.batch_edit = wallet.consolidation_batch().to_string();
+        match wallet.outputs() {
+            Ok(outputs) => {
+                self.outputs = outputs;
+                self.load_error = false;
+            }
+            Err(_) => {
+                self.outputs = vec![];
+                self.load_error = true;
+            }
+        }
+    }
+
+    /// Draw [`Modal`] content.
+    pub fn ui(&mut self,
+              ui: &mut egui::Ui,
+              wallet: &Wallet,
+              modal: &Modal,
+              cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        if self.consolidation {
+            self.consolidation_ui(ui, wallet, modal, cb);
+            return;
+        }
+
+        // Show account filter when there are multiple accounts.
+        let accounts = wallet.accounts();
+        if accounts.len() > 1 {
+            ui.horizontal_wrapped(|ui| {
+                View::radio_value(ui, &mut self.account, None, t!("wallets.tx_all"));
+                for a in accounts {
+                    let label = a.label.clone();
+                    View::radio_value(ui, &mut self.account, Some(a.label), label);
+                }
+            });
+            ui.add_space(8.0);
+        }
+
+        let outputs = self.outputs.iter()
+            .filter(|o| self.account.as_ref().map(|a| &o.account == a).unwrap_or(true))
+            .cloned()
+            .collect::<Vec<WalletOutput>>();
+        if outputs.is_empty() {
+            ui.vertical_centered(|ui| {
+                let (text, color) = if self.load_error {
+                    (t!("wallets.outputs_err"), Colors::red())
+                } else {
+                    (t!("wallets.outputs_empty"), Colors::inactive_text())
+                };
+                ui.label(RichText::new(text).size(17.0).color(color));
+            });
+        } else {
+            let size = outputs.len();
+            ScrollArea::vertical()
+                .id_salt(Id::from("wallet_outputs_scroll").with(wallet.get_config().id))
+                .scroll_bar_visibility(ScrollBarVisibility::AlwaysHidden)
+                .max_height(Self::OUTPUT_ITEM_HEIGHT * 5.0)
+                .auto_shrink([true; 2])
+                .show_rows(ui, Self::OUTPUT_ITEM_HEIGHT, size, |ui, row_range| {
+                    for index in row_range {
+                        let output = outputs.get(index).unwrap();
+                        output_item_ui(ui, output, index, size, None, || {});
+                    }
+                });
+        }
+        ui.add_space(12.0);
+
+        // Watch-only wallet can not consolidate outputs.
+        if wallet.is_watch_only() {
+            ui.vertical_centered_justified(|ui| {
+                View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                    self.outputs = vec![];
+                    Modal::close();
+                });
+            });
+            ui.add_space(6.0);
+            return;
+        }
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                    self.outputs = vec![];
+                    Modal::close();
+                });
+            });
+            columns[1].vertical_centered_justified(|ui| {
+                let text = format!("{} {}", STACK_SIMPLE, t!("wallets.consolidate"));
+                View::button(ui, text, Colors::white_or_black(false), || {
+                    self.consolidation = true;
+                });
+            });
+        });
+        ui.add_space(6.0);
+    }
+
+    /// Draw outputs consolidation setup content.
+    fn consolidation_ui(&mut self,
+                        ui: &mut egui::Ui,
+                        wallet: &Wallet,
+                        modal: &Modal,
+                        cb: &dyn PlatformCallbacks) {
+        if wallet.consolidating() {
+            ui.vertical_centered(|ui| {
+                View::small_loading_spinner(ui);
+                ui.add_space(8.0);
+                ui.label(RichText::new(t!("wallets.consolidating"))
+                    .size(17.0)
+                    .color(Colors::text(false)));
+            });
+            ui.add_space(12.0);
+            return;
+        }
+
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.consolidate_desc"))
+                .size(17.0)
+                .color(Colors::text(false)));
+            ui.add_space(8.0);
+            ui.label(RichText::new(t!("wallets.consolidate_batch"))
+                .size(17.0)
+                .color(Colors::gray()));
+        });
+        ui.add_space(8.0);
+        let batch_id = Id::from(modal.id).with("consolidation_batch").with(wallet.get_config().id);
+        let mut batch_edit = TextEdit::new(batch_id).h_center().numeric().focus(false);
+        batch_edit.ui(ui, &mut self.batch_edit, cb);
+        ui.add_space(8.0);
+
+        // Pick smallest spendable outputs of current account.
+        let batch = self.batch_edit.trim().parse::<usize>().unwrap_or(0);
+        let account = wallet.get_config().account;
+        let mut outputs = self.outputs.iter()
+            .filter(|o| o.spendable && o.account == account)
+            .cloned()
+            .collect::<Vec<WalletOutput>>();
+        outputs.sort_by_key(|o| o.value);
+        outputs.truncate(batch);
+        let total: u64 = outputs.iter().map(|o| o.value).sum();
+        let fee = Wallet::consolidation_fee(outputs.len());
+        let can_consolidate = outputs.len() > 1 && total > fee;
+
+        // Show fee estimation or error.
+        ui.vertical_centered(|ui| {
+            if can_consolidate {
+                let text = t!("wallets.consolidate_info",
+                    "count" => outputs.len(),
+                    "amount" => amount_to_hr_string(total - fee, true),
+                    "fee" => amount_to_hr_string(fee, true));
+                ui.label(RichText::new(text).size(16.0).color(Colors::gray()));
+            } else {
+                ui.label(RichText::new(t!("wallets.consolidate_err"))
+                    .size(17.0)
+                    .color(Colors::red()));
+            }
+        });
+        ui.add_space(12.0);
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                View::button(ui, t!("back"), Colors::white_or_black(false), || {
+                    self.consolidation = false;
+                });
+            });
+            columns[1].vertical_centered_justified(|ui| {
+                if can_consolidate {
+                    View::button(ui, t!("continue"), Colors::white_or_black(false), || {
+                        wallet.update_consolidation_batch(batch);
+                        let _ = wallet.task(WalletTask::Consolidate(batch));
+                        self.outputs = vec![];
+                        Modal::close();
+                    });
+                }
+            });
+        });
+        ui.add_space(6.0);
+    }
+}
+
+/// Draw output item content with optional selection state and callback on selection.
+pub fn output_item_ui(ui: &mut egui::Ui,
+                      output: &WalletOutput,
+                      index: usize,
+                      size: usize,
+                      selected: Option<bool>,
+                      on_select: impl FnOnce()) {
+    // Setup layout size.
+    let mut rect = ui.available_rect_before_wrap();
+    rect.set_height(WalletOutputsContent::OUTPUT_ITEM_HEIGHT);
+
+    // Draw round background.
+    let item_rounding = View::item_rounding(index, size, false);
+    ui.painter().rect(rect, item_rounding, Colors::fill(), View::item_stroke(), StrokeKind::Middle);
+
+    ui.allocate_ui_with_layout(rect.size(), Layout::right_to_left(Align::Center), |ui| {
+        // Draw button to select output.
+        if let Some(selected) = selected {
+            let icon = if selected { CHECK_SQUARE } else { SQUARE };
+            View::item_button(ui, View::item_rounding(index, size, true), icon, None, || {
+                on_select();
+            });
+        }
+
+        let layout_size = ui.available_size();
+        ui.allocate_ui_with_layout(layout_size, Layout::left_to_right(Align::Center), |ui| {
+            ui.add_space(6.0);
+            ui.vertical(|ui| {
+                ui.add_space(3.0);
+                let mut value = format!("{} ツ", amount_to_hr_string(output.value, true));
+                if output.is_coinbase {
+                    value = format!("{} · {}", value, t!("wallets.output_coinbase"));
+                }
+                View::ellipsize_text(ui, value, 18.0, Colors::white_or_black(true));
+                View::ellipsize_text(ui, output.commit.clone(), 15.0, Colors::text(false));
+                let status = match output.status {
+                    OutputStatus::Unconfirmed => t!("wallets.output_unconfirmed"),
+                    OutputStatus::Unspent => t!("wallets.output_unspent"),
+                    OutputStatus::Locked => t!("wallets.output_locked"),
+                    OutputStatus::Spent => t!("wallets.output_spent"),
+                    OutputStatus::Reverted => t!("wallets.output_reverted"),
+                };
+                let mut info = format!("{} · {} {}",
+                                       status,
+                                       t!("wallets.output_height"),
+                                       output.height);
+                if output.lock_height > output.height {
+                    info = format!("{} · {} {}",
+                                   info,
+                                   t!("wallets.output_lock_height"),
+                                   output.lock_height);
+                }
+                let color = if output.spendable { Colors::green() } else { Colors::gray() };
+                View::ellipsize_text(ui, info, 15.0, color);
+                ui.add_space(3.0);
+            });
+        });
+    });
+}
@@ -0,0 +1,154 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use egui::RichText;
+use grin_core::core::amount_to_hr_string;
+use grin_util::ToHex;
+
+use crate::gui::icons::{CLIPBOARD_TEXT, COINS, FILE_ARCHIVE, USER, USER_CIRCLE};
+use crate::gui::platform::PlatformCallbacks;
+use crate::gui::views::wallets::wallet::txs::tx::info_item_ui;
+use crate::gui::views::{FilePickContent, FilePickContentType, Modal, View};
+use crate::gui::Colors;
+use crate::wallet::types::{PaymentProofStatus, WalletTask};
+use crate::wallet::Wallet;
+
+/// Payment proof verification [`Modal`] content.
+pub struct PaymentProofContent {
+    /// Button to pick payment proof file.
+    file_pick_button: FilePickContent,
+}
+
+impl Default for PaymentProofContent {
+    fn default() -> Self {
+        Self {
+            file_pick_button: FilePickContent::new(FilePickContentType::Button),
+        }
+    }
+}
+
+impl PaymentProofContent {
+    /// Draw [`Modal`] content.
+    pub fn ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        ui.add_space(6.0);
+        match wallet.proof_status() {
+            None => self.input_ui(ui, wallet, cb),
+            Some(status) => {
+                match status {
+                    PaymentProofStatus::Verifying => {
+                        ui.vertical_centered(|ui| {
+                            View::small_loading_spinner(ui);
+                            ui.add_space(8.0);
+                            ui.label(RichText::new(t!("wallets.proof_verifying"))
+                                .size(17.0)
+                                .color(Colors::text(false)));
+                        });
+                        ui.add_space(12.0);
+                        return;
+                    }
+                    PaymentProofStatus::Valid(proof, sender, receiver) => {
+                        ui.vertical_centered(|ui| {
+                            ui.label(RichText::new(t!("wallets.proof_valid"))
+                                .size(17.0)
+                                .color(Colors::green()));
+                        });
+                        ui.add_space(8.0);
+                        let amount = format!("{} ツ", amount_to_hr_string(proof.amount, true));
+                        let amount_label = format!("{} {}", COINS, t!("wallets.proof_amount"));
+                        info_item_ui(ui, amount, amount_label, false, cb);
+                        let kernel_label = format!("{} {}", FILE_ARCHIVE, t!("kernel"));
+                        info_item_ui(ui, proof.excess.0.to_hex(), kernel_label, true, cb);
+                        let sender_label = format!("{} {}", USER, t!("wallets.proof_sender"));
+                        info_item_ui(ui, proof.sender_address.to_string(), sender_label, true, cb);
+                        let receiver_label = format!("{} {}",
+                                                     USER_CIRCLE,
+                                                     t!("wallets.proof_receiver"));
+                        let receiver_addr = proof.recipient_address.to_string();
+                        info_item_ui(ui, receiver_addr, receiver_label, true, cb);
+
+                        // Show if addresses belong to the wallet.
+                        if sender || receiver {
+                            ui.add_space(8.0);
+                            ui.vertical_centered(|ui| {
+                                let text = if sender {
+                                    t!("wallets.proof_sender_mine")
+                                } else {
+                                    t!("wallets.proof_receiver_mine")
+                                };
+                                ui.label(RichText::new(text)
+                                    .size(16.0)
+                                    .color(Colors::inactive_text()));
+                            });
+                        }
+                    }
+                    PaymentProofStatus::Invalid(err) => {
+                        ui.vertical_centered(|ui| {
+                            ui.label(RichText::new(t!("wallets.proof_invalid"))
+                                .size(17.0)
+                                .color(Colors::red()));
+                            ui.add_space(6.0);
+                            ui.label(RichText::new(err)
+                                .size(15.0)
+                                .color(Colors::inactive_text()));
+                        });
+                    }
+                }
+                ui.add_space(12.0);
+                ui.vertical_centered_justified(|ui| {
+                    View::button(ui, t!("close"), Colors::white_or_black(false), || {
+                        wallet.clear_proof_status();
+                        Modal::close();
+                    });
+                });
+                ui.add_space(6.0);
+            }
+        }
+    }
+
+    /// Draw content to provide payment proof.
+    fn input_ui(&mut self, ui: &mut egui::Ui, wallet: &Wallet, cb: &dyn PlatformCallbacks) {
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.verify_proof_desc"))
+                .size(17.0)
+                .color(Colors::text(false)));
+        });
+        ui.add_space(12.0);
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        // Show buttons to pick or paste payment proof.
+        ui.columns(2, |columns| {
+            columns[0].vertical_centered_justified(|ui| {
+                self.file_pick_button.ui(ui, cb, |data| {
+                    let _ = wallet.task(WalletTask::VerifyProof(data));
+                });
+            });
+            columns[1].vertical_centered_justified(|ui| {
+                let paste_text = format!("{} {}", CLIPBOARD_TEXT, t!("paste"));
+                View::button(ui, paste_text, Colors::white_or_black(false), || {
+                    let data = cb.get_string_from_buffer();
+                    let _ = wallet.task(WalletTask::VerifyProof(data));
+                });
+            });
+        });
+        ui.add_space(8.0);
+        ui.vertical_centered_justified(|ui| {
+            View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                Modal::close();
+            });
+        });
+        ui.add_space(6.0);
+    }
+}
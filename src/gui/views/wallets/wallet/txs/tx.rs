@@ -12,18 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use egui::{Align, CornerRadius, Layout, RichText, StrokeKind};
+use egui::{Align, CornerRadius, Id, Layout, RichText, StrokeKind};
 use grin_core::core::amount_to_hr_string;
 use grin_util::ToHex;
 use grin_wallet_libwallet::TxLogEntryType;
 
-use crate::gui::icons::{CIRCLE_HALF, COPY, CUBE, FILE_ARCHIVE, FILE_TEXT, HASH_STRAIGHT, PROHIBIT, QR_CODE, SCAN};
+use crate::gui::icons::{AT, CALENDAR_X, CIRCLE_HALF, COPY, CUBE, FILE_ARCHIVE, FILE_TEXT, HASH_STRAIGHT, NOTE, NOTE_PENCIL, PROHIBIT, QR_CODE, RECEIPT, SCAN, SEAL_CHECK, TAG, USER, USER_PLUS};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::wallets::wallet::txs::WalletTransactions;
-use crate::gui::views::{CameraContent, FilePickContent, FilePickContentType, Modal, QrCodeContent, View};
+use crate::gui::views::{CameraContent, FilePickContent, FilePickContentType, Modal, QrCodeContent, TextEdit, View};
 use crate::gui::Colors;
-use crate::wallet::types::{WalletTask, WalletTransaction};
-use crate::wallet::Wallet;
+use crate::wallet::types::{WalletTask, WalletTransaction, WalletTransactionNote};
+use crate::wallet::{Wallet, WalletContact};
 
 /// Transaction information [`Modal`] content.
 pub struct WalletTransactionContent {
@@ -38,6 +38,14 @@ pub struct WalletTransactionContent {
 
     /// Button to parse picked file content.
     file_pick_button: FilePickContent,
+
+    /// Transaction note editing values.
+    note_edit: Option<WalletTransactionNote>,
+
+    /// Receiver contact name input to save in address book.
+    contact_name_edit: Option<String>,
+    /// Receiver address to save in address book.
+    contact_address: Option<String>,
 }
 
 impl WalletTransactionContent {
@@ -50,9 +58,19 @@ impl WalletTransactionContent {
             file_pick_button: FilePickContent::new(
                 FilePickContentType::ItemButton(View::item_rounding(0, 2, true))
             ),
+            note_edit: None,
+            contact_name_edit: None,
+            contact_address: None,
         }
     }
 
+    /// Show content to save provided receiver address in address book.
+    pub fn with_contact_prompt(mut self, address: String) -> Self {
+        self.contact_address = Some(address);
+        self.contact_name_edit = Some("".to_string());
+        self
+    }
+
     /// Draw [`Modal`] content.
     pub fn ui(&mut self,
               ui: &mut egui::Ui,
@@ -103,7 +121,7 @@ impl WalletTransactionContent {
                 modal.enable_closing();
                 self.scan_qr_content = None;
                 // Provide scan result as Slatepack message.
-                wallet.task(WalletTask::OpenMessage(result.text()));
+                let _ = wallet.task(WalletTask::OpenMessage(result.text()));
             } else {
                 scan_content.ui(ui, cb);
             }
@@ -129,6 +147,10 @@ impl WalletTransactionContent {
                     });
                 });
             });
+        } else if self.note_edit.is_some() {
+            self.note_edit_ui(ui, modal, wallet, cb);
+        } else if self.contact_name_edit.is_some() {
+            self.contact_save_ui(ui, modal, tx, wallet, cb);
         } else {
             // Show transaction information.
             self.info_ui(ui, modal, tx, wallet, cb);
@@ -174,6 +196,13 @@ impl WalletTransactionContent {
         };
         ui.add_space(6.0);
         ui.vertical_centered(|ui| {
+            // Show error of last transaction action.
+            if let Some(e) = &tx.action_error {
+                ui.label(RichText::new(format!("{}: {}", t!("error"), e))
+                    .size(16.0)
+                    .color(Colors::red()));
+                ui.add_space(6.0);
+            }
             ui.label(RichText::new(desc_text).size(16.0).color(Colors::inactive_text()));
         });
         ui.add_space(6.0);
@@ -247,7 +276,7 @@ impl WalletTransactionContent {
             if tx.can_finalize() {
                 // Draw button to pick file.
                 self.file_pick_button.ui(ui, cb, |data| {
-                    wallet.task(WalletTask::OpenMessage(data));
+                    let _ = wallet.task(WalletTask::OpenMessage(data));
                 });
                 // Draw button to scan QR code.
                 let r =  CornerRadius::default();
@@ -269,7 +298,7 @@ impl WalletTransactionContent {
                         View::item_rounding(0, 2, true)
                     };
                     View::item_button(ui, r, PROHIBIT, Some(Colors::red()), || {
-                        wallet.task(WalletTask::Cancel(tx.clone()));
+                        let _ = wallet.task(WalletTask::Cancel(tx.clone()));
                         Modal::close();
                     });
                 }
@@ -298,18 +327,244 @@ impl WalletTransactionContent {
         }
         // Show receiver address.
         if let Some(rec) = &tx.receiver {
-            let label = format!("{} {}", CIRCLE_HALF, t!("network_mining.address"));
+            let label = match wallet.contact(&rec.to_string()) {
+                Some(c) => format!("{} {}", USER, c.name),
+                None => format!("{} {}", CIRCLE_HALF, t!("network_mining.address"))
+            };
             info_item_ui(ui, rec.to_string(), label, true, cb);
         }
+        // Show issued invoice details.
+        if let Some(invoice) = tx.data.tx_slate_id.and_then(|id| wallet.invoice(&id.to_string())) {
+            if !invoice.request.memo.is_empty() {
+                let label = format!("{} {}", RECEIPT, t!("wallets.invoice_memo"));
+                info_item_ui(ui, invoice.request.memo.clone(), label, true, cb);
+            }
+            if !invoice.request.requested_by.is_empty() {
+                let label = format!("{} {}", AT, t!("wallets.invoice_requested_by"));
+                info_item_ui(ui, invoice.request.requested_by.clone(), label, true, cb);
+            }
+            if let Some(expires) = invoice.request.expires {
+                let label = format!("{} {}", CALENDAR_X, t!("wallets.invoice_expires"));
+                info_item_ui(ui, View::format_time(expires), label, false, cb);
+            }
+        }
+        // Show additional address transaction was received at.
+        if let Some(addr) = &tx.receive_address {
+            let label = format!("{} {}", AT, addr.label);
+            info_item_ui(ui, addr.address.clone(), label, true, cb);
+        }
+        // Show user note.
+        if let Some(note) = &tx.note {
+            if !note.label.is_empty() {
+                let label = format!("{} {}", TAG, t!("wallets.tx_label"));
+                info_item_ui(ui, note.label.clone(), label, true, cb);
+            }
+            if !note.counterparty.is_empty() {
+                let label = format!("{} {}", USER, t!("wallets.tx_counterparty"));
+                info_item_ui(ui, note.counterparty.clone(), label, true, cb);
+            }
+            if !note.note.is_empty() {
+                let label = format!("{} {}", NOTE, t!("wallets.tx_note"));
+                info_item_ui(ui, note.note.clone(), label, true, cb);
+            }
+        }
+
+        // Show button to edit note.
+        ui.add_space(8.0);
+        ui.vertical_centered_justified(|ui| {
+            let edit_text = format!("{} {}", NOTE_PENCIL, t!("wallets.tx_edit_note"));
+            View::button(ui, edit_text, Colors::white_or_black(false), || {
+                self.note_edit = Some(tx.note.clone().unwrap_or_default());
+            });
+        });
+        // Show button to export payment proof of confirmed sent transaction.
+        let sent = tx.data.tx_type == TxLogEntryType::TxSent;
+        if sent && tx.data.confirmed && tx.data.payment_proof.is_some() {
+            ui.add_space(8.0);
+            ui.vertical_centered_justified(|ui| {
+                let proof_text = format!("{} {}", SEAL_CHECK, t!("wallets.export_proof"));
+                View::button(ui, proof_text, Colors::white_or_black(false), || {
+                    if let Ok(data) = wallet.export_payment_proof(tx.data.id) {
+                        let id = tx.data.tx_slate_id.map(|id| id.to_string())
+                            .unwrap_or(tx.data.id.to_string());
+                        let name = format!("{}-proof.json", id);
+                        cb.share_data(name, data).unwrap_or_default();
+                    }
+                });
+            });
+        }
+
+        // Offer to save new receiver of sent transaction to address book.
+        if let Some(rec) = &tx.receiver {
+            if sent && wallet.contact(&rec.to_string()).is_none() {
+                ui.add_space(8.0);
+                ui.vertical_centered_justified(|ui| {
+                    let save_text = format!("{} {}", USER_PLUS, t!("contacts.save"));
+                    View::button(ui, save_text, Colors::white_or_black(false), || {
+                        self.contact_address = Some(rec.to_string());
+                        self.contact_name_edit = Some("".to_string());
+                    });
+                });
+            }
+        }
+    }
+
+    /// Draw content to save transaction receiver to address book.
+    fn contact_save_ui(&mut self,
+                       ui: &mut egui::Ui,
+                       modal: &Modal,
+                       tx: &WalletTransaction,
+                       wallet: &Wallet,
+                       cb: &dyn PlatformCallbacks) {
+        let address = self.contact_address.clone().unwrap_or_else(|| {
+            tx.receiver.as_ref().map(|r| r.to_string()).unwrap_or_default()
+        });
+        ui.add_space(6.0);
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(&address)
+                .size(16.0)
+                .color(Colors::inactive_text()));
+            ui.add_space(8.0);
+            ui.label(RichText::new(t!("contacts.name"))
+                .size(17.0)
+                .color(Colors::gray()));
+        });
+        ui.add_space(6.0);
+        let name_id = Id::from(modal.id).with("tx_contact_name").with(self.tx_id);
+        let mut name_edit = TextEdit::new(name_id).focus(Modal::first_draw());
+        name_edit.ui(ui, self.contact_name_edit.as_mut().unwrap(), cb);
+        ui.add_space(12.0);
+
+        // Save contact with entered name.
+        let mut save = name_edit.enter_pressed;
+        let mut close = false;
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        ui.columns(2, |cols| {
+            cols[0].vertical_centered_justified(|ui| {
+                View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                    close = true;
+                });
+            });
+            cols[1].vertical_centered_justified(|ui| {
+                View::button(ui, t!("modal.save"), Colors::white_or_black(false), || {
+                    save = true;
+                });
+            });
+        });
+        if save {
+            let contact = WalletContact {
+                name: self.contact_name_edit.as_ref().unwrap().trim().to_string(),
+                address,
+            };
+            if contact.is_valid() {
+                wallet.save_contact(contact);
+                close = true;
+            }
+        }
+        if close {
+            self.contact_name_edit = None;
+            self.contact_address = None;
+        }
+    }
+
+    /// Draw transaction note editing content.
+    fn note_edit_ui(&mut self,
+                    ui: &mut egui::Ui,
+                    modal: &Modal,
+                    wallet: &Wallet,
+                    cb: &dyn PlatformCallbacks) {
+        let tx_id = self.tx_id;
+        let note = self.note_edit.as_mut().unwrap();
+        let id = Id::from(modal.id).with("tx_note").with(tx_id);
+        ui.add_space(6.0);
+
+        // Show label input.
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.tx_label"))
+                .size(17.0)
+                .color(Colors::gray()));
+        });
+        ui.add_space(6.0);
+        let mut label_edit = TextEdit::new(id.with("label")).focus(Modal::first_draw());
+        label_edit.ui(ui, &mut note.label, cb);
+        ui.add_space(8.0);
+
+        // Show counterparty input.
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.tx_counterparty"))
+                .size(17.0)
+                .color(Colors::gray()));
+        });
+        ui.add_space(6.0);
+        let mut counterparty_edit = TextEdit::new(id.with("counterparty")).focus(false);
+        if label_edit.enter_pressed {
+            counterparty_edit.focus_request();
+        }
+        counterparty_edit.ui(ui, &mut note.counterparty, cb);
+        ui.add_space(8.0);
+
+        // Show note input.
+        ui.vertical_centered(|ui| {
+            ui.label(RichText::new(t!("wallets.tx_note"))
+                .size(17.0)
+                .color(Colors::gray()));
+        });
+        ui.add_space(6.0);
+        let mut note_edit = TextEdit::new(id.with("note")).focus(false);
+        if counterparty_edit.enter_pressed {
+            note_edit.focus_request();
+        }
+        note_edit.ui(ui, &mut note.note, cb);
+        ui.add_space(12.0);
+
+        // Save note on Enter press.
+        let save_note = |note: &WalletTransactionNote| {
+            let note = WalletTransactionNote {
+                label: note.label.trim().to_string(),
+                counterparty: note.counterparty.trim().to_string(),
+                note: note.note.trim().to_string(),
+            };
+            wallet.update_tx_note(tx_id, note);
+        };
+        if note_edit.enter_pressed {
+            save_note(note);
+            self.note_edit = None;
+            return;
+        }
+
+        // Setup spacing between buttons.
+        ui.spacing_mut().item_spacing = egui::Vec2::new(8.0, 0.0);
+
+        // Show buttons to cancel editing or save note.
+        let mut close = false;
+        ui.columns(2, |cols| {
+            cols[0].vertical_centered_justified(|ui| {
+                View::button(ui, t!("modal.cancel"), Colors::white_or_black(false), || {
+                    close = true;
+                });
+            });
+            cols[1].vertical_centered_justified(|ui| {
+                View::button(ui, t!("modal.save"), Colors::white_or_black(false), || {
+                    save_note(note);
+                    close = true;
+                });
+            });
+        });
+        if close {
+            self.note_edit = None;
+        }
     }
 }
 
 /// Draw transaction information item content.
-fn info_item_ui(ui: &mut egui::Ui,
-                value: String,
-                label: String,
-                copy: bool,
-                cb: &dyn PlatformCallbacks) {
+pub(super) fn info_item_ui(ui: &mut egui::Ui,
+                           value: String,
+                           label: String,
+                           copy: bool,
+                           cb: &dyn PlatformCallbacks) {
     // Setup layout size.
     let mut rect = ui.available_rect_before_wrap();
     rect.set_height(50.0);
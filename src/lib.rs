@@ -31,6 +31,7 @@ use crate::gui::{Colors, App};
 use crate::gui::platform::PlatformCallbacks;
 use crate::gui::views::View;
 use crate::node::Node;
+use crate::wallet::Wallet;
 
 i18n!("locales");
 
@@ -39,6 +40,8 @@ mod wallet;
 mod tor;
 mod settings;
 mod http;
+pub mod cli;
+pub mod control;
 pub mod gui;
 
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -95,6 +98,8 @@ fn use_dark_theme(platform: &gui::platform::Android) -> bool {
 pub fn app_creator<T: 'static>(app: App<T>) -> eframe::AppCreator<'static>
     where App<T>: eframe::App, T: PlatformCallbacks {
     Box::new(|cc| {
+        // Enable recurring payments with confirmation at GUI.
+        Wallet::enable_payments();
         setup_fonts(&cc.egui_ctx);
         // Setup images support.
         egui_extras::install_image_loaders(&cc.egui_ctx);
@@ -261,6 +266,11 @@ pub fn consume_incoming_data() -> Option<String> {
     None
 }
 
+/// Check if provided text is a payment request URI.
+pub fn is_payment_uri(text: &str) -> bool {
+    wallet::PaymentUri::parse(text).is_some()
+}
+
 /// Provide data from deeplink or opened file.
 pub fn on_data(data: String) {
     let mut w_data = INCOMING_DATA.write();
@@ -31,12 +31,24 @@ fn real_main() {
 
     // Handle file path argument passing.
     let args: Vec<_> = std::env::args().collect();
+    let headless = args.get(1).map(|a| a == grim::cli::HeadlessArgs::HEADLESS_ARG)
+        .unwrap_or(false);
     let mut data = None;
-    if args.len() > 1 {
-        let path = std::path::PathBuf::from(&args[1]);
-        let content = match std::fs::read_to_string(path) {
-            Ok(s) => Some(s),
-            Err(_) => Some(args[1].clone())
+    if args.len() > 1 && !headless {
+        let arg = &args[1];
+        let content = if grim::is_payment_uri(arg) {
+            Some(arg.clone())
+        } else {
+            // Desktop environment can pass file path as URI.
+            let path = url::Url::parse(arg)
+                .ok()
+                .filter(|u| u.scheme() == "file")
+                .and_then(|u| u.to_file_path().ok())
+                .unwrap_or(std::path::PathBuf::from(arg));
+            match std::fs::read_to_string(path) {
+                Ok(s) => Some(s),
+                Err(_) => Some(arg.clone())
+            }
         };
         data = content
     }
@@ -74,6 +86,11 @@ fn real_main() {
         println!("{}\n{}", msg, loc);
     }));
 
+    // Run command without GUI.
+    if headless {
+        std::process::exit(grim::cli::start_headless(args[2..].to_vec()));
+    }
+
     // Start GUI.
     let _ = std::panic::catch_unwind(|| {
         if is_app_running(&data) {
@@ -198,6 +215,11 @@ fn is_app_running(data: &Option<String>) -> bool {
     }
 }
 
+/// Amount of seconds to wait for data from socket connection.
+#[allow(dead_code)]
+#[cfg(not(target_os = "android"))]
+const READ_TIMEOUT: u64 = 10;
+
 /// Start desktop socket that handles data for single application instance.
 #[allow(dead_code)]
 #[cfg(not(target_os = "android"))]
@@ -213,21 +235,40 @@ fn start_app_socket(platform: grim::gui::platform::Desktop) {
                     Listener, ListenerOptions,
                 };
                 use std::io;
+                use std::time::Duration;
                 use tokio::{
-                    io::{AsyncBufReadExt, BufReader},
+                    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
                 };
                 use grim::gui::platform::PlatformCallbacks;
+                use grim::control::ControlRpc;
 
-                // Handle incoming connection.
+                // Handle incoming connection, returns data if it is not a control request.
                 async fn handle_conn(conn: Stream)
-                                     -> io::Result<String> {
-                    let mut read = BufReader::new(&conn);
+                                     -> io::Result<Option<String>> {
+                    let (rec, mut sen) = conn.split();
+                    let mut read = BufReader::new(rec);
                     let mut buffer = String::new();
-                    // Read data.
-                    let _ = read.read_line(&mut buffer).await;
-                    Ok(buffer)
+                    // Read data, drop connection if client did not send a line in time.
+                    let read_timeout = Duration::from_secs(READ_TIMEOUT);
+                    match tokio::time::timeout(read_timeout, read.read_line(&mut buffer)).await {
+                        Ok(_) => {}
+                        Err(_) => return Ok(None)
+                    }
+                    // Send response to control request handled outside of socket loop.
+                    let request = buffer.clone();
+                    let res = tokio::task::spawn_blocking(move || ControlRpc::handle(&request))
+                        .await
+                        .unwrap_or(None);
+                    if let Some(res) = res {
+                        sen.write_all(res.as_bytes()).await?;
+                        return Ok(None);
+                    }
+                    Ok(Some(buffer))
                 }
 
+                // Setup token to authorize control requests.
+                ControlRpc::init_token();
+
                 // Setup socket name.
                 let socket_path = grim::Settings::socket_path();
                 if socket_path.exists() {
@@ -253,15 +294,18 @@ fn start_app_socket(platform: grim::gui::platform::Desktop) {
                             continue
                         }
                     };
-                    // Handle connection.
-                    let res = handle_conn(conn).await;
-                    match res {
-                        Ok(data) => {
-                            grim::on_data(data);
-                            platform.request_user_attention();
-                        },
-                        Err(_) => {}
-                    }
+                    // Handle connection at separate task to not block other connections.
+                    let platform = platform.clone();
+                    tokio::spawn(async move {
+                        let res = handle_conn(conn).await;
+                        match res {
+                            Ok(Some(data)) => {
+                                grim::on_data(data);
+                                platform.request_user_attention();
+                            },
+                            _ => {}
+                        }
+                    });
                 }
             });
     });
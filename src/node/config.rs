@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use core::num::NonZeroU32;
+use std::fs;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Write};
 use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4, TcpListener, ToSocketAddrs};
 use std::path::PathBuf;
 use std::str::FromStr;
 use local_ip_address::list_afinet_netifas;
+use native_tls::{Identity, TlsAcceptor};
 use serde::{Deserialize, Serialize};
 
 use grin_config::{config, ConfigError, ConfigMembers, GlobalConfig};
@@ -26,7 +29,9 @@ use grin_core::global::ChainTypes;
 use grin_p2p::{PeerAddr, Seeding};
 use grin_p2p::msg::PeerAddrs;
 use grin_servers::common::types::ChainValidationMode;
+use grin_util::{from_hex, ToHex};
 use rand::Rng;
+use ring::pbkdf2;
 
 use crate::{AppConfig, Settings};
 use crate::node::Node;
@@ -126,11 +131,124 @@ impl PeersConfig {
     }
 }
 
+/// Credentials of stratum worker allowed to login.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct StratumWorker {
+    /// Worker login.
+    pub login: String,
+    /// PBKDF2 hash of worker password in hex format.
+    pass_hash: String,
+    /// Password hash salt in hex format.
+    #[serde(default)]
+    salt: String,
+}
+
+impl StratumWorker {
+    /// Amount of password hash iterations.
+    const KDF_ITERATIONS: u32 = 10_000;
+
+    /// Create worker credentials from provided login and password.
+    pub fn new(login: String, pass: &str) -> Self {
+        let salt: [u8; 16] = rand::rng().random();
+        let mut hash = [0; 32];
+        pbkdf2::derive(pbkdf2::PBKDF2_HMAC_SHA256,
+                       NonZeroU32::new(Self::KDF_ITERATIONS).unwrap(),
+                       &salt,
+                       pass.as_bytes(),
+                       &mut hash);
+        Self { login, pass_hash: hash.to_hex(), salt: salt.to_hex() }
+    }
+
+    /// Check if provided login and password are matching comparing hashes in constant time.
+    pub fn check(&self, login: &str, pass: &str) -> bool {
+        let (salt, hash) = match (from_hex(&self.salt), from_hex(&self.pass_hash)) {
+            (Ok(salt), Ok(hash)) => (salt, hash),
+            _ => return false
+        };
+        // Credentials without salt are not supported.
+        if self.login != login || salt.is_empty() {
+            return false;
+        }
+        pbkdf2::verify(pbkdf2::PBKDF2_HMAC_SHA256,
+                       NonZeroU32::new(Self::KDF_ITERATIONS).unwrap(),
+                       &salt,
+                       pass.as_bytes(),
+                       &hash).is_ok()
+    }
+}
+
+/// Stratum server config with settings not available at [`ConfigMembers`].
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct StratumConfig {
+    /// Target amount of shares per minute from each worker to adjust its difficulty,
+    /// `0` to use minimum share difficulty for all workers.
+    pub shares_per_minute: u32,
+    /// Flag to start TLS listener next to the plain one.
+    pub tls_enabled: bool,
+    /// TLS listener port.
+    pub tls_port: u16,
+    /// Path to TLS certificate file in PEM format.
+    pub tls_cert_path: String,
+    /// Path to TLS private key file in PKCS #8 PEM format.
+    pub tls_key_path: String,
+    /// Maximum amount of connections from single IP address, `0` for unlimited.
+    pub max_ip_connections: u32,
+    /// Workers allowed to login, any login is accepted if empty.
+    pub workers: Vec<StratumWorker>,
+}
+
+impl Default for StratumConfig {
+    fn default() -> Self {
+        Self {
+            shares_per_minute: 0,
+            tls_enabled: false,
+            tls_port: Self::DEFAULT_TLS_PORT,
+            tls_cert_path: "".to_string(),
+            tls_key_path: "".to_string(),
+            max_ip_connections: 0,
+            workers: vec![],
+        }
+    }
+}
+
+impl StratumConfig {
+    /// File name for stratum config.
+    pub const FILE_NAME: &'static str = "stratum.toml";
+
+    /// Default TLS listener port.
+    const DEFAULT_TLS_PORT: u16 = 3417;
+
+    /// Create TLS acceptor from certificate and private key files.
+    pub fn tls_acceptor(&self) -> Result<TlsAcceptor, String> {
+        let cert = fs::read(&self.tls_cert_path).map_err(|e| e.to_string())?;
+        let key = fs::read(&self.tls_key_path).map_err(|e| e.to_string())?;
+        let identity = Identity::from_pkcs8(&cert, &key).map_err(|e| e.to_string())?;
+        TlsAcceptor::new(identity).map_err(|e| e.to_string())
+    }
+
+    /// Get TLS listener setup error, `None` if TLS is disabled or ready to use.
+    pub fn tls_error(&self) -> Option<String> {
+        if !self.tls_enabled {
+            return None;
+        }
+        self.tls_acceptor().err()
+    }
+
+    /// Save stratum config to the file.
+    pub fn save(&self) {
+        let chain_type = AppConfig::chain_type();
+        let config_path = Settings::config_path(Self::FILE_NAME, Some(chain_type.shortname()));
+        Settings::write_to_file(self, config_path);
+    }
+}
+
 /// Wrapped node config to be used by [`grin_servers::Server`].
 #[derive(Serialize, Deserialize)]
 pub struct NodeConfig {
     pub(crate) node: ConfigMembers,
-    pub(crate) peers: PeersConfig
+    pub(crate) peers: PeersConfig,
+    pub(crate) stratum: StratumConfig
 }
 
 impl NodeConfig {
@@ -152,6 +270,18 @@ impl NodeConfig {
             }
         };
 
+        // Initialize stratum config.
+        let stratum_config = {
+            let sub_dir = Some(chain_type.shortname());
+            let path = Settings::config_path(StratumConfig::FILE_NAME, sub_dir);
+            let config = Settings::read_from_file::<StratumConfig>(path.clone());
+            if !path.exists() || config.is_err() {
+                Self::save_default_stratum_config(chain_type)
+            } else {
+                config.unwrap()
+            }
+        };
+
         // Initialize node config.
         let node_config = {
             let sub_dir = Some(chain_type.shortname());
@@ -164,7 +294,7 @@ impl NodeConfig {
             }
         };
 
-        Self { node: node_config, peers: peers_config }
+        Self { node: node_config, peers: peers_config, stratum: stratum_config }
     }
 
     /// Save default node config for specified [`ChainTypes`].
@@ -235,10 +365,12 @@ impl NodeConfig {
         };
         let node_server_config = Self::save_default_node_server_config(&chain_type);
         let peers_config = Self::save_default_peers_config(&chain_type);
+        let stratum_config = Self::save_default_stratum_config(&chain_type);
         {
             let mut w_config = Settings::node_config_to_update();
             w_config.node = node_server_config;
             w_config.peers = peers_config;
+            w_config.stratum = stratum_config;
         }
     }
 
@@ -436,6 +568,126 @@ impl NodeConfig {
         w_config.save();
     }
 
+    /// Get stratum config to use for stratum server before start.
+    pub fn stratum_config() -> StratumConfig {
+        let r_config = Settings::node_config_to_read();
+        r_config.stratum.clone()
+    }
+
+    /// Get target amount of shares per minute from each worker.
+    pub fn get_stratum_shares_per_minute() -> String {
+        let r_config = Settings::node_config_to_read();
+        r_config.stratum.shares_per_minute.to_string()
+    }
+
+    /// Save target amount of shares per minute from each worker.
+    pub fn save_stratum_shares_per_minute(shares: u32) {
+        let mut w_config = Settings::node_config_to_update();
+        w_config.stratum.shares_per_minute = shares;
+        w_config.stratum.save();
+    }
+
+    /// Check if stratum TLS listener is enabled.
+    pub fn is_stratum_tls_enabled() -> bool {
+        let r_config = Settings::node_config_to_read();
+        r_config.stratum.tls_enabled
+    }
+
+    /// Toggle stratum TLS listener.
+    pub fn toggle_stratum_tls() {
+        let mut w_config = Settings::node_config_to_update();
+        w_config.stratum.tls_enabled = !w_config.stratum.tls_enabled;
+        w_config.stratum.save();
+    }
+
+    /// Get stratum TLS listener port.
+    pub fn get_stratum_tls_port() -> String {
+        let r_config = Settings::node_config_to_read();
+        r_config.stratum.tls_port.to_string()
+    }
+
+    /// Save stratum TLS listener port.
+    pub fn save_stratum_tls_port(port: u16) {
+        let mut w_config = Settings::node_config_to_update();
+        w_config.stratum.tls_port = port;
+        w_config.stratum.save();
+    }
+
+    /// Check if stratum TLS port is available across the system and config.
+    pub fn is_stratum_tls_port_available(port: &String) -> bool {
+        let (ip, stratum_port) = Self::get_stratum_address();
+        if &stratum_port == port {
+            return false;
+        }
+        if Node::get_stratum_stats().is_running && Self::is_stratum_tls_enabled() {
+            // Check if TLS listener with same port is running.
+            if &Self::get_stratum_tls_port() == port {
+                return true;
+            }
+        }
+        Self::is_not_running_stratum_port_available(&ip, port)
+    }
+
+    /// Get path to stratum TLS certificate file.
+    pub fn get_stratum_tls_cert_path() -> String {
+        let r_config = Settings::node_config_to_read();
+        r_config.stratum.tls_cert_path.clone()
+    }
+
+    /// Save path to stratum TLS certificate file.
+    pub fn save_stratum_tls_cert_path(path: String) {
+        let mut w_config = Settings::node_config_to_update();
+        w_config.stratum.tls_cert_path = path;
+        w_config.stratum.save();
+    }
+
+    /// Get path to stratum TLS private key file.
+    pub fn get_stratum_tls_key_path() -> String {
+        let r_config = Settings::node_config_to_read();
+        r_config.stratum.tls_key_path.clone()
+    }
+
+    /// Save path to stratum TLS private key file.
+    pub fn save_stratum_tls_key_path(path: String) {
+        let mut w_config = Settings::node_config_to_update();
+        w_config.stratum.tls_key_path = path;
+        w_config.stratum.save();
+    }
+
+    /// Get maximum amount of stratum connections from single IP address.
+    pub fn get_stratum_max_ip_connections() -> String {
+        let r_config = Settings::node_config_to_read();
+        r_config.stratum.max_ip_connections.to_string()
+    }
+
+    /// Save maximum amount of stratum connections from single IP address.
+    pub fn save_stratum_max_ip_connections(max: u32) {
+        let mut w_config = Settings::node_config_to_update();
+        w_config.stratum.max_ip_connections = max;
+        w_config.stratum.save();
+    }
+
+    /// Get logins of stratum workers allowed to connect.
+    pub fn get_stratum_workers() -> Vec<String> {
+        let r_config = Settings::node_config_to_read();
+        r_config.stratum.workers.iter().map(|w| w.login.clone()).collect()
+    }
+
+    /// Save stratum worker credentials replacing worker with same login.
+    pub fn save_stratum_worker(login: String, pass: &str) {
+        let mut w_config = Settings::node_config_to_update();
+        w_config.stratum.workers.retain(|w| w.login != login);
+        w_config.stratum.workers.push(StratumWorker::new(login, pass));
+        w_config.stratum.save();
+    }
+
+    /// Remove stratum worker credentials.
+    pub fn remove_stratum_worker(login: &String) {
+        let mut w_config = Settings::node_config_to_update();
+        w_config.stratum.workers.retain(|w| &w.login != login);
+        w_config.stratum.save();
+    }
+
     /// Check if stratum mining server autorun is enabled.
     pub fn is_stratum_autorun_enabled() -> bool {
         let r_config = Settings::node_config_to_read();
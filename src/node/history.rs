@@ -0,0 +1,257 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{AppConfig, Settings};
+
+/// Status of the block found by stratum workers.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub enum FoundBlockStatus {
+    /// Block is not deep enough at the chain to be checked.
+    Pending,
+    /// Block is at the main chain.
+    Confirmed,
+    /// Block was replaced at the main chain.
+    Orphan,
+}
+
+/// Block found by stratum workers.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FoundBlock {
+    /// Block height.
+    pub height: u64,
+    /// Block hash in hex format.
+    pub hash: String,
+    /// Block reward with fees.
+    pub reward: u64,
+    /// Login or identifier of the worker.
+    pub worker: String,
+    /// Time when block was found.
+    pub time: i64,
+    /// Block status at the chain.
+    pub status: FoundBlockStatus,
+    /// Flag to check if block reward was split between workers.
+    #[serde(default)]
+    pub paid: bool,
+}
+
+/// Hourly sample of stratum workers shares.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct HistorySample {
+    /// Start time of the hour.
+    pub time: i64,
+    /// Sum of unscaled difficulty of accepted shares.
+    pub difficulty: u64,
+    /// Amount of accepted shares.
+    pub accepted: u64,
+    /// Amount of rejected shares.
+    pub rejected: u64,
+    /// Amount of shares submitted too late.
+    pub stale: u64,
+}
+
+impl HistorySample {
+    /// Amount of seconds in the sample.
+    pub const DURATION: i64 = 60 * 60;
+
+    /// Graphs to search for a solution of unscaled difficulty 1, that is Cuckoo cycle length,
+    /// same multiplier is used by stratum server to calculate network hashrate.
+    const GRAPHS_PER_DIFFICULTY: f64 = 42.0;
+
+    /// Calculate average graphs per second of the workers for the sample at provided time.
+    pub fn hashrate(&self, now: i64) -> f64 {
+        let elapsed = (now - self.time).clamp(1, Self::DURATION);
+        Self::GRAPHS_PER_DIFFICULTY * self.difficulty as f64 / elapsed as f64
+    }
+}
+
+/// Persistent history of stratum mining with found blocks and hourly shares samples.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct MiningHistory {
+    /// Found blocks from oldest to newest.
+    pub blocks: Vec<FoundBlock>,
+    /// Hourly samples from oldest to newest.
+    pub samples: VecDeque<HistorySample>,
+}
+
+impl MiningHistory {
+    /// File name for mining history.
+    pub const FILE_NAME: &'static str = "mining.toml";
+
+    /// Amount of hourly samples to keep.
+    pub const MAX_SAMPLES: usize = 7 * 24;
+
+    /// Amount of blocks on top of found block to check if it is still at the main chain.
+    pub const CONFIRMATION_DEPTH: u64 = 10;
+
+    /// Get mining history file path for current chain type.
+    fn path() -> PathBuf {
+        let chain_type = AppConfig::chain_type();
+        Settings::config_path(Self::FILE_NAME, Some(chain_type.shortname()))
+    }
+
+    /// Load mining history from the file.
+    pub fn load() -> Self {
+        Settings::read_from_file::<MiningHistory>(Self::path()).unwrap_or_default()
+    }
+
+    /// Save mining history to the file.
+    pub fn save(&self) {
+        Settings::write_to_file(self, Self::path());
+    }
+
+    /// Get sample for current hour, create if not exists.
+    fn sample_mut(&mut self) -> &mut HistorySample {
+        let now = chrono::Utc::now().timestamp();
+        let time = now - now % HistorySample::DURATION;
+        if self.samples.back().map(|s| s.time != time).unwrap_or(true) {
+            self.samples.push_back(HistorySample { time, ..Default::default() });
+            while self.samples.len() > Self::MAX_SAMPLES {
+                self.samples.pop_front();
+            }
+        }
+        self.samples.back_mut().unwrap()
+    }
+
+    /// Count accepted share with provided difficulty.
+    pub fn add_accepted(&mut self, difficulty: u64) {
+        let sample = self.sample_mut();
+        sample.accepted += 1;
+        sample.difficulty += difficulty;
+    }
+
+    /// Count rejected share.
+    pub fn add_rejected(&mut self) {
+        self.sample_mut().rejected += 1;
+    }
+
+    /// Count share submitted too late.
+    pub fn add_stale(&mut self) {
+        self.sample_mut().stale += 1;
+    }
+
+    /// Save found block.
+    pub fn add_block(&mut self, height: u64, hash: String, reward: u64, worker: String) {
+        self.blocks.push(FoundBlock {
+            height,
+            hash,
+            reward,
+            worker,
+            time: chrono::Utc::now().timestamp(),
+            status: FoundBlockStatus::Pending,
+            paid: false,
+        });
+    }
+
+    /// Get reward with fees of confirmed blocks which was not split between workers.
+    pub fn unpaid_reward(&self) -> u64 {
+        self.blocks.iter()
+            .filter(|b| b.status == FoundBlockStatus::Confirmed && !b.paid)
+            .map(|b| b.reward)
+            .sum()
+    }
+
+    /// Mark reward of confirmed blocks as split between workers.
+    pub fn mark_paid(&mut self) {
+        for b in self.blocks.iter_mut() {
+            if b.status == FoundBlockStatus::Confirmed {
+                b.paid = true;
+            }
+        }
+    }
+
+    /// Check pending blocks deep enough at the chain with provided head height
+    /// comparing hashes of main chain blocks, returns true if some status was changed.
+    pub fn check_blocks(&mut self,
+                        head_height: u64,
+                        hash_at: impl Fn(u64) -> Option<String>) -> bool {
+        let mut changed = false;
+        for b in self.blocks.iter_mut() {
+            if b.status != FoundBlockStatus::Pending
+                || b.height + Self::CONFIRMATION_DEPTH > head_height {
+                continue;
+            }
+            b.status = match hash_at(b.height) {
+                Some(hash) if hash == b.hash => FoundBlockStatus::Confirmed,
+                _ => FoundBlockStatus::Orphan
+            };
+            changed = true;
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Create history with pending blocks at provided heights.
+    fn history(heights: &[u64]) -> MiningHistory {
+        let mut history = MiningHistory::default();
+        for h in heights {
+            history.add_block(*h, format!("hash{}", h), 60_000_000_000, "worker".to_string());
+        }
+        history
+    }
+
+    /// Get statuses of history blocks.
+    fn statuses(history: &MiningHistory) -> Vec<FoundBlockStatus> {
+        history.blocks.iter().map(|b| b.status.clone()).collect()
+    }
+
+    #[test]
+    fn confirms_blocks_at_main_chain() {
+        let mut history = history(&[100, 105]);
+        assert!(history.check_blocks(110, |h| Some(format!("hash{}", h))));
+        assert!(statuses(&history) == vec![FoundBlockStatus::Confirmed,
+                                            FoundBlockStatus::Pending]);
+        assert!(!history.check_blocks(114, |h| Some(format!("hash{}", h))));
+        assert!(history.check_blocks(115, |h| Some(format!("hash{}", h))));
+        assert!(statuses(&history) == vec![FoundBlockStatus::Confirmed,
+                                            FoundBlockStatus::Confirmed]);
+        assert_eq!(history.unpaid_reward(), 120_000_000_000);
+    }
+
+    #[test]
+    fn marks_replaced_blocks_as_orphan() {
+        let mut history = history(&[100, 101]);
+        let changed = history.check_blocks(200, |h| {
+            if h == 100 { Some("other".to_string()) } else { None }
+        });
+        assert!(changed);
+        assert!(statuses(&history) == vec![FoundBlockStatus::Orphan, FoundBlockStatus::Orphan]);
+        assert_eq!(history.unpaid_reward(), 0);
+        // Checked blocks are not changed later.
+        assert!(!history.check_blocks(300, |h| Some(format!("hash{}", h))));
+    }
+
+    #[test]
+    fn calculates_hashrate() {
+        let sample = HistorySample {
+            time: 3600,
+            difficulty: 600,
+            accepted: 10,
+            ..Default::default()
+        };
+        // 42 graphs per unscaled difficulty during elapsed time.
+        assert_eq!(sample.hashrate(3600 + 60), 42.0 * 600.0 / 60.0);
+        // Elapsed time is limited by sample duration.
+        assert_eq!(sample.hashrate(3600 * 3), 42.0 * 600.0 / 3600.0);
+        assert_eq!(sample.hashrate(3600), 42.0 * 600.0);
+        assert_eq!(HistorySample::default().hashrate(60), 0.0);
+    }
+}
@@ -22,4 +22,10 @@ mod config;
 pub use config::*;
 
 mod types;
-pub use types::*;
\ No newline at end of file
+pub use types::*;
+
+mod shares;
+pub use shares::*;
+
+mod history;
+pub use history::*;
\ No newline at end of file
@@ -29,7 +29,7 @@ use grin_p2p::Seeding;
 use grin_servers::{Server, ServerStats, StratumServerConfig, StratumStats};
 use grin_servers::common::types::Error;
 
-use crate::node::{NodeConfig, NodeError, PeersConfig};
+use crate::node::{MiningHistory, NodeConfig, NodeError, PeersConfig, PplnsReport, ShareAccounting};
 use crate::node::stratum::{StratumStopState, StratumServer};
 
 lazy_static! {
@@ -44,6 +44,12 @@ pub struct Node {
 
     /// [`StratumServer`] statistics information.
     stratum_stats: Arc<grin_util::RwLock<StratumStats>>,
+    /// [`StratumServer`] share accounting of workers.
+    stratum_shares: Arc<grin_util::RwLock<ShareAccounting>>,
+    /// [`StratumServer`] history of found blocks and shares.
+    stratum_history: Arc<grin_util::RwLock<MiningHistory>>,
+    /// [`StratumServer`] TLS listener start error.
+    stratum_tls_error: Arc<grin_util::RwLock<Option<String>>>,
     /// Flag to start [`StratumServer`].
     start_stratum_needed: AtomicBool,
     /// State to stop [`StratumServer`] from outside.
@@ -69,6 +75,9 @@ impl Default for Node {
         Self {
             stats: Arc::new(RwLock::new(None)),
             stratum_stats: Arc::new(grin_util::RwLock::new(StratumStats::default())),
+            stratum_shares: Arc::new(grin_util::RwLock::new(ShareAccounting::default())),
+            stratum_history: Arc::new(grin_util::RwLock::new(MiningHistory::default())),
+            stratum_tls_error: Arc::new(grin_util::RwLock::new(None)),
             stratum_stop_state: Arc::new(StratumStopState::default()),
             starting: AtomicBool::new(false),
             restart_needed: AtomicBool::new(false),
@@ -132,6 +141,53 @@ impl Node {
         NODE_STATE.stratum_stats.read().clone()
     }
 
+    /// Get [`StratumServer`] TLS listener start error.
+    pub fn get_stratum_tls_error() -> Option<String> {
+        NODE_STATE.stratum_tls_error.read().clone()
+    }
+
+    /// Get [`StratumServer`] share accounting of workers.
+    pub fn get_stratum_shares() -> ShareAccounting {
+        if Self::get_stratum_stats().is_running {
+            NODE_STATE.stratum_shares.read().clone()
+        } else {
+            ShareAccounting::load()
+        }
+    }
+
+    /// Get [`StratumServer`] history of found blocks and shares.
+    pub fn get_mining_history() -> MiningHistory {
+        if Self::get_stratum_stats().is_running {
+            NODE_STATE.stratum_history.read().clone()
+        } else {
+            MiningHistory::load()
+        }
+    }
+
+    /// Get [`StratumServer`] report to split reward of confirmed unpaid blocks between workers.
+    pub fn get_pplns_report() -> PplnsReport {
+        let reward = Self::get_mining_history().unpaid_reward();
+        Self::get_stratum_shares().pplns_report(reward)
+    }
+
+    /// Split reward of confirmed unpaid blocks between workers, marking blocks as paid and
+    /// clearing PPLNS window, returns `None` if there is nothing to pay.
+    pub fn pay_pplns_report() -> Option<PplnsReport> {
+        let pay = |shares: &mut ShareAccounting, history: &mut MiningHistory| {
+            let report = shares.pay_pplns(history)?;
+            history.save();
+            shares.save();
+            Some(report)
+        };
+        if Self::get_stratum_stats().is_running {
+            let mut w_shares = NODE_STATE.stratum_shares.write();
+            let mut w_history = NODE_STATE.stratum_history.write();
+            pay(&mut w_shares, &mut w_history)
+        } else {
+            pay(&mut ShareAccounting::load(), &mut MiningHistory::load())
+        }
+    }
+
     /// Stop [`StratumServer`].
     pub fn stop_stratum() {
         NODE_STATE.stratum_stop_state.stop()
@@ -291,7 +347,10 @@ impl Node {
                         let stratum_start_requested = Self::is_stratum_starting();
                         if stratum_start_requested {
                             let (s_ip, s_port) = NodeConfig::get_stratum_address();
-                            if NodeConfig::is_stratum_port_available(&s_ip, &s_port) {
+                            if NodeConfig::stratum_config().tls_error().is_some() {
+                                // Do not start without enabled TLS listener.
+                                NODE_STATE.start_stratum_needed.store(false, Ordering::Relaxed);
+                            } else if NodeConfig::is_stratum_port_available(&s_ip, &s_port) {
                                 let stratum_config = server
                                     .config
                                     .stratum_mining_config
@@ -626,17 +685,33 @@ pub fn start_stratum_mining_server(server: &Server, config: StratumServerConfig)
     let proof_size = global::proofsize();
     let sync_state = server.sync_state.clone();
 
+    // Load share accounting and mining history.
+    {
+        let mut w_shares = NODE_STATE.stratum_shares.write();
+        *w_shares = ShareAccounting::load();
+        let mut w_history = NODE_STATE.stratum_history.write();
+        *w_history = MiningHistory::load();
+        let mut w_tls_error = NODE_STATE.stratum_tls_error.write();
+        *w_tls_error = None;
+    }
+
     let mut stratum_server = StratumServer::new(
         config,
+        NodeConfig::stratum_config(),
         server.chain.clone(),
         server.tx_pool.clone(),
         NODE_STATE.stratum_stats.clone(),
+        NODE_STATE.stratum_shares.clone(),
+        NODE_STATE.stratum_history.clone(),
+        NODE_STATE.stratum_tls_error.clone(),
     );
     let stop_state = NODE_STATE.stratum_stop_state.clone();
     stop_state.reset();
     let server_state = stop_state.clone();
     thread::spawn(move || {
             stratum_server.run_loop(proof_size, sync_state, stop_state);
+            NODE_STATE.stratum_shares.read().save();
+            NODE_STATE.stratum_history.read().save();
             server_state.reset();
             // Reset stratum stats.
             {
@@ -0,0 +1,350 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use grin_core::core::amount_to_hr_string;
+use grin_wallet_libwallet::SlatepackAddress;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::node::MiningHistory;
+use crate::{AppConfig, Settings};
+
+/// Accepted share at PPLNS window.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PplnsShare {
+    /// Worker login.
+    pub login: String,
+    /// Unscaled share difficulty requested from worker.
+    pub difficulty: u64,
+    /// Block height of the share.
+    pub height: u64,
+}
+
+/// Share statistics of stratum worker login.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct LoginShares {
+    /// Worker login.
+    pub login: String,
+    /// Amount of accepted shares.
+    pub accepted: u64,
+    /// Amount of rejected shares.
+    pub rejected: u64,
+    /// Amount of shares submitted too late.
+    pub stale: u64,
+    /// Sum of unscaled difficulty of accepted shares.
+    pub difficulty: u64,
+    /// Heights of found blocks.
+    pub blocks: Vec<u64>,
+}
+
+/// Persistent share accounting of stratum workers by login,
+/// shares from workers without login are not counted.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct ShareAccounting {
+    /// Statistics of each login.
+    pub logins: Vec<LoginShares>,
+    /// Last accepted shares to split rewards.
+    pub window: VecDeque<PplnsShare>,
+}
+
+impl ShareAccounting {
+    /// File name for share accounting.
+    pub const FILE_NAME: &'static str = "shares.toml";
+
+    /// Amount of last shares to split rewards.
+    pub const PPLNS_WINDOW: usize = 1000;
+
+    /// Get share accounting file path for current chain type.
+    fn path() -> PathBuf {
+        let chain_type = AppConfig::chain_type();
+        Settings::config_path(Self::FILE_NAME, Some(chain_type.shortname()))
+    }
+
+    /// Load share accounting from the file.
+    pub fn load() -> Self {
+        Settings::read_from_file::<ShareAccounting>(Self::path()).unwrap_or_default()
+    }
+
+    /// Save share accounting to the file.
+    pub fn save(&self) {
+        Settings::write_to_file(self, Self::path());
+    }
+
+    /// Get statistics for provided login, create if not exists.
+    fn login_mut(&mut self, login: &String) -> &mut LoginShares {
+        let index = match self.logins.iter().position(|l| &l.login == login) {
+            Some(i) => i,
+            None => {
+                self.logins.push(LoginShares {
+                    login: login.clone(),
+                    ..Default::default()
+                });
+                self.logins.len() - 1
+            }
+        };
+        &mut self.logins[index]
+    }
+
+    /// Count accepted share with provided difficulty.
+    pub fn add_accepted(&mut self, login: &String, difficulty: u64, height: u64) {
+        let shares = self.login_mut(login);
+        shares.accepted += 1;
+        shares.difficulty += difficulty;
+        self.window.push_back(PplnsShare { login: login.clone(), difficulty, height });
+        while self.window.len() > Self::PPLNS_WINDOW {
+            self.window.pop_front();
+        }
+    }
+
+    /// Count rejected share.
+    pub fn add_rejected(&mut self, login: &String) {
+        self.login_mut(login).rejected += 1;
+    }
+
+    /// Count share submitted too late.
+    pub fn add_stale(&mut self, login: &String) {
+        self.login_mut(login).stale += 1;
+    }
+
+    /// Save found block height.
+    pub fn add_block(&mut self, login: &String, height: u64) {
+        self.login_mut(login).blocks.push(height);
+    }
+
+    /// Clear PPLNS window after split of the reward.
+    pub fn clear_window(&mut self) {
+        self.window.clear();
+    }
+
+    /// Split reward of confirmed unpaid blocks from provided history between logins,
+    /// marking blocks as paid and clearing PPLNS window, returns `None` if nothing to pay.
+    pub fn pay_pplns(&mut self, history: &mut MiningHistory) -> Option<PplnsReport> {
+        let report = self.pplns_report(history.unpaid_reward());
+        if !report.can_pay() {
+            return None;
+        }
+        history.mark_paid();
+        self.clear_window();
+        Some(report)
+    }
+
+    /// Split provided reward between logins by difficulty of shares at PPLNS window,
+    /// shares of logins which are not Slatepack addresses are not paid.
+    pub fn pplns_report(&self, reward: u64) -> PplnsReport {
+        let mut invalid: Vec<String> = vec![];
+        let mut payouts: Vec<PplnsPayout> = vec![];
+        for share in &self.window {
+            if invalid.contains(&share.login) {
+                continue;
+            }
+            if SlatepackAddress::try_from(share.login.as_str()).is_err() {
+                invalid.push(share.login.clone());
+                continue;
+            }
+            match payouts.iter_mut().find(|p| p.login == share.login) {
+                Some(p) => p.difficulty += share.difficulty,
+                None => payouts.push(PplnsPayout {
+                    login: share.login.clone(),
+                    difficulty: share.difficulty,
+                    amount: 0,
+                })
+            }
+        }
+        payouts.sort_by_key(|p| std::cmp::Reverse(p.difficulty));
+        let total: u64 = payouts.iter().map(|p| p.difficulty).sum();
+        if total > 0 {
+            for p in payouts.iter_mut() {
+                p.amount = (reward as u128 * p.difficulty as u128 / total as u128) as u64;
+            }
+            // Give remainder to the login with most shares.
+            let paid: u64 = payouts.iter().map(|p| p.amount).sum();
+            if let Some(p) = payouts.first_mut() {
+                p.amount += reward - paid;
+            }
+        }
+        PplnsReport {
+            reward,
+            logins: self.logins.clone(),
+            payouts,
+            invalid,
+        }
+    }
+}
+
+/// Reward part for the login.
+#[derive(Clone)]
+pub struct PplnsPayout {
+    /// Worker login.
+    pub login: String,
+    /// Sum of shares difficulty at PPLNS window.
+    pub difficulty: u64,
+    /// Amount of reward to pay.
+    pub amount: u64,
+}
+
+/// Report to pay reward to workers based on last accepted shares.
+#[derive(Clone)]
+pub struct PplnsReport {
+    /// Reward to split.
+    pub reward: u64,
+    /// Share statistics of each login.
+    pub logins: Vec<LoginShares>,
+    /// Reward parts for logins from the window.
+    pub payouts: Vec<PplnsPayout>,
+    /// Logins from the window which are not Slatepack addresses to pay.
+    pub invalid: Vec<String>,
+}
+
+impl PplnsReport {
+    /// Report file extension.
+    pub const FILE_EXTENSION: &'static str = "csv";
+
+    /// Check if there is amount to pay.
+    pub fn can_pay(&self) -> bool {
+        self.payouts.iter().any(|p| p.amount > 0)
+    }
+
+    /// Convert report to CSV text with share statistics and amount to pay for each login,
+    /// logins which are not Slatepack addresses are marked as not payable.
+    pub fn to_csv(&self) -> String {
+        let mut csv = "login,accepted,rejected,stale,difficulty,blocks,pplns_difficulty,amount,\
+            payable\n".to_string();
+        for l in &self.logins {
+            let payout = self.payouts.iter().find(|p| p.login == l.login);
+            let (pplns_difficulty, amount) = match payout {
+                Some(p) => (p.difficulty, p.amount),
+                None => (0, 0)
+            };
+            let payable = !self.invalid.contains(&l.login);
+            csv.push_str(format!("{},{},{},{},{},{},{},{},{}\n",
+                                 l.login.replace(',', " "),
+                                 l.accepted,
+                                 l.rejected,
+                                 l.stale,
+                                 l.difficulty,
+                                 l.blocks.len(),
+                                 pplns_difficulty,
+                                 amount_to_hr_string(amount, true),
+                                 payable).as_str());
+        }
+        csv
+    }
+
+    /// Convert payouts to CSV text with address and amount for batch payout at the wallet.
+    pub fn payouts_csv(&self) -> String {
+        let mut csv = "address,amount\n".to_string();
+        for p in self.payouts.iter().filter(|p| p.amount > 0) {
+            csv.push_str(format!("{},{}\n",
+                                 p.login,
+                                 amount_to_hr_string(p.amount, true)).as_str());
+        }
+        csv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::BatchPayout;
+
+    const ADDRESS_1: &str = "grin1tpnxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenqnr5dak";
+    const ADDRESS_2: &str = "grin1ex3ls64wge0su4j38pj9zreejatplgkfap02y8wz9y3snu7dvq3q2xxzk9";
+
+    fn accounting(shares: &[(&str, u64)]) -> ShareAccounting {
+        let mut accounting = ShareAccounting::default();
+        for (login, difficulty) in shares {
+            accounting.add_accepted(&login.to_string(), *difficulty, 1);
+        }
+        accounting
+    }
+
+    #[test]
+    fn splits_reward_by_difficulty() {
+        let accounting = accounting(&[(ADDRESS_1, 3), (ADDRESS_2, 1), (ADDRESS_1, 4)]);
+        let report = accounting.pplns_report(8_000);
+        assert_eq!(report.payouts.len(), 2);
+        assert_eq!(report.payouts[0].login, ADDRESS_1);
+        assert_eq!(report.payouts[0].difficulty, 7);
+        assert_eq!(report.payouts[0].amount, 7_000);
+        assert_eq!(report.payouts[1].amount, 1_000);
+    }
+
+    #[test]
+    fn gives_rounding_remainder_to_top_login() {
+        let accounting = accounting(&[(ADDRESS_1, 1), (ADDRESS_2, 1), (ADDRESS_1, 1)]);
+        let report = accounting.pplns_report(100);
+        assert_eq!(report.payouts[0].amount, 67);
+        assert_eq!(report.payouts[1].amount, 33);
+        let paid: u64 = report.payouts.iter().map(|p| p.amount).sum();
+        assert_eq!(paid, 100);
+    }
+
+    #[test]
+    fn skips_logins_without_address() {
+        let accounting = accounting(&[(ADDRESS_1, 1), ("rig1", 5), (ADDRESS_2, 1)]);
+        let report = accounting.pplns_report(60_000_000_001);
+        assert_eq!(report.invalid, vec!["rig1".to_string()]);
+        assert_eq!(report.payouts.len(), 2);
+        let paid: u64 = report.payouts.iter().map(|p| p.amount).sum();
+        assert_eq!(paid, 60_000_000_001);
+        assert!(report.to_csv().lines().any(|l| l.starts_with("rig1,") && l.ends_with(",false")));
+    }
+
+    #[test]
+    fn empty_window_has_no_payouts() {
+        let report = ShareAccounting::default().pplns_report(60_000_000_000);
+        assert!(report.payouts.is_empty());
+        assert!(!report.can_pay());
+        assert_eq!(report.payouts_csv(), "address,amount\n");
+
+        let mut accounting = accounting(&[(ADDRESS_1, 1)]);
+        accounting.clear_window();
+        assert!(accounting.pplns_report(60_000_000_000).payouts.is_empty());
+        assert_eq!(accounting.logins[0].accepted, 1);
+    }
+
+    #[test]
+    fn pays_confirmed_blocks_once() {
+        let mut history = MiningHistory::default();
+        history.add_block(10, "hash10".to_string(), 60_000_000_100, ADDRESS_1.to_string());
+        history.add_block(20, "hash20".to_string(), 60_000_000_000, ADDRESS_1.to_string());
+        history.check_blocks(25, |h| Some(format!("hash{}", h)));
+
+        // Pay nothing without confirmed blocks.
+        let mut accounting = accounting(&[(ADDRESS_1, 1), (ADDRESS_2, 1)]);
+        assert!(accounting.pay_pplns(&mut MiningHistory::default()).is_none());
+        assert_eq!(accounting.window.len(), 2);
+
+        let report = accounting.pay_pplns(&mut history).unwrap();
+        assert_eq!(report.reward, 60_000_000_100);
+        assert!(history.blocks[0].paid);
+        assert!(!history.blocks[1].paid);
+        assert!(accounting.window.is_empty());
+        assert!(accounting.pay_pplns(&mut history).is_none());
+    }
+
+    #[test]
+    fn payouts_are_read_by_batch_payout() {
+        let accounting = accounting(&[(ADDRESS_1, 2), ("rig1", 1), (ADDRESS_2, 1)]);
+        let report = accounting.pplns_report(60_123_456_789);
+        let batch = BatchPayout::from_csv(&report.payouts_csv()).unwrap();
+        assert_eq!(batch.rows.len(), 2);
+        for (row, payout) in batch.rows.iter().zip(report.payouts.iter()) {
+            assert_eq!(row.address, payout.login);
+            assert_eq!(row.amount, payout.amount);
+        }
+        assert_eq!(batch.amount_to_send(), 60_123_456_789);
+    }
+}
@@ -17,15 +17,17 @@
 use futures::channel::mpsc;
 use futures::pin_mut;
 use futures::{SinkExt, StreamExt, TryStreamExt};
+use tokio_old::io::{AsyncRead, AsyncWrite};
 use tokio_old::net::TcpListener;
 use tokio_old::runtime::Runtime;
 use tokio_util_old::codec::{Framed, LinesCodec};
+use tokio_tls_old::TlsAcceptor;
 
 use grin_util::RwLock;
 use chrono::prelude::Utc;
 use serde_json::Value;
 use std::collections::HashMap;
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread;
@@ -35,6 +37,7 @@ use futures::future::{abortable, AbortHandle};
 use grin_chain::{self, SyncState};
 use grin_servers::common::stats::{StratumStats, WorkerStats};
 use grin_servers::common::types::StratumServerConfig;
+use grin_core::consensus;
 use grin_core::consensus::graph_weight;
 use grin_core::core::hash::Hashed;
 use grin_core::core::Block;
@@ -46,10 +49,24 @@ use grin_servers::ServerTxPool;
 use log::{debug, error};
 use serde_derive::{Deserialize, Serialize};
 use crate::node::mine_block::get_block;
+use crate::node::{MiningHistory, ShareAccounting, StratumConfig, StratumWorker};
 use crate::wallet::WalletConfig;
 
 type Tx = mpsc::UnboundedSender<String>;
 
+// Minimal amount of seconds between worker difficulty changes
+const VARDIFF_RETARGET_TIME: i64 = 30;
+// Maximal multiplier of worker difficulty at single change
+const VARDIFF_MAX_FACTOR: f64 = 4.0;
+// Allowed deviation multiplier of shares rate to keep worker difficulty
+const VARDIFF_TOLERANCE: f64 = 1.25;
+// Amount of seconds between saving of share accounting and mining history
+const SHARES_SAVE_INTERVAL: i64 = 60;
+// Amount of seconds for worker to login when credentials are required
+const LOGIN_TIMEOUT: u64 = 30;
+// Amount of seconds for TLS handshake
+const TLS_HANDSHAKE_TIMEOUT: u64 = 10;
+
 // ----------------------------------------
 // http://www.jsonrpc.org/specification
 // RPC Methods
@@ -129,6 +146,18 @@ impl RpcError {
             message: "Invalid Request".to_string(),
         }
     }
+    pub fn unauthorized() -> Self {
+        RpcError {
+            code: -32500,
+            message: "Unauthorized".to_string(),
+        }
+    }
+    pub fn too_many_connections() -> Self {
+        RpcError {
+            code: -32504,
+            message: "Too many connections from IP address".to_string(),
+        }
+    }
 }
 
 impl From<RpcError> for Value {
@@ -163,7 +192,7 @@ struct SubmitParams {
     pow: Vec<u64>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct JobTemplate {
     height: u64,
     job_id: u64,
@@ -188,18 +217,16 @@ struct State {
     // nothing has changed. We only want to create a key_id for each new block,
     // and reuse it when we rebuild the current block to add new tx.
     current_key_id: Option<grin_keychain::Identifier>,
-    current_difficulty: u64,       // scaled
-    minimum_share_difficulty: u64, // unscaled
+    current_difficulty: u64, // scaled
 }
 
 impl State {
-    pub fn new(minimum_share_difficulty: u64) -> Self {
+    pub fn new() -> Self {
         let blocks = vec![Block::default()];
         State {
             current_block_versions: blocks,
             current_key_id: None,
             current_difficulty: <u64>::max_value(),
-            minimum_share_difficulty: minimum_share_difficulty,
         }
     }
 }
@@ -246,24 +273,34 @@ impl Handler {
     pub fn new(
         id: String,
         stratum_stats: Arc<RwLock<StratumStats>>,
+        shares: Arc<RwLock<ShareAccounting>>,
+        history: Arc<RwLock<MiningHistory>>,
         sync_state: Arc<SyncState>,
         minimum_share_difficulty: u64,
+        stratum_config: &StratumConfig,
         chain: Arc<grin_chain::Chain>,
     ) -> Self {
         Handler {
             id: id,
-            workers: Arc::new(WorkersList::new(stratum_stats)),
+            workers: Arc::new(WorkersList::new(stratum_stats,
+                                               shares,
+                                               history,
+                                               minimum_share_difficulty,
+                                               stratum_config)),
             sync_state: sync_state,
             chain: chain,
-            current_state: Arc::new(RwLock::new(State::new(minimum_share_difficulty))),
+            current_state: Arc::new(RwLock::new(State::new())),
         }
     }
     pub fn from_stratum(stratum: &StratumServer) -> Self {
         Handler::new(
             stratum.id.clone(),
             stratum.stratum_stats.clone(),
+            stratum.shares.clone(),
+            stratum.history.clone(),
             stratum.sync_state.clone(),
             stratum.config.minimum_share_difficulty,
+            &stratum.stratum_config,
             stratum.chain.clone(),
         )
     }
@@ -273,20 +310,29 @@ impl Handler {
         // Call the handler function for requested method
         let response = match request.method.as_str() {
             "login" => self.handle_login(request.params, worker_id),
+            // Worker should login first when credentials are required
+            _ if self.workers.check_auth(worker_id).is_err() => Err(RpcError::unauthorized()),
+            "keepalive" => self.handle_keepalive(),
             "submit" => {
                 let res = self.handle_submit(request.params, worker_id);
                 // this key_id has been used now, reset
                 if let Ok((_, true)) = res {
                     self.current_state.write().current_key_id = None;
                 }
+                // send job with new difficulty if worker submits shares too often or too rarely
+                if res.is_ok() {
+                    self.workers.add_share(worker_id);
+                    if self.workers.retarget_difficulty(worker_id) {
+                        self.send_job(worker_id);
+                    }
+                }
                 res.map(|(v, _)| v)
             }
-            "keepalive" => self.handle_keepalive(),
             "getjobtemplate" => {
                 if self.sync_state.is_syncing() {
                     Err(RpcError::node_is_syncing())
                 } else {
-                    self.handle_getjobtemplate()
+                    self.handle_getjobtemplate(worker_id)
                 }
             }
             "status" => self.handle_status(worker_id),
@@ -317,7 +363,7 @@ impl Handler {
     }
     fn handle_login(&self, params: Option<Value>, worker_id: usize) -> Result<Value, RpcError> {
         let params: LoginParams = parse_params(params)?;
-        self.workers.login(worker_id, params.login, params.agent)?;
+        self.workers.login(worker_id, params.login, params.pass, params.agent)?;
         return Ok("ok".into());
     }
 
@@ -348,9 +394,11 @@ impl Handler {
         return Ok(response);
     }
     // Handle GETJOBTEMPLATE message
-    fn handle_getjobtemplate(&self) -> Result<Value, RpcError> {
+    fn handle_getjobtemplate(&self, worker_id: usize) -> Result<Value, RpcError> {
         // Build a JobTemplate from a BlockHeader and return JSON
-        let job_template = self.build_block_template();
+        let difficulty = self.workers.get_worker(worker_id)?.difficulty;
+        let job_template = self.build_block_template(difficulty);
+        self.workers.add_job(worker_id, &job_template);
         let response = serde_json::to_value(&job_template).unwrap();
         println!(
 			"(Server ID: {}) sending block {} with id {} to single worker",
@@ -360,7 +408,8 @@ impl Handler {
     }
 
     // Build and return a JobTemplate for mining the current block
-    fn build_block_template(&self) -> JobTemplate {
+    // with provided unscaled share difficulty
+    fn build_block_template(&self, difficulty: u64) -> JobTemplate {
         let bh = self
             .current_state
             .read()
@@ -381,7 +430,7 @@ impl Handler {
         let job_template = JobTemplate {
             height: bh.height,
             job_id: (current_state.current_block_versions.len() - 1) as u64,
-            difficulty: current_state.minimum_share_difficulty,
+            difficulty,
             pre_pow,
         };
         return job_template;
@@ -411,6 +460,7 @@ impl Handler {
 					self.id, params.height, params.edge_bits, params.nonce, params.job_id,
 				);
             self.workers.update_stats(worker_id, |ws| ws.num_stale += 1);
+            self.workers.add_stale(worker_id);
             return Err(RpcError::too_late());
         }
 
@@ -432,23 +482,29 @@ impl Handler {
 				);
             self.workers
                 .update_stats(worker_id, |worker_stats| worker_stats.num_rejected += 1);
+            self.workers.add_rejected(worker_id);
             return Err(RpcError::cannot_validate());
         }
 
         // Get share difficulty values
         scaled_share_difficulty = b.header.pow.to_difficulty(b.header.height).to_num();
         unscaled_share_difficulty = b.header.pow.to_unscaled_difficulty().to_num();
-        // Note:  state.minimum_share_difficulty is unscaled
+        let worker_share_difficulty = self
+            .workers
+            .get_worker(worker_id)?
+            .job_difficulty(params.height, params.job_id);
+        // Note:  worker share difficulty is unscaled
         //        state.current_difficulty is scaled
         // If the difficulty is too low its an error
-        if unscaled_share_difficulty < state.minimum_share_difficulty {
+        if unscaled_share_difficulty < worker_share_difficulty {
             // Return error status
             println!(
 					"(Server ID: {}) Share at height {}, hash {}, edge_bits {}, nonce {}, job_id {} rejected due to low difficulty: {}/{}",
-					self.id, params.height, b.hash(), params.edge_bits, params.nonce, params.job_id, unscaled_share_difficulty, state.minimum_share_difficulty,
+					self.id, params.height, b.hash(), params.edge_bits, params.nonce, params.job_id, unscaled_share_difficulty, worker_share_difficulty,
 				);
             self.workers
                 .update_stats(worker_id, |worker_stats| worker_stats.num_rejected += 1);
+            self.workers.add_rejected(worker_id);
             return Err(RpcError::too_low_difficulty());
         }
 
@@ -470,12 +526,16 @@ impl Handler {
 					);
                 self.workers
                     .update_stats(worker_id, |worker_stats| worker_stats.num_rejected += 1);
+                self.workers.add_rejected(worker_id);
                 return Err(RpcError::cannot_validate());
             }
             share_is_block = true;
             self.workers
                 .update_stats(worker_id, |worker_stats| worker_stats.num_blocks_found += 1);
             self.workers.stratum_stats.write().blocks_found += 1;
+            let reward = consensus::reward(b.total_fees());
+            self.workers.add_block(worker_id, params.height, b.hash().to_hex(), reward);
+            self.workers.save_mining_data();
             // Log message to make it obvious we found a block
             let stats = self.workers.get_stats(worker_id)?;
             println!(
@@ -503,6 +563,7 @@ impl Handler {
 					);
                 self.workers
                     .update_stats(worker_id, |worker_stats| worker_stats.num_rejected += 1);
+                self.workers.add_rejected(worker_id);
                 return Err(RpcError::cannot_validate());
             }
         }
@@ -528,6 +589,7 @@ impl Handler {
 			);
         self.workers
             .update_stats(worker_id, |worker_stats| worker_stats.num_accepted += 1);
+        self.workers.add_accepted(worker_id, worker_share_difficulty, b.header.height);
         let submit_response = if share_is_block {
             format!("blockfound - {}", b.hash().to_hex())
         } else {
@@ -539,11 +601,9 @@ impl Handler {
         ));
     } // handle submit a solution
 
-    fn broadcast_job(&self) {
-        debug!("broadcast job");
-        // Package new block into RpcRequest
-        let job_template = self.build_block_template();
-        let job_template_json = serde_json::to_string(&job_template).unwrap();
+    // Package JobTemplate into RpcRequest json
+    fn job_request_json(job_template: &JobTemplate) -> String {
+        let job_template_json = serde_json::to_string(job_template).unwrap();
         // Issue #1159 - use a serde_json Value type to avoid extra quoting
         let job_template_value: Value = serde_json::from_str(&job_template_json).unwrap();
         let job_request = RpcRequest {
@@ -552,12 +612,33 @@ impl Handler {
             method: String::from("job"),
             params: Some(job_template_value),
         };
-        let job_request_json = serde_json::to_string(&job_request).unwrap();
+        serde_json::to_string(&job_request).unwrap()
+    }
+
+    // Send current job to single worker with its difficulty
+    fn send_job(&self, worker_id: usize) {
+        if let Ok(worker) = self.workers.get_worker(worker_id) {
+            let job_template = self.build_block_template(worker.difficulty);
+            debug!(
+				"(Server ID: {}) sending block {} with id {} and difficulty {} to worker {}",
+				self.id, job_template.height, job_template.job_id, worker.difficulty, worker_id,
+			);
+            self.workers.add_job(worker_id, &job_template);
+            self.workers.send_to(worker_id, Self::job_request_json(&job_template));
+        }
+    }
+
+    fn broadcast_job(&self) {
+        debug!("broadcast job");
+        // Adjust difficulty of workers before sending new block
+        self.workers.retarget_all();
+        // Package new block into RpcRequest with difficulty of each worker
+        let job_template = self.build_block_template(self.workers.minimum_difficulty);
         debug!(
 			"(Server ID: {}) sending block {} with id {} to stratum clients",
 			self.id, job_template.height, job_template.job_id,
 		);
-        self.workers.broadcast(job_request_json);
+        self.workers.broadcast_with(&job_template, Self::job_request_json);
     }
 
     pub fn run(&self,
@@ -566,8 +647,10 @@ impl Handler {
                stop_state: Arc<StratumStopState>) {
         debug!("Run main loop");
         let mut deadline: i64 = 0;
+        let mut shares_save_deadline = Utc::now().timestamp() + SHARES_SAVE_INTERVAL;
         let mut head = self.chain.head().unwrap();
         let mut current_hash = head.prev_block_h;
+        let mut checked_hash = head.prev_block_h;
         loop {
             if stop_state.is_stopped() {
                 thread::sleep(Duration::from_millis(1500));
@@ -577,6 +660,12 @@ impl Handler {
             head = self.chain.head().unwrap();
             let latest_hash = head.last_block_h;
 
+            // Check found blocks at the main chain on new block
+            if checked_hash != latest_hash {
+                self.workers.check_found_blocks(&self.chain, head.height);
+                checked_hash = latest_hash;
+            }
+
             // Build a new block if there is at least one worker and
             // There is a new block on the chain or its time to rebuild
             // the current one to include new transactions
@@ -618,8 +707,6 @@ impl Handler {
                         state.current_key_id = block_fees.key_id();
 
                         current_hash = latest_hash;
-                        // set the minimum acceptable share unscaled difficulty for this block
-                        state.minimum_share_difficulty = config.minimum_share_difficulty;
 
                         // set a new deadline for rebuilding with fresh transactions
                         deadline = Utc::now().timestamp() + config.attempt_time_per_block as i64;
@@ -646,6 +733,12 @@ impl Handler {
                 self.broadcast_job();
             }
 
+            // Save share accounting and mining history
+            if Utc::now().timestamp() >= shares_save_deadline {
+                self.workers.save_mining_data();
+                shares_save_deadline = Utc::now().timestamp() + SHARES_SAVE_INTERVAL;
+            }
+
             // sleep before restarting loop
             thread::sleep(Duration::from_millis(5));
         } // Main Loop
@@ -655,10 +748,13 @@ impl Handler {
 // ----------------------------------------
 // Worker Factory Thread Function
 fn accept_connections(listen_addr: SocketAddr,
+                      tls: Option<(SocketAddr, TlsAcceptor)>,
+                      tls_error: Arc<RwLock<Option<String>>>,
                       handler: Arc<Handler>,
                       stop_state: Arc<StratumStopState>) {
     debug!("Start tokio stratum server");
-    let task = async move {
+    let h = handler.clone();
+    let plain = async move {
         let mut listener = TcpListener::bind(&listen_addr).await.unwrap_or_else(|_| {
             panic!("Stratum: Failed to bind to listen address {}", listen_addr)
         });
@@ -666,62 +762,156 @@ fn accept_connections(listen_addr: SocketAddr,
             .incoming()
             .filter_map(|s| async { s.map_err(|e| error!("accept error = {:?}", e)).ok() })
             .for_each(move |socket| {
-                let handler = handler.clone();
+                let handler = h.clone();
                 async move {
-                    // Spawn a task to process the connection
-                    let (tx, mut rx) = mpsc::unbounded();
-
-                    let worker_id = handler.workers.add_worker(tx);
-                    debug!("Worker {} connected", worker_id);
-
-                    let framed = Framed::new(socket, LinesCodec::new());
-                    let (mut writer, mut reader) = framed.split();
-
-                    let h = handler.clone();
-                    let read = async move {
-                        while let Some(line) = reader
-                            .try_next()
-                            .await
-                            .map_err(|e| debug!("error reading line: {}", e))?
-                        {
-                            let request = serde_json::from_str(&line)
-                                .map_err(|e| debug!("error serializing line: {}", e))?;
-                            let resp = h.handle_rpc_requests(request, worker_id);
-                            h.workers.send_to(worker_id, resp);
-                        }
-
-                        Result::<_, ()>::Ok(())
-                    };
-
-                    let write = async move {
-                        while let Some(line) = rx.next().await {
-                            writer
-                                .send(line)
-                                .await
-                                .map_err(|e| debug!("error writing line: {}", e))?;
-                        }
-
-                        Result::<_, ()>::Ok(())
-                    };
-
-                    let task = async move {
-                        pin_mut!(read, write);
-                        futures::future::select(read, write).await;
-                        handler.workers.remove_worker(worker_id);
-                        debug!("Worker {} disconnected", worker_id);
-                    };
-                    tokio_old::spawn(task);
+                    if let Ok(addr) = socket.peer_addr() {
+                        spawn_worker(socket, addr.ip(), false, handler);
+                    }
                 }
             });
         server.await
     };
 
+    let secure = async move {
+        if let Some((tls_addr, acceptor)) = tls {
+            let mut listener = match TcpListener::bind(&tls_addr).await {
+                Ok(l) => l,
+                Err(e) => {
+                    error!("Stratum: Failed to bind to TLS listen address {}: {:?}", tls_addr, e);
+                    *tls_error.write() = Some(format!("{}: {}", tls_addr, e));
+                    return;
+                }
+            };
+            let server = listener
+                .incoming()
+                .filter_map(|s| async { s.map_err(|e| error!("accept error = {:?}", e)).ok() })
+                .for_each(move |socket| {
+                    let handler = handler.clone();
+                    let acceptor = acceptor.clone();
+                    async move {
+                        let ip = match socket.peer_addr() {
+                            Ok(addr) => addr.ip(),
+                            Err(_) => return,
+                        };
+                        // Count connection to the IP address limit before TLS handshake
+                        if !handler.workers.reserve_connection(ip) {
+                            debug!("TLS connection from {} rejected: too many connections", ip);
+                            return;
+                        }
+                        // Spawn a task to not block listener on TLS handshake
+                        tokio_old::spawn(async move {
+                            let timeout = Duration::from_secs(TLS_HANDSHAKE_TIMEOUT);
+                            match tokio_old::time::timeout(timeout, acceptor.accept(socket)).await {
+                                Ok(Ok(stream)) => spawn_worker(stream, ip, true, handler),
+                                Ok(Err(e)) => {
+                                    handler.workers.release_connection(ip);
+                                    debug!("TLS handshake error: {:?}", e)
+                                }
+                                Err(_) => {
+                                    handler.workers.release_connection(ip);
+                                    debug!("TLS handshake timeout from {}", ip)
+                                }
+                            }
+                        });
+                    }
+                });
+            server.await
+        }
+    };
+
+    let task = async move {
+        futures::future::join(plain, secure).await;
+    };
+
     let mut rt = Runtime::new().unwrap();
     let (task, handle) = abortable(task);
     rt.spawn(check_stop_state(stop_state, handle));
     rt.block_on(task).unwrap_or_default();
 }
 
+// Spawn a task to process the connection, reserved connection was already
+// counted to the IP address limit
+fn spawn_worker<S>(socket: S, ip: IpAddr, reserved: bool, handler: Arc<Handler>)
+    where S: AsyncRead + AsyncWrite + Unpin + Send + 'static
+{
+    let (tx, mut rx) = mpsc::unbounded();
+
+    let mut framed = Framed::new(socket, LinesCodec::new());
+    let worker_id = match handler.workers.add_worker(tx, ip, reserved) {
+        Ok(id) => id,
+        Err(e) => {
+            debug!("Worker from {} rejected: {}", ip, e.message);
+            // Send an error and close the connection
+            let resp = RpcResponse {
+                id: JsonId::StrId(String::from("Stratum")),
+                jsonrpc: String::from("2.0"),
+                method: String::from("login"),
+                result: None,
+                error: Some(e.into()),
+            };
+            let resp_json = serde_json::to_string(&resp).unwrap();
+            tokio_old::spawn(async move {
+                let _ = framed.send(resp_json).await;
+            });
+            return;
+        }
+    };
+    debug!("Worker {} connected", worker_id);
+
+    // Disconnect worker which did not login in time when credentials are required
+    if handler.workers.auth_required() {
+        let h = handler.clone();
+        tokio_old::spawn(async move {
+            tokio_old::time::delay_for(Duration::from_secs(LOGIN_TIMEOUT)).await;
+            if !h.workers.is_authenticated(worker_id) {
+                debug!("Worker {} login timeout", worker_id);
+                h.workers.disconnect(worker_id);
+            }
+        });
+    }
+
+    let (mut writer, mut reader) = framed.split();
+
+    let h = handler.clone();
+    let read = async move {
+        while let Some(line) = reader
+            .try_next()
+            .await
+            .map_err(|e| debug!("error reading line: {}", e))?
+        {
+            let request = serde_json::from_str(&line)
+                .map_err(|e| debug!("error serializing line: {}", e))?;
+            let resp = h.handle_rpc_requests(request, worker_id);
+            h.workers.send_to(worker_id, resp);
+            // Disconnect rejected worker after the error was sent
+            if h.workers.is_rejected(worker_id) {
+                h.workers.disconnect(worker_id);
+            }
+        }
+
+        Result::<_, ()>::Ok(())
+    };
+
+    let write = async move {
+        while let Some(line) = rx.next().await {
+            writer
+                .send(line)
+                .await
+                .map_err(|e| debug!("error writing line: {}", e))?;
+        }
+
+        Result::<_, ()>::Ok(())
+    };
+
+    let task = async move {
+        pin_mut!(read, write);
+        futures::future::select(read, write).await;
+        handler.workers.remove_worker(worker_id);
+        debug!("Worker {} disconnected", worker_id);
+    };
+    tokio_old::spawn(task);
+}
+
 async fn check_stop_state(stop_state: Arc<StratumStopState>, handle: AbortHandle) {
     loop {
         // Ping stratum socket on stop to handle TcpListener unbind.
@@ -743,49 +933,161 @@ pub struct Worker {
     agent: String,
     login: Option<String>,
     authenticated: bool,
+    rejected: bool,
+    ip: IpAddr,
     tx: Tx,
+    difficulty: u64, // unscaled
+    jobs: HashMap<(u64, u64), u64>, // unscaled difficulty by job height and id
+    shares: u32,
+    retarget_time: i64,
 }
 
 impl Worker {
     /// Creates a new Stratum Worker.
-    pub fn new(id: usize, tx: Tx) -> Worker {
+    pub fn new(id: usize, tx: Tx, ip: IpAddr, difficulty: u64) -> Worker {
         Worker {
             id: id,
             agent: String::from(""),
             login: None,
             authenticated: false,
+            rejected: false,
+            ip,
             tx: tx,
+            difficulty,
+            jobs: HashMap::new(),
+            shares: 0,
+            retarget_time: Utc::now().timestamp(),
+        }
+    }
+
+    /// Save difficulty of the job sent to worker, jobs from previous heights are removed.
+    fn add_job(&mut self, job: &JobTemplate) {
+        self.jobs.retain(|(height, _), _| *height >= job.height);
+        self.jobs.insert((job.height, job.job_id), job.difficulty);
+    }
+
+    /// Difficulty of the job share was mined against, current difficulty for unknown job.
+    pub fn job_difficulty(&self, height: u64, job_id: u64) -> u64 {
+        *self.jobs.get(&(height, job_id)).unwrap_or(&self.difficulty)
+    }
+
+    /// Calculate new difficulty at provided time to get target amount of shares per minute,
+    /// returns `true` if difficulty was changed.
+    fn retarget(&mut self, now: i64, shares_per_minute: u32, minimum_difficulty: u64) -> bool {
+        let elapsed = now - self.retarget_time;
+        if shares_per_minute == 0 || elapsed < VARDIFF_RETARGET_TIME {
+            return false;
+        }
+        let rate = self.shares as f64 * 60.0 / elapsed as f64;
+        let factor = (rate / shares_per_minute as f64)
+            .clamp(1.0 / VARDIFF_MAX_FACTOR, VARDIFF_MAX_FACTOR);
+        self.shares = 0;
+        self.retarget_time = now;
+        // Skip small deviations from target rate.
+        if (1.0 / VARDIFF_TOLERANCE..=VARDIFF_TOLERANCE).contains(&factor) {
+            return false;
+        }
+        let difficulty = ((self.difficulty as f64 * factor) as u64).max(minimum_difficulty);
+        if difficulty == self.difficulty {
+            return false;
         }
+        self.difficulty = difficulty;
+        true
     }
 } // impl Worker
 
 struct WorkersList {
     workers_list: Arc<RwLock<HashMap<usize, Worker>>>,
     stratum_stats: Arc<RwLock<StratumStats>>,
+    shares: Arc<RwLock<ShareAccounting>>,
+    history: Arc<RwLock<MiningHistory>>,
+    minimum_difficulty: u64, // unscaled
+    shares_per_minute: u32,
+    credentials: Vec<StratumWorker>,
+    max_ip_connections: u32,
+    connecting: RwLock<HashMap<IpAddr, u32>>, // connections at TLS handshake by IP address
 }
 
 impl WorkersList {
-    pub fn new(stratum_stats: Arc<RwLock<StratumStats>>) -> Self {
+    pub fn new(stratum_stats: Arc<RwLock<StratumStats>>,
+               shares: Arc<RwLock<ShareAccounting>>,
+               history: Arc<RwLock<MiningHistory>>,
+               minimum_difficulty: u64,
+               config: &StratumConfig) -> Self {
         WorkersList {
             workers_list: Arc::new(RwLock::new(HashMap::new())),
             stratum_stats: stratum_stats,
+            shares,
+            history,
+            minimum_difficulty,
+            shares_per_minute: config.shares_per_minute,
+            credentials: config.workers.clone(),
+            max_ip_connections: config.max_ip_connections,
+            connecting: RwLock::new(HashMap::new()),
         }
     }
 
-    pub fn add_worker(&self, tx: Tx) -> usize {
+    // Count connection from IP address before TLS handshake, returns false when limit reached
+    pub fn reserve_connection(&self, ip: IpAddr) -> bool {
+        let workers_list = self.workers_list.read();
+        let mut connecting = self.connecting.write();
+        if self.ip_limit_reached(&workers_list, &connecting, ip) {
+            return false;
+        }
+        *connecting.entry(ip).or_insert(0) += 1;
+        true
+    }
+
+    // Remove connection counted before TLS handshake
+    pub fn release_connection(&self, ip: IpAddr) {
+        Self::release(&mut self.connecting.write(), ip);
+    }
+
+    fn release(connecting: &mut HashMap<IpAddr, u32>, ip: IpAddr) {
+        if let Some(count) = connecting.get_mut(&ip) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                connecting.remove(&ip);
+            }
+        }
+    }
+
+    // Check connections limit from the same IP address including TLS handshakes
+    fn ip_limit_reached(&self,
+                        workers_list: &HashMap<usize, Worker>,
+                        connecting: &HashMap<IpAddr, u32>,
+                        ip: IpAddr) -> bool {
+        if self.max_ip_connections == 0 {
+            return false;
+        }
+        let workers = workers_list.values().filter(|w| w.ip == ip).count() as u32;
+        let handshakes = connecting.get(&ip).cloned().unwrap_or(0);
+        workers + handshakes >= self.max_ip_connections
+    }
+
+    pub fn add_worker(&self, tx: Tx, ip: IpAddr, reserved: bool) -> Result<usize, RpcError> {
         let mut stratum_stats = self.stratum_stats.write();
-        let worker_id = stratum_stats.worker_stats.len();
-        let worker = Worker::new(worker_id, tx);
         let mut workers_list = self.workers_list.write();
+        {
+            let mut connecting = self.connecting.write();
+            if reserved {
+                Self::release(&mut connecting, ip);
+            }
+            if self.ip_limit_reached(&workers_list, &connecting, ip) {
+                return Err(RpcError::too_many_connections());
+            }
+        }
+        let worker_id = stratum_stats.worker_stats.len();
+        let worker = Worker::new(worker_id, tx, ip, self.minimum_difficulty);
         workers_list.insert(worker_id, worker);
 
         let mut worker_stats = WorkerStats::default();
         worker_stats.is_connected = true;
         worker_stats.id = worker_id.to_string();
-        worker_stats.pow_difficulty = stratum_stats.minimum_share_difficulty;
+        worker_stats.pow_difficulty = self.minimum_difficulty;
         stratum_stats.worker_stats.push(worker_stats);
         stratum_stats.num_workers = workers_list.len();
-        worker_id
+        Ok(worker_id)
     }
     pub fn remove_worker(&self, worker_id: usize) {
         self.update_stats(worker_id, |ws| ws.is_connected = false);
@@ -798,18 +1100,71 @@ impl WorkersList {
         stratum_stats.num_workers = workers_list.len();
     }
 
-    pub fn login(&self, worker_id: usize, login: String, agent: String) -> Result<(), RpcError> {
+    pub fn login(&self,
+                 worker_id: usize,
+                 login: String,
+                 pass: String,
+                 agent: String) -> Result<(), RpcError> {
         let mut wl = self.workers_list.write();
         let worker = wl
             .get_mut(&worker_id)
             .ok_or_else(RpcError::internal_error)?;
+        // Validate credentials if workers are configured
+        if !self.credentials.is_empty() &&
+            !self.credentials.iter().any(|c| c.check(&login, &pass)) {
+            worker.rejected = true;
+            return Err(RpcError::unauthorized());
+        }
         worker.login = Some(login);
-        // XXX TODO Future - Validate password?
         worker.agent = agent;
         worker.authenticated = true;
         Ok(())
     }
 
+    // Check if credentials are required to login
+    pub fn auth_required(&self) -> bool {
+        !self.credentials.is_empty()
+    }
+
+    pub fn is_authenticated(&self, worker_id: usize) -> bool {
+        self.workers_list
+            .read()
+            .get(&worker_id)
+            .map(|w| w.authenticated)
+            .unwrap_or(false)
+    }
+
+    // Check if worker logged in when credentials are required, reject it otherwise
+    pub fn check_auth(&self, worker_id: usize) -> Result<(), RpcError> {
+        if self.credentials.is_empty() {
+            return Ok(());
+        }
+        let mut wl = self.workers_list.write();
+        let worker = wl
+            .get_mut(&worker_id)
+            .ok_or_else(RpcError::internal_error)?;
+        if !worker.authenticated {
+            worker.rejected = true;
+            return Err(RpcError::unauthorized());
+        }
+        Ok(())
+    }
+
+    pub fn is_rejected(&self, worker_id: usize) -> bool {
+        self.workers_list
+            .read()
+            .get(&worker_id)
+            .map(|w| w.rejected)
+            .unwrap_or(false)
+    }
+
+    // Close worker channel to disconnect after sending of queued messages
+    pub fn disconnect(&self, worker_id: usize) {
+        if let Some(worker) = self.workers_list.read().get(&worker_id) {
+            worker.tx.close_channel();
+        }
+    }
+
     pub fn get_worker(&self, worker_id: usize) -> Result<Worker, RpcError> {
         self.workers_list
             .read()
@@ -840,6 +1195,57 @@ impl WorkersList {
         f(&mut stratum_stats.worker_stats[worker_id]);
     }
 
+    // Update share accounting for login of the worker
+    fn update_shares(&self, worker_id: usize, f: impl FnOnce(&mut ShareAccounting, &String)) {
+        let login = self
+            .workers_list
+            .read()
+            .get(&worker_id)
+            .and_then(|w| w.login.clone());
+        if let Some(login) = login {
+            f(&mut self.shares.write(), &login);
+        }
+    }
+
+    pub fn add_accepted(&self, worker_id: usize, difficulty: u64, height: u64) {
+        self.update_shares(worker_id, |s, login| s.add_accepted(login, difficulty, height));
+        self.history.write().add_accepted(difficulty);
+    }
+
+    pub fn add_rejected(&self, worker_id: usize) {
+        self.update_shares(worker_id, |s, login| s.add_rejected(login));
+        self.history.write().add_rejected();
+    }
+
+    pub fn add_stale(&self, worker_id: usize) {
+        self.update_shares(worker_id, |s, login| s.add_stale(login));
+        self.history.write().add_stale();
+    }
+
+    pub fn add_block(&self, worker_id: usize, height: u64, hash: String, reward: u64) {
+        self.update_shares(worker_id, |s, login| s.add_block(login, height));
+        let worker = match self.workers_list.read().get(&worker_id) {
+            Some(w) => w.login.clone().unwrap_or(w.id.to_string()),
+            None => worker_id.to_string()
+        };
+        self.history.write().add_block(height, hash, reward, worker);
+    }
+
+    // Update status of found blocks deep enough at the main chain
+    pub fn check_found_blocks(&self, chain: &grin_chain::Chain, head_height: u64) {
+        let changed = self.history.write().check_blocks(head_height, |height| {
+            chain.get_header_by_height(height).ok().map(|h| h.hash().to_hex())
+        });
+        if changed {
+            self.history.read().save();
+        }
+    }
+
+    pub fn save_mining_data(&self) {
+        self.shares.read().save();
+        self.history.read().save();
+    }
+
     pub fn send_to(&self, worker_id: usize, msg: String) {
         let _ = self
             .workers_list
@@ -850,9 +1256,68 @@ impl WorkersList {
             .unbounded_send(msg);
     }
 
-    pub fn broadcast(&self, msg: String) {
-        for worker in self.workers_list.read().values() {
-            let _ = worker.tx.unbounded_send(msg.clone());
+    // Save difficulty of the job sent to single worker
+    pub fn add_job(&self, worker_id: usize, job_template: &JobTemplate) {
+        if let Some(worker) = self.workers_list.write().get_mut(&worker_id) {
+            worker.add_job(job_template);
+        }
+    }
+
+    // Send job to all workers with difficulty of each worker
+    pub fn broadcast_with(&self, job_template: &JobTemplate, msg: impl Fn(&JobTemplate) -> String) {
+        for worker in self.workers_list.write().values_mut() {
+            // Skip workers not logged in when credentials are required
+            if !self.credentials.is_empty() && !worker.authenticated {
+                continue;
+            }
+            let job = JobTemplate { difficulty: worker.difficulty, ..job_template.clone() };
+            worker.add_job(&job);
+            let _ = worker.tx.unbounded_send(msg(&job));
+        }
+    }
+
+    pub fn add_share(&self, worker_id: usize) {
+        if let Some(worker) = self.workers_list.write().get_mut(&worker_id) {
+            worker.shares += 1;
+        }
+    }
+
+    // Adjust worker difficulty to target shares rate, returns true if it was changed
+    pub fn retarget_difficulty(&self, worker_id: usize) -> bool {
+        let now = Utc::now().timestamp();
+        let difficulty = {
+            let mut wl = self.workers_list.write();
+            match wl.get_mut(&worker_id) {
+                Some(w) => {
+                    if !w.retarget(now, self.shares_per_minute, self.minimum_difficulty) {
+                        return false;
+                    }
+                    w.difficulty
+                }
+                None => return false,
+            }
+        };
+        self.update_stats(worker_id, |ws| ws.pow_difficulty = difficulty);
+        true
+    }
+
+    // Adjust difficulty of all workers to target shares rate
+    pub fn retarget_all(&self) {
+        let now = Utc::now().timestamp();
+        let changed: Vec<(usize, u64)> = self
+            .workers_list
+            .write()
+            .values_mut()
+            .filter_map(|w| {
+                if w.retarget(now, self.shares_per_minute, self.minimum_difficulty) {
+                    Some((w.id, w.difficulty))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        for (worker_id, difficulty) in changed {
+            self.update_stats(worker_id, |ws| ws.pow_difficulty = difficulty);
         }
     }
 
@@ -893,27 +1358,39 @@ impl WorkersList {
 pub struct StratumServer {
     id: String,
     config: StratumServerConfig,
+    stratum_config: StratumConfig,
     chain: Arc<grin_chain::Chain>,
     pub tx_pool: ServerTxPool,
     sync_state: Arc<SyncState>,
     stratum_stats: Arc<RwLock<StratumStats>>,
+    shares: Arc<RwLock<ShareAccounting>>,
+    history: Arc<RwLock<MiningHistory>>,
+    tls_error: Arc<RwLock<Option<String>>>,
 }
 
 impl StratumServer {
     /// Creates a new Stratum Server.
     pub fn new(
         config: StratumServerConfig,
+        stratum_config: StratumConfig,
         chain: Arc<grin_chain::Chain>,
         tx_pool: ServerTxPool,
         stratum_stats: Arc<RwLock<StratumStats>>,
+        shares: Arc<RwLock<ShareAccounting>>,
+        history: Arc<RwLock<MiningHistory>>,
+        tls_error: Arc<RwLock<Option<String>>>,
     ) -> StratumServer {
         StratumServer {
             id: String::from("0"),
             config,
+            stratum_config,
             chain,
             tx_pool,
             sync_state: Arc::new(SyncState::new()),
             stratum_stats: stratum_stats,
+            shares,
+            history,
+            tls_error,
         }
     }
 
@@ -941,13 +1418,31 @@ impl StratumServer {
             .parse()
             .expect("Stratum: Incorrect address ");
 
+        // Setup TLS listener at the same IP address, refuse to start without it.
+        let tls = if self.stratum_config.tls_enabled {
+            match self.stratum_config.tls_acceptor() {
+                Ok(acceptor) => {
+                    let mut tls_addr: SocketAddr = listen_addr;
+                    tls_addr.set_port(self.stratum_config.tls_port);
+                    Some((tls_addr, TlsAcceptor::from(acceptor)))
+                }
+                Err(e) => {
+                    error!("Stratum: Failed to setup TLS: {}", e);
+                    return;
+                }
+            }
+        } else {
+            None
+        };
+
         let handler = Arc::new(Handler::from_stratum(&self));
         let h = handler.clone();
 
         let stop_socket = stop_state.clone();
         let check_state = stop_socket.clone();
+        let tls_error = self.tls_error.clone();
         let _listener_th = thread::spawn(move || {
-            accept_connections(listen_addr, h, stop_socket);
+            accept_connections(listen_addr, tls, tls_error, h, stop_socket);
         });
 
         // We have started
@@ -976,4 +1471,74 @@ fn parse_params<T>(params: Option<Value>) -> Result<T, RpcError>
     params
         .and_then(|v| serde_json::from_value(v).ok())
         .ok_or_else(RpcError::invalid_request)
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Create worker with provided difficulty and shares submitted since retarget at zero time.
+    fn worker(difficulty: u64, shares: u32) -> Worker {
+        let (tx, _) = mpsc::unbounded();
+        let mut worker = Worker::new(0, tx, IpAddr::from([127, 0, 0, 1]), difficulty);
+        worker.shares = shares;
+        worker.retarget_time = 0;
+        worker
+    }
+
+    #[test]
+    fn clamps_retarget_factor() {
+        // 100 shares per minute instead of 10 raises difficulty 4 times only.
+        let mut w = worker(1000, 100);
+        assert!(w.retarget(60, 10, 1));
+        assert_eq!(w.difficulty, 4000);
+        assert_eq!(w.shares, 0);
+        assert_eq!(w.retarget_time, 60);
+        // No shares lowers difficulty 4 times only.
+        let mut w = worker(1000, 0);
+        assert!(w.retarget(60, 10, 1));
+        assert_eq!(w.difficulty, 250);
+    }
+
+    #[test]
+    fn adjusts_difficulty_to_shares_rate() {
+        // 20 shares per 2 minutes instead of 5 per minute doubles difficulty.
+        let mut w = worker(1000, 20);
+        assert!(w.retarget(120, 5, 1));
+        assert_eq!(w.difficulty, 2000);
+        // 13 shares per minute instead of 10.
+        let mut w = worker(1000, 13);
+        assert!(w.retarget(60, 10, 1));
+        assert_eq!(w.difficulty, 1300);
+    }
+
+    #[test]
+    fn skips_retarget_within_tolerance() {
+        for shares in [8, 10, 12] {
+            let mut w = worker(1000, shares);
+            assert!(!w.retarget(60, 10, 1));
+            assert_eq!(w.difficulty, 1000);
+            assert_eq!(w.shares, 0);
+            assert_eq!(w.retarget_time, 60);
+        }
+    }
+
+    #[test]
+    fn keeps_minimum_difficulty() {
+        let mut w = worker(1000, 0);
+        assert!(w.retarget(60, 10, 500));
+        assert_eq!(w.difficulty, 500);
+        let mut w = worker(500, 0);
+        assert!(!w.retarget(60, 10, 500));
+        assert_eq!(w.difficulty, 500);
+    }
+
+    #[test]
+    fn skips_retarget_without_target_or_time() {
+        let mut w = worker(1000, 100);
+        assert!(!w.retarget(60, 0, 1));
+        assert!(!w.retarget(VARDIFF_RETARGET_TIME - 1, 10, 1));
+        assert_eq!(w.difficulty, 1000);
+        assert_eq!(w.shares, 100);
+        assert_eq!(w.retarget_time, 0);
+    }
+}
@@ -52,6 +52,8 @@ impl Settings {
     pub const CRASH_REPORT_FILE_NAME: &'static str = "crash.log";
     /// Application socket name.
     pub const SOCKET_NAME: &'static str = "grim.sock";
+    /// Application socket control token file name.
+    pub const SOCKET_TOKEN_FILE_NAME: &'static str = "grim.token";
 
     /// Initialize settings with app and node configs.
     fn init() -> Self {
@@ -154,6 +156,13 @@ impl Settings {
         socket_path
     }
 
+    /// Get application socket control token file path.
+    pub fn socket_token_path() -> PathBuf {
+        let mut path = Self::base_path(None);
+        path.push(Self::SOCKET_TOKEN_FILE_NAME);
+        path
+    }
+
     /// Get configuration file path from provided name and subdirectory if needed.
     pub fn config_path(config_name: &str, sub_dir: Option<String>) -> PathBuf {
         let mut path = Self::base_path(sub_dir);
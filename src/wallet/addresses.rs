@@ -0,0 +1,249 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::wallet::store::TxAddressStore;
+
+/// Additional Slatepack address to receive transactions, derived from account key.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct ReceiveAddress {
+    /// Key derivation index, main account address is derived at `0`.
+    pub index: u32,
+    /// Account label address was derived for.
+    pub account: String,
+    /// Address label.
+    pub label: String,
+    /// Slatepack address.
+    pub address: String,
+}
+
+/// Local proxy to forward requests from Tor service of additional address to Foreign API,
+/// tagging received transactions with address derivation index.
+pub struct ReceiveAddressProxy {
+    /// Address derivation index.
+    pub index: u32,
+    /// Local port to listen.
+    pub port: u16,
+    /// Flag to stop listening.
+    stop: Arc<AtomicBool>,
+}
+
+/// Maximum size of request headers.
+const MAX_HEADERS_SIZE: usize = 64 * 1024;
+/// Maximum size of request body.
+const MAX_BODY_SIZE: usize = 10 * 1024 * 1024;
+/// Maximum amount of connections handled at the same time.
+const MAX_CONNECTIONS: usize = 8;
+
+impl ReceiveAddressProxy {
+    /// Start proxy for address derivation index forwarding requests to Foreign API port.
+    pub fn start(index: u32, api_port: u16, db_path: String) -> std::io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+        let port = listener.local_addr()?.port();
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_listener = stop.clone();
+        thread::spawn(move || {
+            let mut handlers: Vec<JoinHandle<()>> = vec![];
+            for stream in listener.incoming() {
+                if stop_listener.load(Ordering::Relaxed) {
+                    break;
+                }
+                handlers.retain(|h| !h.is_finished());
+                // Drop connection when limit is reached.
+                if handlers.len() >= MAX_CONNECTIONS {
+                    continue;
+                }
+                if let Ok(stream) = stream {
+                    let db_path = db_path.clone();
+                    handlers.push(thread::spawn(move || {
+                        Self::forward(stream, index, api_port, db_path).unwrap_or_default();
+                    }));
+                }
+            }
+            for h in handlers {
+                let _ = h.join();
+            }
+        });
+        Ok(Self { index, port, stop })
+    }
+
+    /// Stop proxy.
+    pub fn stop(&self) {
+        self.stop.store(true, Ordering::Relaxed);
+        // Connect to unblock waiting listener.
+        let _ = TcpStream::connect((Ipv4Addr::LOCALHOST, self.port));
+    }
+
+    /// Forward single request to Foreign API and save slate identifier of received transaction.
+    fn forward(mut client: TcpStream,
+               index: u32,
+               api_port: u16,
+               db_path: String) -> std::io::Result<()> {
+        client.set_read_timeout(Some(Duration::from_secs(60)))?;
+        let mut reader = BufReader::new(client.try_clone()?);
+
+        // Read request headers.
+        let mut headers: Vec<String> = vec![];
+        let mut headers_size = 0;
+        loop {
+            let mut line = String::new();
+            let n = reader.read_line(&mut line)?;
+            if n == 0 {
+                return Ok(());
+            }
+            headers_size += n;
+            if headers_size > MAX_HEADERS_SIZE {
+                return Ok(());
+            }
+            let line = line.trim_end().to_string();
+            if line.is_empty() {
+                break;
+            }
+            headers.push(line);
+        }
+        let header = |name: &str| {
+            headers.iter()
+                .filter_map(|l| l.split_once(':'))
+                .find(|(k, _)| k.trim().eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.trim().to_lowercase())
+        };
+
+        // Read request body.
+        let chunked = header("transfer-encoding").map(|v| v.contains("chunked")).unwrap_or(false);
+        let body = if chunked {
+            Self::read_chunked(&mut reader)?
+        } else {
+            let content_length = header("content-length")
+                .and_then(|v| v.parse::<usize>().ok())
+                .unwrap_or(0);
+            if content_length > MAX_BODY_SIZE {
+                return Ok(());
+            }
+            let mut body = vec![0u8; content_length];
+            reader.read_exact(&mut body)?;
+            body
+        };
+
+        // Refuse payment proof requests, proof is signed with main address key by the wallet.
+        if Self::is_proof_request(&body) {
+            let error = serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": serde_json::from_slice::<Value>(&body).ok().map(|r| r["id"].clone()),
+                "error": {
+                    "code": -32000,
+                    "message": "Payment proofs are not supported by this address, send without payment proof"
+                }
+            }).to_string();
+            let response = format!("HTTP/1.1 200 OK\r\n\
+                                    Content-Type: application/json\r\n\
+                                    Content-Length: {}\r\n\
+                                    Connection: close\r\n\r\n{}", error.len(), error);
+            client.write_all(response.as_bytes())?;
+            return Ok(());
+        }
+
+        // Ask API server to close connection after response and pass body length.
+        let mut request = headers.iter()
+            .filter(|l| {
+                let l = l.to_lowercase();
+                !l.starts_with("connection:")
+                    && !l.starts_with("keep-alive:")
+                    && !l.starts_with("content-length:")
+                    && !l.starts_with("transfer-encoding:")
+            })
+            .map(|l| l.as_str())
+            .collect::<Vec<&str>>()
+            .join("\r\n");
+        request.push_str(format!("\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                                 body.len()).as_str());
+
+        // Forward request and response.
+        let mut api = TcpStream::connect((Ipv4Addr::LOCALHOST, api_port))?;
+        api.set_read_timeout(Some(Duration::from_secs(60)))?;
+        api.write_all(request.as_bytes())?;
+        api.write_all(&body)?;
+        let mut response = vec![];
+        api.read_to_end(&mut response)?;
+        client.write_all(&response)?;
+
+        // Save address index for received transaction.
+        if let Some(slate_id) = Self::received_slate_id(&body, &response) {
+            TxAddressStore::new(db_path).write_index(&slate_id, index);
+        }
+        Ok(())
+    }
+
+    /// Read body with chunked transfer encoding.
+    fn read_chunked(reader: &mut impl BufRead) -> std::io::Result<Vec<u8>> {
+        let invalid = || std::io::Error::new(ErrorKind::InvalidData, "Invalid chunk");
+        let mut body = vec![];
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line)?;
+            let size_text = line.trim().split(';').next().unwrap_or("");
+            let size = usize::from_str_radix(size_text, 16).map_err(|_| invalid())?;
+            if body.len() + size > MAX_BODY_SIZE {
+                return Err(invalid());
+            }
+            if size == 0 {
+                // Skip trailer headers.
+                loop {
+                    line.clear();
+                    let n = reader.read_line(&mut line)?;
+                    if n == 0 || line.trim().is_empty() {
+                        return Ok(body);
+                    }
+                }
+            }
+            let start = body.len();
+            body.resize(start + size, 0);
+            reader.read_exact(&mut body[start..])?;
+            line.clear();
+            reader.read_line(&mut line)?;
+        }
+    }
+
+    /// Check if request is `receive_tx` with payment proof.
+    fn is_proof_request(body: &[u8]) -> bool {
+        let request: Value = match serde_json::from_slice(body) {
+            Ok(r) => r,
+            Err(_) => return false
+        };
+        request["method"].as_str() == Some("receive_tx")
+            && !request["params"][0]["proof"].is_null()
+    }
+
+    /// Get slate identifier from successful `receive_tx` request.
+    fn received_slate_id(body: &[u8], response: &[u8]) -> Option<String> {
+        let request: Value = serde_json::from_slice(body).ok()?;
+        if request["method"].as_str()? != "receive_tx" {
+            return None;
+        }
+        let response_start = response.windows(4).position(|w| w == b"\r\n\r\n")? + 4;
+        let response: Value = serde_json::from_slice(&response[response_start..]).ok()?;
+        if response["result"]["Ok"].is_null() {
+            return None;
+        }
+        request["params"][0]["id"].as_str().map(|id| id.to_string())
+    }
+}
@@ -0,0 +1,330 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::num::NonZeroU32;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use grin_util::{from_hex, ToHex, ZeroingString};
+use grin_wallet_libwallet::Error;
+use rand::{rng, Rng};
+use ring::{aead, pbkdf2};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::AppConfig;
+use crate::wallet::WalletConfig;
+
+/// Wallet file stored at the backup.
+#[derive(Serialize, Deserialize)]
+struct BackupFile {
+    /// Path relative to wallet directory.
+    path: String,
+    /// File content in hex format.
+    data: String,
+}
+
+/// Password-encrypted backup of wallet configuration, seed, extra database, Slatepacks and
+/// metadata files, wallet database is not included to restore outputs by scanning the chain.
+#[derive(Serialize, Deserialize)]
+pub struct WalletBackup {
+    /// Backup format version.
+    version: u8,
+    /// Encrypted list of wallet files in hex format.
+    encrypted_files: String,
+    /// Key derivation salt.
+    salt: String,
+    /// Encryption nonce.
+    nonce: String,
+}
+
+impl WalletBackup {
+    /// Backup file extension.
+    pub const FILE_EXTENSION: &'static str = "grimbackup";
+
+    /// Current backup format version.
+    const VERSION: u8 = 2;
+
+    /// Amount of key derivation iterations.
+    const KDF_ITERATIONS: u32 = 100_000;
+
+    /// Database lock file name to skip at backup.
+    const DB_LOCK_FILE_NAME: &'static str = "lock.mdb";
+
+    /// Wallet database directory name inside database path.
+    const WALLET_DB_DIR_NAME: &'static str = "lmdb";
+
+    /// Saved transactions directory name inside wallet data path.
+    const SAVED_TXS_DIR_NAME: &'static str = "saved_txs";
+
+    /// Create encrypted backup of wallet files for provided configuration.
+    pub fn create(config: &WalletConfig, password: &ZeroingString) -> Result<String, Error> {
+        let wallet_dir = PathBuf::from(config.get_wallet_path());
+        let mut files = vec![];
+        collect_files(&wallet_dir, &wallet_dir, &Self::skipped_paths(config), &mut files)?;
+        let backup = Self::encrypt(&files, password)?;
+        serde_json::to_string(&backup).map_err(|e| Error::GenericError(e.to_string()))
+    }
+
+    /// Restore wallet files from encrypted backup with provided name and password,
+    /// returns configuration of restored wallet, its database should be created to scan
+    /// the chain.
+    pub fn restore(data: &str,
+                   name: &String,
+                   password: &ZeroingString) -> Result<WalletConfig, Error> {
+        let backup: WalletBackup = serde_json::from_str(data.trim())
+            .map_err(|_| Error::GenericError("Invalid backup".to_string()))?;
+        let files = backup.decrypt(password)?;
+
+        // Write files into new wallet directory.
+        let chain_type = AppConfig::chain_type();
+        let mut id = chrono::Utc::now().timestamp();
+        let mut wallet_dir = WalletConfig::get_base_path(chain_type);
+        while wallet_dir.join(id.to_string()).exists() {
+            id += 1;
+        }
+        wallet_dir.push(id.to_string());
+        let res = write_files(&wallet_dir, files);
+        let config = res.and_then(|_| {
+            WalletConfig::load(wallet_dir.clone())
+                .ok_or(Error::GenericError("Wallet configuration not found".to_string()))
+        }).and_then(|c| {
+            if c.chain_type != chain_type {
+                return Err(Error::GenericError("Wrong backup network".to_string()));
+            }
+            Ok(c)
+        });
+        match config {
+            Ok(mut config) => {
+                config.id = id;
+                config.name = name.clone();
+                config.api_port = Some(rng().random_range(10000..30000));
+                config.save();
+                // Remove wallet database from backups of previous version.
+                for path in Self::skipped_paths(&config) {
+                    if path.is_dir() {
+                        let _ = fs::remove_dir_all(path);
+                    } else {
+                        let _ = fs::remove_file(path);
+                    }
+                }
+                Ok(config)
+            }
+            Err(e) => {
+                let _ = fs::remove_dir_all(wallet_dir);
+                Err(e)
+            }
+        }
+    }
+
+    /// Get paths of wallet database and files depending on it to skip at backup.
+    fn skipped_paths(config: &WalletConfig) -> Vec<PathBuf> {
+        vec![
+            PathBuf::from(config.get_db_path()).join(Self::WALLET_DB_DIR_NAME),
+            PathBuf::from(config.get_data_path()).join(Self::SAVED_TXS_DIR_NAME),
+            config.get_hidden_outputs_path(),
+        ]
+    }
+
+    /// Encrypt provided files with password.
+    fn encrypt(files: &Vec<BackupFile>, password: &ZeroingString) -> Result<Self, Error> {
+        let mut content = serde_json::to_vec(files)
+            .map_err(|e| Error::GenericError(e.to_string()))?;
+        let salt: [u8; 8] = rng().random();
+        let nonce: [u8; 12] = rng().random();
+        let key = Self::key(password, &salt);
+        let res = key.seal_in_place_append_tag(aead::Nonce::assume_unique_for_key(nonce),
+                                               aead::Aad::empty(),
+                                               &mut content);
+        if res.is_err() {
+            return Err(Error::GenericError("Backup encryption error".to_string()));
+        }
+        Ok(Self {
+            version: Self::VERSION,
+            encrypted_files: content.to_hex(),
+            salt: salt.to_hex(),
+            nonce: nonce.to_hex(),
+        })
+    }
+
+    /// Decrypt backup files with password.
+    fn decrypt(&self, password: &ZeroingString) -> Result<Vec<BackupFile>, Error> {
+        if self.version > Self::VERSION {
+            return Err(Error::GenericError("Unsupported backup version".to_string()));
+        }
+        let salt = from_hex(self.salt.as_str())
+            .map_err(|_| Error::GenericError("Invalid backup salt".to_string()))?;
+        let nonce: [u8; 12] = from_hex(self.nonce.as_str())
+            .ok()
+            .and_then(|n| n.try_into().ok())
+            .ok_or(Error::GenericError("Invalid backup nonce".to_string()))?;
+        let mut content = from_hex(self.encrypted_files.as_str())
+            .map_err(|_| Error::GenericError("Invalid backup content".to_string()))?;
+        let key = Self::key(password, &salt);
+        let content = key.open_in_place(aead::Nonce::assume_unique_for_key(nonce),
+                                        aead::Aad::empty(),
+                                        &mut content)
+            .map_err(|_| Error::GenericError("Wrong backup password".to_string()))?;
+        serde_json::from_slice(content)
+            .map_err(|_| Error::GenericError("Invalid backup content".to_string()))
+    }
+
+    /// Derive encryption key from password and salt.
+    fn key(password: &ZeroingString, salt: &[u8]) -> aead::LessSafeKey {
+        let mut key = [0; 32];
+        pbkdf2::derive(
+            pbkdf2::PBKDF2_HMAC_SHA512,
+            NonZeroU32::new(Self::KDF_ITERATIONS).unwrap(),
+            salt,
+            password.as_bytes(),
+            &mut key,
+        );
+        let unbound_key = aead::UnboundKey::new(&aead::CHACHA20_POLY1305, &key).unwrap();
+        aead::LessSafeKey::new(unbound_key)
+    }
+}
+
+/// Read files from directory recursively with paths relative to base directory,
+/// except provided paths.
+fn collect_files(base: &Path,
+                 dir: &Path,
+                 skip: &Vec<PathBuf>,
+                 files: &mut Vec<BackupFile>) -> Result<(), Error> {
+    let entries = fs::read_dir(dir).map_err(|e| Error::IO(e.to_string()))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if skip.contains(&path) {
+            continue;
+        }
+        if path.is_dir() {
+            collect_files(base, &path, skip, files)?;
+            continue;
+        }
+        if entry.file_name() == WalletBackup::DB_LOCK_FILE_NAME {
+            continue;
+        }
+        let rel_path = path.strip_prefix(base)
+            .map_err(|e| Error::GenericError(e.to_string()))?
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect::<Vec<String>>()
+            .join("/");
+        let data = fs::read(&path).map_err(|e| Error::IO(e.to_string()))?;
+        files.push(BackupFile {
+            path: rel_path,
+            data: data.to_hex(),
+        });
+    }
+    Ok(())
+}
+
+/// Write backup files into provided directory.
+fn write_files(dir: &PathBuf, files: Vec<BackupFile>) -> Result<(), Error> {
+    for file in files {
+        // Skip paths outside of wallet directory.
+        let rel_path = PathBuf::from(file.path);
+        if !rel_path.components().all(|c| matches!(c, Component::Normal(_))) {
+            continue;
+        }
+        let path = dir.join(rel_path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| Error::IO(e.to_string()))?;
+        }
+        let data = from_hex(file.data.as_str())
+            .map_err(|_| Error::GenericError("Invalid backup file".to_string()))?;
+        fs::write(path, data).map_err(|e| Error::IO(e.to_string()))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Create temporary directory with provided name.
+    fn temp_dir(name: &str) -> PathBuf {
+        let mut dir = std::env::temp_dir();
+        dir.push(format!("grim_backup_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Create wallet files at provided directory.
+    fn create_files(dir: &PathBuf) {
+        fs::create_dir_all(dir.join("wallet_data/db/lmdb")).unwrap();
+        fs::create_dir_all(dir.join("wallet_data/db/extra")).unwrap();
+        fs::create_dir_all(dir.join("slatepacks")).unwrap();
+        fs::write(dir.join("grim-wallet.toml"), "name = \"test\"").unwrap();
+        fs::write(dir.join("wallet_data/wallet.seed"), [1, 2, 3]).unwrap();
+        fs::write(dir.join("wallet_data/db/lmdb/data.mdb"), [0; 64]).unwrap();
+        fs::write(dir.join("wallet_data/db/extra/data.mdb"), [4, 5, 6]).unwrap();
+        fs::write(dir.join("wallet_data/db/extra/lock.mdb"), [0]).unwrap();
+        fs::write(dir.join("slatepacks/tx.S1.slatepack"), "BEGINSLATEPACK.").unwrap();
+    }
+
+    #[test]
+    fn restores_files_with_password() {
+        let dir = temp_dir("source");
+        create_files(&dir);
+        let skip = vec![dir.join("wallet_data/db/lmdb")];
+        let mut files = vec![];
+        collect_files(&dir, &dir, &skip, &mut files).unwrap();
+        let mut paths: Vec<String> = files.iter().map(|f| f.path.clone()).collect();
+        paths.sort();
+        assert_eq!(paths, vec!["grim-wallet.toml",
+                               "slatepacks/tx.S1.slatepack",
+                               "wallet_data/db/extra/data.mdb",
+                               "wallet_data/wallet.seed"]);
+
+        let password = ZeroingString::from("password");
+        let data = serde_json::to_string(&WalletBackup::encrypt(&files, &password).unwrap())
+            .unwrap();
+        let backup: WalletBackup = serde_json::from_str(&data).unwrap();
+        assert_eq!(backup.version, WalletBackup::VERSION);
+        let restored = backup.decrypt(&password).unwrap();
+
+        let restore_dir = temp_dir("restore");
+        write_files(&restore_dir, restored).unwrap();
+        for path in &paths {
+            assert_eq!(fs::read(dir.join(path)).unwrap(),
+                       fs::read(restore_dir.join(path)).unwrap());
+        }
+        assert!(!restore_dir.join("wallet_data/db/lmdb").exists());
+        let _ = fs::remove_dir_all(dir);
+        let _ = fs::remove_dir_all(restore_dir);
+    }
+
+    #[test]
+    fn fails_with_wrong_password() {
+        let files = vec![BackupFile {
+            path: "wallet_data/wallet.seed".to_string(),
+            data: "010203".to_string(),
+        }];
+        let backup = WalletBackup::encrypt(&files, &ZeroingString::from("password")).unwrap();
+        let res = backup.decrypt(&ZeroingString::from("wrong"));
+        assert!(matches!(res, Err(Error::GenericError(e)) if e == "Wrong backup password"));
+    }
+
+    #[test]
+    fn skips_paths_outside_of_directory() {
+        let dir = temp_dir("outside");
+        let files = vec![BackupFile {
+            path: "../outside.toml".to_string(),
+            data: "010203".to_string(),
+        }];
+        write_files(&dir.join("wallet"), files).unwrap();
+        assert!(!dir.join("outside.toml").exists());
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,225 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use grin_core::core::{amount_from_hr_string, amount_to_hr_string};
+use grin_core::libtx::tx_fee;
+use grin_wallet_libwallet::SlatepackAddress;
+
+/// Status of batch payout row.
+#[derive(Clone, PartialEq)]
+pub enum BatchRowStatus {
+    /// Row is waiting to be sent.
+    Pending,
+    /// Transaction is sending.
+    Sending,
+    /// Transaction was sent and posted.
+    /// * slate id
+    Sent(String),
+    /// Transaction was not created or receiver was not reached, row can be retried.
+    /// * error
+    Failed(String),
+    /// Transaction was created but not posted, should be handled from transactions list.
+    /// * slate id
+    /// * error
+    NotPosted(String, String),
+}
+
+/// Batch payout recipient.
+#[derive(Clone)]
+pub struct BatchPayoutRow {
+    /// Receiver Slatepack address to send over Tor.
+    pub address: String,
+    /// Amount to send.
+    pub amount: u64,
+    /// Sending status.
+    pub status: BatchRowStatus,
+}
+
+impl BatchPayoutRow {
+    /// Check if row should be sent.
+    pub fn can_send(&self) -> bool {
+        match self.status {
+            BatchRowStatus::Pending | BatchRowStatus::Failed(_) => true,
+            _ => false
+        }
+    }
+}
+
+/// Payouts to multiple recipients imported from CSV file.
+#[derive(Clone, Default)]
+pub struct BatchPayout {
+    /// List of recipients.
+    pub rows: Vec<BatchPayoutRow>,
+}
+
+impl BatchPayout {
+    /// Parse payouts from CSV data with receiver address and amount at each line,
+    /// returns number of incorrect line on error. Values are separated by comma or by
+    /// semicolon when it is found at the first line, amount can have decimal comma then.
+    pub fn from_csv(data: &str) -> Result<Self, usize> {
+        let first_line = data.lines().find(|l| !l.trim().is_empty()).unwrap_or("");
+        let delimiter = if first_line.contains(';') { ';' } else { ',' };
+        let mut rows = vec![];
+        for (i, line) in data.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let values = split_csv_line(line, delimiter);
+            if values.len() != 2 {
+                return Err(i + 1);
+            }
+            let address = values[0].as_str();
+            let amount = values[1].replace(',', ".");
+            let valid_address = SlatepackAddress::try_from(address).is_ok();
+            match amount_from_hr_string(amount.as_str()) {
+                Ok(amount) if valid_address && amount > 0 => {
+                    rows.push(BatchPayoutRow {
+                        address: address.to_string(),
+                        amount,
+                        status: BatchRowStatus::Pending,
+                    });
+                }
+                // Skip header line.
+                Err(_) if rows.is_empty() && line == first_line.trim() && !valid_address => continue,
+                _ => return Err(i + 1)
+            }
+        }
+        if rows.is_empty() {
+            return Err(0);
+        }
+        Ok(Self { rows })
+    }
+
+    /// Get total amount of rows to send.
+    pub fn amount_to_send(&self) -> u64 {
+        self.rows.iter().filter(|r| r.can_send()).map(|r| r.amount).sum()
+    }
+
+    /// Get minimal fee to send rows, each row is sent as separate transaction.
+    pub fn fee_to_send(&self) -> u64 {
+        let count = self.rows.iter().filter(|r| r.can_send()).count() as u64;
+        count * tx_fee(1, 2, 1)
+    }
+
+    /// Check if there are rows to send.
+    pub fn can_send(&self) -> bool {
+        self.rows.iter().any(|r| r.can_send())
+    }
+
+    /// Get status report in CSV format.
+    pub fn report_csv(&self) -> Vec<u8> {
+        let mut csv = "address,amount,status,slate_id,error\n".to_string();
+        for r in &self.rows {
+            let (status, id, err) = match &r.status {
+                BatchRowStatus::Pending => ("pending", "", ""),
+                BatchRowStatus::Sending => ("sending", "", ""),
+                BatchRowStatus::Sent(id) => ("sent", id.as_str(), ""),
+                BatchRowStatus::Failed(e) => ("failed", "", e.as_str()),
+                BatchRowStatus::NotPosted(id, e) => ("not_posted", id.as_str(), e.as_str()),
+            };
+            csv.push_str(format!("{},{},{},{},\"{}\"\n",
+                                 r.address,
+                                 amount_to_hr_string(r.amount, true),
+                                 status,
+                                 id,
+                                 err.replace("\"", "\"\"")).as_str());
+        }
+        csv.into_bytes()
+    }
+}
+
+/// Split CSV line by delimiter with trimmed values, delimiter inside quotes is skipped.
+fn split_csv_line(line: &str, delimiter: char) -> Vec<String> {
+    let mut values = vec![];
+    let mut value = String::new();
+    let mut quoted = false;
+    for c in line.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => {
+                values.push(value.trim().to_string());
+                value.clear();
+            }
+            c => value.push(c),
+        }
+    }
+    values.push(value.trim().to_string());
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS_1: &str = "grin1tpnxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenqnr5dak";
+    const ADDRESS_2: &str = "grin1ex3ls64wge0su4j38pj9zreejatplgkfap02y8wz9y3snu7dvq3q2xxzk9";
+
+    #[test]
+    fn parses_comma_separated_rows_with_header() {
+        let data = format!("address,amount\n{},1.5\n\n{},\"2\"\n", ADDRESS_1, ADDRESS_2);
+        let batch = BatchPayout::from_csv(&data).unwrap();
+        assert_eq!(batch.rows.len(), 2);
+        assert_eq!(batch.rows[0].address, ADDRESS_1);
+        assert_eq!(batch.rows[0].amount, 1_500_000_000);
+        assert_eq!(batch.rows[1].amount, 2_000_000_000);
+        assert_eq!(batch.amount_to_send(), 3_500_000_000);
+    }
+
+    #[test]
+    fn parses_semicolon_rows_with_decimal_comma() {
+        let data = format!("address;amount\n{};1,5\n{}; 0,25 \n", ADDRESS_1, ADDRESS_2);
+        let batch = BatchPayout::from_csv(&data).unwrap();
+        assert_eq!(batch.rows[0].amount, 1_500_000_000);
+        assert_eq!(batch.rows[1].amount, 250_000_000);
+    }
+
+    #[test]
+    fn parses_quoted_decimal_comma() {
+        let data = format!("{},\"1,5\"", ADDRESS_1);
+        let batch = BatchPayout::from_csv(&data).unwrap();
+        assert_eq!(batch.rows[0].amount, 1_500_000_000);
+    }
+
+    #[test]
+    fn rejects_extra_fields() {
+        // Unquoted decimal comma at comma separated file.
+        assert_eq!(BatchPayout::from_csv(&format!("{},1,5", ADDRESS_1)).err(), Some(1));
+        let data = format!("{};1;note\n", ADDRESS_1);
+        assert_eq!(BatchPayout::from_csv(&data).err(), Some(1));
+        let data = format!("{};1\n{},2\n", ADDRESS_1, ADDRESS_2);
+        assert_eq!(BatchPayout::from_csv(&data).err(), Some(2));
+    }
+
+    #[test]
+    fn rejects_invalid_amounts() {
+        let data = format!("{},1\n{},abc\n", ADDRESS_1, ADDRESS_2);
+        assert_eq!(BatchPayout::from_csv(&data).err(), Some(2));
+        assert_eq!(BatchPayout::from_csv(&format!("{},0", ADDRESS_1)).err(), Some(1));
+        assert_eq!(BatchPayout::from_csv(&format!("{},-1", ADDRESS_1)).err(), Some(1));
+    }
+
+    #[test]
+    fn rejects_invalid_address() {
+        assert_eq!(BatchPayout::from_csv("grin1invalid,1").err(), Some(1));
+        let data = format!("{},1\nheader,amount\n", ADDRESS_1);
+        assert_eq!(BatchPayout::from_csv(&data).err(), Some(2));
+    }
+
+    #[test]
+    fn rejects_empty_data() {
+        assert_eq!(BatchPayout::from_csv("").err(), Some(0));
+        assert_eq!(BatchPayout::from_csv("address,amount\n").err(), Some(0));
+    }
+}
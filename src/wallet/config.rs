@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 use std::string::ToString;
@@ -22,7 +23,7 @@ use rand::Rng;
 use serde_derive::{Deserialize, Serialize};
 
 use crate::{AppConfig, Settings};
-use crate::wallet::ConnectionsConfig;
+use crate::wallet::{ConnectionsConfig, PaymentsLog, ReceiveAddress, RecurringPayment, WalletContacts, WalletInvoices, WatchOnlyData};
 use crate::wallet::types::{ConnectionMethod, WalletTransaction};
 
 /// Wallet configuration.
@@ -48,6 +49,17 @@ pub struct WalletConfig {
     pub api_port: Option<u16>,
     /// Delay in blocks before another transaction broadcasting attempt.
     pub tx_broadcast_timeout: Option<u64>,
+    /// Payments to send on schedule.
+    pub recurring_payments: Option<Vec<RecurringPayment>>,
+    /// Maximum amount of outputs to merge by one consolidation transaction.
+    pub consolidation_batch: Option<usize>,
+    /// Additional addresses to receive transactions.
+    pub receive_addresses: Option<Vec<ReceiveAddress>>,
+    /// Rewind hash of watched wallet, wallet is watch-only when provided.
+    pub rewind_hash: Option<String>,
+    /// Last derivation index of additional receive address for each account,
+    /// not lowered on address deletion to not derive the same address again.
+    pub receive_address_indexes: Option<HashMap<String, u32>>,
 }
 
 /// Base wallets directory name.
@@ -62,6 +74,8 @@ const CONFIG_FILE_NAME: &'static str = "grim-wallet.toml";
 const SLATEPACKS_DIR_NAME: &'static str = "slatepacks";
 /// Seed file name.
 const SEED_FILE: &str = "wallet.seed";
+/// File name of outputs hidden from inputs selection while sending with coin control.
+const HIDDEN_OUTPUTS_FILE: &str = "hidden_outputs.json";
 
 /// Default value of minimal amount of confirmations.
 const MIN_CONFIRMATIONS_DEFAULT: u64 = 10;
@@ -73,6 +87,9 @@ impl WalletConfig {
     /// Default value of timeout for broadcasting transaction in blocks.
     pub const BROADCASTING_TIMEOUT_DEFAULT: u64 = 10;
 
+    /// Default amount of outputs to merge by one consolidation transaction.
+    pub const CONSOLIDATION_BATCH_DEFAULT: usize = 100;
+
     /// Create new wallet config.
     pub fn create(name: String, conn_method: &ConnectionMethod) -> WalletConfig {
         // Setup configuration path.
@@ -94,6 +111,11 @@ impl WalletConfig {
             enable_tor_listener: Some(false),
             api_port: Some(rand::rng().random_range(10000..30000)),
             tx_broadcast_timeout: Some(Self::BROADCASTING_TIMEOUT_DEFAULT),
+            recurring_payments: None,
+            consolidation_batch: Some(Self::CONSOLIDATION_BATCH_DEFAULT),
+            receive_addresses: None,
+            rewind_hash: None,
+            receive_address_indexes: None,
         };
         Settings::write_to_file(&config, config_path);
         config
@@ -139,6 +161,11 @@ impl WalletConfig {
         ConnectionMethod::Integrated
     }
 
+    /// Check if wallet is watch-only.
+    pub fn is_watch_only(&self) -> bool {
+        self.rewind_hash.is_some()
+    }
+
     /// Save wallet config.
     pub fn save(&self) {
         let config_path = Self::get_config_file_path(self.chain_type, self.id);
@@ -198,6 +225,41 @@ impl WalletConfig {
         path.to_str().unwrap().to_string()
     }
 
+    /// Get wallet address book file path.
+    pub fn get_contacts_path(&self) -> PathBuf {
+        let mut path = PathBuf::from(self.get_wallet_path());
+        path.push(WalletContacts::FILE_NAME);
+        path
+    }
+
+    /// Get wallet recurring payments log file path.
+    pub fn get_payments_log_path(&self) -> PathBuf {
+        let mut path = PathBuf::from(self.get_wallet_path());
+        path.push(PaymentsLog::FILE_NAME);
+        path
+    }
+
+    /// Get wallet issued invoices file path.
+    pub fn get_invoices_path(&self) -> PathBuf {
+        let mut path = PathBuf::from(self.get_wallet_path());
+        path.push(WalletInvoices::FILE_NAME);
+        path
+    }
+
+    /// Get file path of outputs hidden from inputs selection while sending.
+    pub fn get_hidden_outputs_path(&self) -> PathBuf {
+        let mut path = PathBuf::from(self.get_wallet_path());
+        path.push(HIDDEN_OUTPUTS_FILE);
+        path
+    }
+
+    /// Get watch-only wallet data file path.
+    pub fn get_watch_data_path(&self) -> PathBuf {
+        let mut path = PathBuf::from(self.get_wallet_path());
+        path.push(WatchOnlyData::FILE_NAME);
+        path
+    }
+
     /// Get Slatepack file path for transaction.
     pub fn get_tx_slate_path(&self, tx: &WalletTransaction) -> PathBuf {
         let mut path = PathBuf::from(self.get_wallet_path());
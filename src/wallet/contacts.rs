@@ -0,0 +1,101 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use grin_wallet_libwallet::SlatepackAddress;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::Settings;
+use crate::wallet::WalletConfig;
+
+/// Address book contact.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct WalletContact {
+    /// Contact name.
+    pub name: String,
+    /// Slatepack address.
+    pub address: String,
+}
+
+impl WalletContact {
+    /// Check if contact has valid name and address.
+    pub fn is_valid(&self) -> bool {
+        !self.name.trim().is_empty() &&
+            SlatepackAddress::try_from(self.address.trim()).is_ok()
+    }
+}
+
+/// Wallet address book.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct WalletContacts {
+    /// List of contacts.
+    contacts: Vec<WalletContact>
+}
+
+impl WalletContacts {
+    /// Address book file name.
+    pub const FILE_NAME: &'static str = "contacts.toml";
+
+    /// Load address book for provided wallet configuration.
+    pub fn load(config: &WalletConfig) -> Self {
+        Settings::read_from_file::<WalletContacts>(config.get_contacts_path())
+            .unwrap_or_default()
+    }
+
+    /// Save address book for provided wallet configuration.
+    pub fn save(&self, config: &WalletConfig) {
+        Settings::write_to_file(self, config.get_contacts_path());
+    }
+
+    /// Get list of contacts.
+    pub fn list(&self) -> &Vec<WalletContact> {
+        &self.contacts
+    }
+
+    /// Find contact by Slatepack address.
+    pub fn find(&self, address: &String) -> Option<&WalletContact> {
+        self.contacts.iter().find(|c| &c.address == address)
+    }
+
+    /// Add new contact or update contact with the same address.
+    pub fn add(&mut self, contact: WalletContact) {
+        if let Some(pos) = self.contacts.iter().position(|c| c.address == contact.address) {
+            self.contacts[pos] = contact;
+        } else {
+            self.contacts.push(contact);
+        }
+        self.contacts.sort_by_key(|c| c.name.to_lowercase());
+    }
+
+    /// Remove contact with provided Slatepack address.
+    pub fn remove(&mut self, address: &String) {
+        self.contacts.retain(|c| &c.address != address);
+    }
+
+    /// Serialize contacts to JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.contacts).unwrap_or_default()
+    }
+
+    /// Parse valid contacts from JSON.
+    pub fn from_json(data: &str) -> Option<Vec<WalletContact>> {
+        let contacts = serde_json::from_str::<Vec<WalletContact>>(data).ok()?;
+        Some(contacts.into_iter()
+            .filter(|c| c.is_valid())
+            .map(|c| WalletContact {
+                name: c.name.trim().to_string(),
+                address: c.address.trim().to_string()
+            })
+            .collect())
+    }
+}
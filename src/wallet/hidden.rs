@@ -0,0 +1,251 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::path::PathBuf;
+use grin_keychain::ExtKeychain;
+use grin_wallet_impls::HTTPNodeClient;
+use grin_wallet_libwallet::{Error, OutputData, OutputStatus, WalletBackend};
+
+/// Wallet backend to hide outputs at.
+type Backend = dyn WalletBackend<'static, HTTPNodeClient, ExtKeychain>;
+
+/// Spendable outputs hidden from inputs selection while sending with coin control,
+/// their statuses are saved at the file to restore them if sending was interrupted.
+pub struct HiddenOutputs {
+    /// File to save outputs statuses.
+    path: PathBuf,
+    /// Commitments of hidden outputs with original statuses.
+    outputs: Vec<(String, OutputStatus)>,
+}
+
+impl HiddenOutputs {
+    /// Load outputs left hidden by interrupted sending from provided file.
+    pub fn load(path: PathBuf) -> Option<Self> {
+        let data = fs::read(&path).ok()?;
+        let outputs = serde_json::from_slice(&data).unwrap_or_default();
+        Some(Self { path, outputs })
+    }
+
+    /// Run provided function with spendable outputs of current account except provided
+    /// commitments hidden from inputs selection, original statuses are restored after the call
+    /// even on failure, the file is kept to restore them later if restoring was not successful.
+    pub fn with_hidden<T>(w: &mut Backend,
+                          commits: &Vec<String>,
+                          path: PathBuf,
+                          f: impl FnOnce(&mut Backend) -> Result<T, Error>) -> Result<T, Error> {
+        let parent_key_id = w.parent_key_id();
+        let outputs: Vec<OutputData> = w.iter().filter(|o| {
+            let commit = o.commit.clone().unwrap_or_default();
+            o.root_key_id == parent_key_id && !commits.contains(&commit) &&
+                (o.status == OutputStatus::Unspent || o.status == OutputStatus::Unconfirmed)
+        }).collect();
+        let hidden = Self {
+            path,
+            outputs: outputs.iter()
+                .map(|o| (o.commit.clone().unwrap_or_default(), o.status.clone()))
+                .collect(),
+        };
+        let data = serde_json::to_vec(&hidden.outputs)
+            .map_err(|e| Error::GenericError(e.to_string()))?;
+        fs::write(&hidden.path, data).map_err(|e| Error::IO(e.to_string()))?;
+
+        // Restore statuses when leaving the scope.
+        let mut guard = RestoreGuard { w, hidden, restored: false };
+        let res = (|| -> Result<T, Error> {
+            {
+                let mut batch = guard.w.batch(None)?;
+                for o in &outputs {
+                    batch.lock_output(&mut o.clone())?;
+                }
+                batch.commit()?;
+            }
+            f(&mut *guard.w)
+        })();
+        guard.restored = true;
+        guard.hidden.restore(&mut *guard.w)?;
+        res
+    }
+
+    /// Restore status of hidden outputs which are still locked, other changes are kept,
+    /// the file is removed on success.
+    pub fn restore(&self, w: &mut Backend) -> Result<(), Error> {
+        let outputs: Vec<(OutputData, OutputStatus)> = w.iter().filter_map(|o| {
+            if o.status != OutputStatus::Locked {
+                return None;
+            }
+            let commit = o.commit.clone().unwrap_or_default();
+            self.outputs.iter()
+                .find(|(c, _)| c == &commit)
+                .map(|(_, status)| (o, status.clone()))
+        }).collect();
+        let mut batch = w.batch(None)?;
+        for (mut o, status) in outputs {
+            o.status = status;
+            batch.save(o)?;
+        }
+        batch.commit()?;
+        let _ = fs::remove_file(&self.path);
+        Ok(())
+    }
+}
+
+/// Guard to restore hidden outputs if provided function was interrupted by panic.
+struct RestoreGuard<'a> {
+    /// Wallet backend.
+    w: &'a mut Backend,
+    /// Hidden outputs to restore.
+    hidden: HiddenOutputs,
+    /// Flag to check if restoring was already done.
+    restored: bool,
+}
+
+impl Drop for RestoreGuard<'_> {
+    fn drop(&mut self) {
+        if !self.restored {
+            // File is kept on error to restore at next wallet opening.
+            let _ = self.hidden.restore(&mut *self.w);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use grin_keychain::Keychain;
+    use grin_wallet_impls::LMDBBackend;
+    use grin_wallet_libwallet::api_impl::owner;
+    use grin_wallet_libwallet::InitTxArgs;
+
+    /// Create wallet backend at temporary directory with outputs of provided statuses.
+    fn backend(name: &str, statuses: &[OutputStatus]) -> (Box<Backend>, PathBuf) {
+        let mut dir = std::env::temp_dir();
+        dir.push(format!("grim_hidden_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let client = HTTPNodeClient::new("http://127.0.0.1:1", None).unwrap();
+        let backend = LMDBBackend::<HTTPNodeClient, ExtKeychain>::new(dir.to_str().unwrap(),
+                                                                      client);
+        let mut w: Box<Backend> = Box::new(backend.unwrap());
+        w.set_keychain(Box::new(ExtKeychain::from_random_seed(false).unwrap()), false, false)
+            .unwrap();
+        let parent_key_id = w.parent_key_id();
+        {
+            let mut batch = w.batch(None).unwrap();
+            for (i, status) in statuses.iter().enumerate() {
+                let key_id = ExtKeychain::derive_key_id(3, 0, 0, i as u32, 0);
+                batch.save(OutputData {
+                    root_key_id: parent_key_id.clone(),
+                    key_id,
+                    n_child: i as u32,
+                    commit: Some(format!("commit{}", i)),
+                    mmr_index: None,
+                    value: 1_000_000_000,
+                    status: status.clone(),
+                    height: 1,
+                    lock_height: 0,
+                    is_coinbase: false,
+                    tx_log_entry: None,
+                }).unwrap();
+            }
+            batch.commit().unwrap();
+        }
+        dir.push("hidden_outputs.json");
+        (w, dir)
+    }
+
+    /// Get output statuses ordered by commitment.
+    fn statuses(w: &Backend) -> Vec<OutputStatus> {
+        let mut outputs: Vec<OutputData> = w.iter().collect();
+        outputs.sort_by_key(|o| o.commit.clone());
+        outputs.into_iter().map(|o| o.status).collect()
+    }
+
+    #[test]
+    fn restores_statuses_after_success() {
+        let initial = [OutputStatus::Unspent, OutputStatus::Unconfirmed, OutputStatus::Unspent];
+        let (mut w, path) = backend("success", &initial);
+        let commits = vec!["commit0".to_string()];
+        HiddenOutputs::with_hidden(&mut *w, &commits, path.clone(), |w| {
+            assert!(path.exists());
+            assert_eq!(statuses(w), vec![OutputStatus::Unspent,
+                                         OutputStatus::Locked,
+                                         OutputStatus::Locked]);
+            // Lock selected output like sending transaction does.
+            let mut o = w.iter().find(|o| o.commit == Some("commit0".to_string())).unwrap();
+            let mut batch = w.batch(None)?;
+            batch.lock_output(&mut o)?;
+            batch.commit()?;
+            Ok(())
+        }).unwrap();
+        assert_eq!(statuses(&*w), vec![OutputStatus::Locked,
+                                       OutputStatus::Unconfirmed,
+                                       OutputStatus::Unspent]);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn restores_statuses_after_failed_sending() {
+        let initial = [OutputStatus::Unspent, OutputStatus::Unspent, OutputStatus::Spent];
+        let (mut w, path) = backend("failure", &initial);
+        let commits = vec!["commit1".to_string()];
+        let res = HiddenOutputs::with_hidden(&mut *w, &commits, path.clone(), |w| {
+            let args = InitTxArgs {
+                src_acct_name: None,
+                amount: 1_000_000_000_000,
+                minimum_confirmations: 1,
+                max_outputs: 500,
+                num_change_outputs: 1,
+                selection_strategy_is_use_all: false,
+                ..Default::default()
+            };
+            owner::init_send_tx(w, None, args, false)
+        });
+        assert!(res.is_err());
+        assert_eq!(statuses(&*w), initial.to_vec());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn restores_statuses_from_file() {
+        let initial = [OutputStatus::Unspent, OutputStatus::Unspent];
+        let (mut w, path) = backend("file", &initial);
+        let commits = vec![];
+        let hidden_path = path.clone();
+        let res: Result<(), Error> = HiddenOutputs::with_hidden(&mut *w, &commits, hidden_path, |w| {
+            // Simulate interrupted sending with outputs left locked.
+            let outputs: Vec<OutputData> = w.iter().collect();
+            let data = fs::read(&path).unwrap();
+            assert_eq!(outputs.len(), 2);
+            assert!(outputs.iter().all(|o| o.status == OutputStatus::Locked));
+            fs::write(path.with_extension("bak"), data).unwrap();
+            Err(Error::GenericError("interrupted".to_string()))
+        });
+        assert!(res.is_err());
+
+        // Lock outputs again and restore from saved file.
+        {
+            let outputs: Vec<OutputData> = w.iter().collect();
+            let mut batch = w.batch(None).unwrap();
+            for mut o in outputs {
+                batch.lock_output(&mut o).unwrap();
+            }
+            batch.commit().unwrap();
+        }
+        fs::rename(path.with_extension("bak"), &path).unwrap();
+        HiddenOutputs::load(path.clone()).unwrap().restore(&mut *w).unwrap();
+        assert_eq!(statuses(&*w), initial.to_vec());
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::Settings;
+use crate::wallet::types::WalletTransaction;
+use crate::wallet::WalletConfig;
+
+/// Invoice details provided on creation.
+#[derive(Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct InvoiceRequest {
+    /// Payment description.
+    pub memo: String,
+    /// Additional Slatepack address invoice is requested by, main address if empty.
+    pub requested_by: String,
+    /// Expiration time in seconds, invoice never expires if `None`.
+    pub expires: Option<i64>,
+}
+
+/// Status of issued invoice.
+#[derive(Clone, PartialEq)]
+pub enum InvoiceStatus {
+    /// Invoice is waiting for payment.
+    Open,
+    /// Invoice was paid and finalized.
+    Paid,
+    /// Invoice was expired or cancelled.
+    Expired,
+}
+
+/// Issued invoice.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct WalletInvoice {
+    /// Transaction slate identifier.
+    pub slate_id: String,
+    /// Account label invoice was issued from.
+    pub account: String,
+    /// Requested amount.
+    pub amount: u64,
+    /// Creation time in seconds.
+    pub created: i64,
+    /// Invoice details.
+    pub request: InvoiceRequest,
+}
+
+impl WalletInvoice {
+    /// Check if invoice expiration time has passed.
+    pub fn expired(&self) -> bool {
+        self.request.expires.map(|e| e <= chrono::Utc::now().timestamp()).unwrap_or(false)
+    }
+
+    /// Get invoice status from its transaction.
+    pub fn status(&self, tx: Option<&WalletTransaction>) -> InvoiceStatus {
+        match tx {
+            // Transaction can be not loaded yet.
+            None => if self.expired() {
+                InvoiceStatus::Expired
+            } else {
+                InvoiceStatus::Open
+            },
+            Some(tx) => {
+                if tx.data.confirmed || tx.finalized() {
+                    InvoiceStatus::Paid
+                } else if tx.cancelled() || self.expired() {
+                    InvoiceStatus::Expired
+                } else {
+                    InvoiceStatus::Open
+                }
+            }
+        }
+    }
+}
+
+/// Invoices issued by the wallet.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct WalletInvoices {
+    /// List of invoices from newest to oldest.
+    invoices: Vec<WalletInvoice>
+}
+
+impl WalletInvoices {
+    /// Invoices file name.
+    pub const FILE_NAME: &'static str = "invoices.toml";
+
+    /// Load invoices for provided wallet configuration.
+    pub fn load(config: &WalletConfig) -> Self {
+        Settings::read_from_file::<WalletInvoices>(config.get_invoices_path())
+            .unwrap_or_default()
+    }
+
+    /// Add invoice and save invoices for provided wallet configuration.
+    pub fn add(&mut self, invoice: WalletInvoice, config: &WalletConfig) {
+        self.invoices.insert(0, invoice);
+        Settings::write_to_file(self, config.get_invoices_path());
+    }
+
+    /// Get list of invoices.
+    pub fn list(&self) -> &Vec<WalletInvoice> {
+        &self.invoices
+    }
+
+    /// Find invoice by transaction slate identifier.
+    pub fn find(&self, slate_id: &String) -> Option<&WalletInvoice> {
+        self.invoices.iter().find(|i| &i.slate_id == slate_id)
+    }
+}
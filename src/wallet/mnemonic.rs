@@ -16,6 +16,7 @@ use grin_keychain::mnemonic::{from_entropy, search, to_entropy};
 use grin_util::ZeroingString;
 use rand::Rng;
 
+use crate::wallet::SeedShares;
 use crate::wallet::types::{PhraseMode, PhraseSize, PhraseWord};
 
 /// Mnemonic phrase container.
@@ -30,6 +31,10 @@ pub struct Mnemonic {
     confirmation: Vec<PhraseWord>,
     /// Flag to check if entered phrase if valid.
     valid: bool,
+    /// Threshold and count of SLIP-39 shares to split generated phrase into.
+    split: Option<(u8, u8)>,
+    /// Entered SLIP-39 shares to restore the phrase.
+    shares: Vec<ZeroingString>,
 }
 
 impl Default for Mnemonic {
@@ -38,7 +43,7 @@ impl Default for Mnemonic {
         let mode = PhraseMode::Generate;
         let words = Self::generate_words(&mode, &size);
         let confirmation = Self::empty_words(&size);
-        Self { mode, size, words, confirmation, valid: true }
+        Self { mode, size, words, confirmation, valid: true, split: None, shares: vec![] }
     }
 }
 
@@ -49,6 +54,8 @@ impl Mnemonic {
         self.words = Self::generate_words(&self.mode, &self.size);
         self.confirmation = Self::empty_words(&self.size);
         self.valid = true;
+        self.split = None;
+        self.shares.clear();
     }
 
     /// Get current phrase mode.
@@ -62,6 +69,7 @@ impl Mnemonic {
         self.words = Self::generate_words(&self.mode, &self.size);
         self.confirmation = Self::empty_words(&self.size);
         self.valid = true;
+        self.shares.clear();
     }
 
     /// Get current phrase size.
@@ -83,6 +91,27 @@ impl Mnemonic {
         }.clone()
     }
 
+    /// Setup threshold and count of shares to split generated phrase into.
+    pub fn set_split(&mut self, split: Option<(u8, u8)>) {
+        self.split = split;
+    }
+
+    /// Get threshold and count of shares to split generated phrase into.
+    pub fn split(&self) -> Option<(u8, u8)> {
+        self.split
+    }
+
+    /// Split generated phrase into SLIP-39 shares.
+    pub fn generate_shares(&self) -> Result<Vec<ZeroingString>, String> {
+        let (threshold, count) = self.split.ok_or("No split setup".to_string())?;
+        SeedShares::split(&ZeroingString::from(self.get_phrase()), threshold, count)
+    }
+
+    /// Get amount of entered SLIP-39 shares.
+    pub fn shares_count(&self) -> usize {
+        self.shares.len()
+    }
+
     /// Check if current phrase is valid.
     pub fn valid(&self) -> bool {
         self.valid
@@ -187,8 +216,18 @@ impl Mnemonic {
         None
     }
 
-    /// Setup phrase from provided text if possible.
+    /// Setup phrase from provided text or SLIP-39 shares on separate lines if possible.
     pub fn import(&mut self, text: &ZeroingString) {
+        if self.mode == PhraseMode::Import {
+            let shares = text.lines()
+                .map(SeedShares::normalize)
+                .filter(|s| SeedShares::is_share(s))
+                .collect::<Vec<String>>();
+            if !shares.is_empty() {
+                self.import_shares(shares);
+                return;
+            }
+        }
         let words_split = text.trim().split(" ");
         let count = words_split.clone().count();
         if let Some(size) = PhraseSize::type_for_value(count) {
@@ -219,6 +258,19 @@ impl Mnemonic {
         }
     }
 
+    /// Add SLIP-39 shares and restore the phrase when there are enough shares.
+    fn import_shares(&mut self, shares: Vec<String>) {
+        for share in shares {
+            if !self.shares.iter().any(|s| s.as_str() == share) {
+                self.shares.push(ZeroingString::from(share));
+            }
+        }
+        if let Ok(phrase) = SeedShares::combine(&self.shares) {
+            self.shares.clear();
+            self.import(&phrase);
+        }
+    }
+
     /// Check if phrase has invalid or empty words.
     pub fn has_empty_or_invalid(&self) -> bool {
         let words = match self.mode {
@@ -17,6 +17,9 @@ pub mod types;
 mod mnemonic;
 pub use mnemonic::Mnemonic;
 
+mod shares;
+pub use shares::SeedShares;
+
 mod connections;
 pub use connections::*;
 
@@ -29,6 +32,33 @@ pub use config::*;
 mod list;
 pub use list::*;
 
+mod contacts;
+pub use contacts::*;
+
+mod payments;
+pub use payments::*;
+
+mod batch;
+pub use batch::*;
+
+mod addresses;
+pub use addresses::*;
+
+mod invoices;
+pub use invoices::*;
+
+mod watch;
+pub use watch::*;
+
+mod backup;
+pub use backup::*;
+
+mod hidden;
+pub use hidden::HiddenOutputs;
+
+mod uri;
+pub use uri::PaymentUri;
+
 mod utils;
 pub use utils::WalletUtils;
 
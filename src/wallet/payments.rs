@@ -0,0 +1,222 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{DateTime, Days, Months};
+use grin_wallet_libwallet::SlatepackAddress;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::Settings;
+use crate::wallet::WalletConfig;
+
+/// Interval between recurring payments.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub enum PaymentInterval {
+    Day, Week, Month
+}
+
+impl PaymentInterval {
+    /// Get time of the payment after provided amount of intervals from start time,
+    /// monthly payments keep day of the start date when month has it.
+    pub fn nth(&self, start: i64, n: u32) -> i64 {
+        let date = DateTime::from_timestamp(start, 0).unwrap_or_default();
+        let next = match self {
+            PaymentInterval::Day => date.checked_add_days(Days::new(n as u64)),
+            PaymentInterval::Week => date.checked_add_days(Days::new(7 * n as u64)),
+            PaymentInterval::Month => date.checked_add_months(Months::new(n))
+        };
+        next.map(|d| d.timestamp()).unwrap_or(i64::MAX)
+    }
+}
+
+/// Payment to send on schedule.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct RecurringPayment {
+    /// Payment identifier.
+    pub id: i64,
+    /// Amount to send.
+    pub amount: u64,
+    /// Receiver Slatepack address to send over Tor.
+    pub address: String,
+    /// Interval between payments.
+    pub interval: PaymentInterval,
+    /// Time of the next payment in seconds.
+    pub next_run: i64,
+    /// Time of the first payment in seconds to count next runs from.
+    #[serde(default)]
+    pub start: i64,
+}
+
+impl RecurringPayment {
+    /// Check if payment has correct values.
+    pub fn is_valid(&self) -> bool {
+        self.amount > 0 && SlatepackAddress::try_from(self.address.trim()).is_ok()
+    }
+
+    /// Check if payment is due at provided time.
+    pub fn is_due(&self, now: i64) -> bool {
+        self.next_run <= now
+    }
+
+    /// Move time of the next payment after provided time skipping missed runs.
+    pub fn schedule_next(&mut self, now: i64) {
+        if self.start == 0 {
+            self.start = self.next_run;
+        }
+        let mut n = 0;
+        while self.next_run <= now {
+            n += 1;
+            self.next_run = self.interval.nth(self.start, n);
+        }
+    }
+}
+
+/// Result of recurring payment run.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub enum PaymentRunStatus {
+    /// Payment was sent and posted.
+    /// * slate id
+    Sent(String),
+    /// Payment was skipped by user.
+    Skipped,
+    /// Payment was not sent.
+    /// * error
+    Failed(String),
+}
+
+/// Recurring payment run log entry.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PaymentRun {
+    /// Payment identifier.
+    pub payment_id: i64,
+    /// Time of the run in seconds.
+    pub time: i64,
+    /// Amount of the payment.
+    pub amount: u64,
+    /// Receiver Slatepack address.
+    pub address: String,
+    /// Run result.
+    pub status: PaymentRunStatus,
+}
+
+/// Log of recurring payment runs.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct PaymentsLog {
+    /// List of runs from newest to oldest.
+    runs: Vec<PaymentRun>
+}
+
+impl PaymentsLog {
+    /// Log file name.
+    pub const FILE_NAME: &'static str = "payments_log.toml";
+
+    /// Maximum amount of entries to keep.
+    const MAX_RUNS: usize = 100;
+
+    /// Load log for provided wallet configuration.
+    pub fn load(config: &WalletConfig) -> Self {
+        Settings::read_from_file::<PaymentsLog>(config.get_payments_log_path())
+            .unwrap_or_default()
+    }
+
+    /// Add run entry and save log for provided wallet configuration.
+    pub fn add(&mut self, run: PaymentRun, config: &WalletConfig) {
+        self.runs.insert(0, run);
+        self.runs.truncate(Self::MAX_RUNS);
+        Settings::write_to_file(self, config.get_payments_log_path());
+    }
+
+    /// Get list of runs.
+    pub fn runs(&self) -> &Vec<PaymentRun> {
+        &self.runs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    const ADDRESS: &str = "grin1tpnxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenqnr5dak";
+
+    /// Get timestamp of provided date at noon.
+    fn time(year: i32, month: u32, day: u32) -> i64 {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+            .and_hms_opt(12, 0, 0).unwrap()
+            .and_utc()
+            .timestamp()
+    }
+
+    /// Create payment with provided interval and time of the first run.
+    fn payment(interval: PaymentInterval, next_run: i64) -> RecurringPayment {
+        RecurringPayment {
+            id: 1,
+            amount: 1_000_000_000,
+            address: ADDRESS.to_string(),
+            interval,
+            next_run,
+            start: 0,
+        }
+    }
+
+    #[test]
+    fn keeps_day_of_month() {
+        let start = time(2025, 1, 31);
+        assert_eq!(PaymentInterval::Month.nth(start, 1), time(2025, 2, 28));
+        assert_eq!(PaymentInterval::Month.nth(start, 2), time(2025, 3, 31));
+        assert_eq!(PaymentInterval::Month.nth(start, 3), time(2025, 4, 30));
+        assert_eq!(PaymentInterval::Month.nth(start, 12), time(2026, 1, 31));
+    }
+
+    #[test]
+    fn counts_leap_years() {
+        let start = time(2024, 1, 31);
+        assert_eq!(PaymentInterval::Month.nth(start, 1), time(2024, 2, 29));
+        let start = time(2024, 2, 29);
+        assert_eq!(PaymentInterval::Month.nth(start, 12), time(2025, 2, 28));
+        assert_eq!(PaymentInterval::Month.nth(start, 48), time(2028, 2, 29));
+        assert_eq!(PaymentInterval::Day.nth(time(2024, 2, 28), 1), time(2024, 2, 29));
+        assert_eq!(PaymentInterval::Day.nth(time(2025, 2, 28), 1), time(2025, 3, 1));
+    }
+
+    #[test]
+    fn adds_weeks() {
+        let start = time(2024, 12, 27);
+        assert_eq!(PaymentInterval::Week.nth(start, 0), start);
+        assert_eq!(PaymentInterval::Week.nth(start, 1), time(2025, 1, 3));
+        assert_eq!(PaymentInterval::Week.nth(start, 10), time(2025, 3, 7));
+    }
+
+    #[test]
+    fn schedules_next_run_from_start() {
+        let mut p = payment(PaymentInterval::Month, time(2024, 1, 31));
+        assert!(p.is_due(time(2024, 1, 31)));
+        p.schedule_next(time(2024, 1, 31));
+        assert_eq!(p.start, time(2024, 1, 31));
+        assert_eq!(p.next_run, time(2024, 2, 29));
+        p.schedule_next(time(2024, 2, 29));
+        // Day of the first payment is restored at longer month.
+        assert_eq!(p.next_run, time(2024, 3, 31));
+        assert!(!p.is_due(time(2024, 3, 30)));
+    }
+
+    #[test]
+    fn skips_missed_runs() {
+        let mut p = payment(PaymentInterval::Week, time(2025, 1, 1));
+        p.schedule_next(time(2025, 1, 20));
+        assert_eq!(p.next_run, time(2025, 1, 22));
+        let mut p = payment(PaymentInterval::Day, time(2025, 1, 1));
+        p.schedule_next(time(2025, 1, 10) + 1);
+        assert_eq!(p.next_run, time(2025, 1, 11));
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use grin_keychain::mnemonic::{from_entropy, to_entropy};
+use grin_util::ZeroingString;
+
+/// SLIP-39 Shamir shares of the wallet seed.
+pub struct SeedShares;
+
+impl SeedShares {
+    /// Maximum amount of shares to split the seed into.
+    pub const MAX_COUNT: u8 = 5;
+
+    /// Amount of words at share for each supported seed size.
+    const WORDS_COUNT: [usize; 5] = [20, 23, 27, 30, 33];
+
+    /// Iteration exponent for shares encryption.
+    const ITERATION_EXPONENT: u8 = 1;
+
+    /// Split entropy of provided mnemonic phrase into shares,
+    /// where `threshold` of `count` shares are needed to restore the seed.
+    pub fn split(phrase: &ZeroingString,
+                 threshold: u8,
+                 count: u8) -> Result<Vec<ZeroingString>, String> {
+        let entropy = to_entropy(phrase).map_err(|e| format!("{:?}", e))?;
+        let groups = sssmc39::generate_mnemonics(1,
+                                                 &[(threshold, count)],
+                                                 &entropy,
+                                                 "",
+                                                 Self::ITERATION_EXPONENT)
+            .map_err(|e| format!("{:?}", e))?;
+        let shares = groups.first()
+            .ok_or("No shares generated".to_string())?
+            .mnemonic_list()
+            .map_err(|e| format!("{:?}", e))?
+            .into_iter()
+            .map(|words| ZeroingString::from(words.join(" ")))
+            .collect();
+        Ok(shares)
+    }
+
+    /// Restore mnemonic phrase from provided shares.
+    pub fn combine(shares: &[ZeroingString]) -> Result<ZeroingString, String> {
+        let mnemonics = shares.iter()
+            .map(|s| s.split_whitespace().map(|w| w.to_string()).collect::<Vec<String>>())
+            .collect::<Vec<Vec<String>>>();
+        let entropy = sssmc39::combine_mnemonics(&mnemonics, "")
+            .map_err(|e| format!("{:?}", e))?;
+        let phrase = from_entropy(&entropy).map_err(|e| format!("{:?}", e))?;
+        Ok(ZeroingString::from(phrase))
+    }
+
+    /// Check if provided text has words count of the share.
+    pub fn is_share(text: &str) -> bool {
+        Self::WORDS_COUNT.contains(&text.split_whitespace().count())
+    }
+
+    /// Normalize share text to compare.
+    pub fn normalize(text: &str) -> String {
+        text.split_whitespace()
+            .map(|w| w.to_lowercase())
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHRASE_12: &str = "attack pizza motion avocado network gather crop fresh patrol \
+    unusual wild holiday";
+    const PHRASE_24: &str = "sound federal bonus bleak light raise false engage round stock \
+    update render quote truck quality fringe palace foot recipe labor glow tortoise potato still";
+
+    #[test]
+    fn combines_shares_into_original_phrase() {
+        for phrase in [PHRASE_12, PHRASE_24] {
+            let shares = SeedShares::split(&ZeroingString::from(phrase), 3, 5).unwrap();
+            assert_eq!(shares.len(), 5);
+            assert!(shares.iter().all(|s| SeedShares::is_share(s)));
+            let recovered = SeedShares::combine(&shares[1..4]).unwrap();
+            assert_eq!(recovered.as_str(), phrase);
+            let recovered = SeedShares::combine(&[shares[4].clone(),
+                                                  shares[0].clone(),
+                                                  shares[2].clone()]).unwrap();
+            assert_eq!(recovered.as_str(), phrase);
+        }
+    }
+
+    #[test]
+    fn fails_to_combine_below_threshold() {
+        let shares = SeedShares::split(&ZeroingString::from(PHRASE_12), 3, 5).unwrap();
+        assert!(SeedShares::combine(&shares[..2]).is_err());
+        assert!(SeedShares::combine(&[]).is_err());
+    }
+
+    #[test]
+    fn normalizes_share_text() {
+        let shares = SeedShares::split(&ZeroingString::from(PHRASE_12), 2, 3).unwrap();
+        let text = format!("  {}\n", shares[0].to_uppercase().replace(" ", "   "));
+        assert_eq!(SeedShares::normalize(&text), shares[0].as_str());
+        let normalized = ZeroingString::from(SeedShares::normalize(&text));
+        let recovered = SeedShares::combine(&[normalized, shares[1].clone()]).unwrap();
+        assert_eq!(recovered.as_str(), PHRASE_12);
+    }
+}
@@ -14,7 +14,10 @@
 
 use std::sync::{Arc, RwLock};
 use rkv::backend::{Lmdb, LmdbDatabase, LmdbEnvironment};
-use rkv::{IntegerStore, Manager, Rkv, StoreOptions, Value};
+use rkv::{IntegerStore, Manager, Rkv, SingleStore, StoreOptions, Value};
+use grin_wallet_libwallet::TxLogEntry;
+
+use crate::wallet::types::WalletTransactionNote;
 
 /// Transaction height storage.
 pub struct TxHeightStore {
@@ -98,3 +101,112 @@ impl TxHeightStore {
         writer.commit().unwrap();
     }
 }
+
+/// Transaction notes storage.
+pub struct TxNoteStore {
+    env: Arc<RwLock<Rkv<LmdbEnvironment>>>,
+    /// Notes serialized as JSON by transaction slate identifier or account with local
+    /// identifier for transactions without slate.
+    notes: SingleStore<LmdbDatabase>
+}
+
+impl TxNoteStore {
+    /// Create new transaction notes storage from provided directory.
+    pub fn new(dir: String) -> Self {
+        let mut manager = Manager::<LmdbEnvironment>::singleton().write().unwrap();
+        let env_arc = manager.get_or_create(std::path::Path::new(&dir), Rkv::new::<Lmdb>).unwrap();
+
+        let env_arc_store = env_arc.clone();
+        let env = env_arc_store.read().unwrap();
+        let notes = env.open_single("tx_slate_note", StoreOptions::create()).unwrap();
+        Self {
+            env: env_arc,
+            notes
+        }
+    }
+
+    /// Get note key for transaction, local identifier is unique only per account.
+    fn key(tx: &TxLogEntry) -> String {
+        match tx.tx_slate_id {
+            Some(slate_id) => slate_id.to_string(),
+            None => format!("{}:{}", tx.parent_key_id.to_bip_32_string(), tx.id)
+        }
+    }
+
+    /// Read transaction note from database.
+    pub fn read_note(&self, tx: &TxLogEntry) -> Option<WalletTransactionNote> {
+        let env = self.env.read().unwrap();
+        let reader = env.read().unwrap();
+        if let Ok(value) = self.notes.get(&reader, Self::key(tx)) {
+            if let Some(note) = value {
+                return match note {
+                    Value::Json(v) => serde_json::from_str(v).ok(),
+                    _ => None
+                };
+            }
+            return None;
+        }
+        None
+    }
+
+    /// Write transaction note to database, delete it if note is empty.
+    pub fn write_note(&self, tx: &TxLogEntry, note: &WalletTransactionNote) {
+        let env = self.env.read().unwrap();
+        let mut writer = env.write().unwrap();
+        let key = Self::key(tx);
+        if note.is_empty() {
+            self.notes.delete(&mut writer, &key).unwrap_or_default();
+        } else {
+            let json = serde_json::to_string(note).unwrap();
+            self.notes.put(&mut writer, &key, &Value::Json(json.as_str())).unwrap();
+        }
+        writer.commit().unwrap();
+    }
+}
+
+/// Storage of additional receive address indexes for received transactions.
+pub struct TxAddressStore {
+    env: Arc<RwLock<Rkv<LmdbEnvironment>>>,
+    /// Address derivation indexes by slate identifier.
+    indexes: SingleStore<LmdbDatabase>
+}
+
+impl TxAddressStore {
+    /// Create new receive address storage from provided directory.
+    pub fn new(dir: String) -> Self {
+        let mut manager = Manager::<LmdbEnvironment>::singleton().write().unwrap();
+        let env_arc = manager.get_or_create(std::path::Path::new(&dir), Rkv::new::<Lmdb>).unwrap();
+
+        let env_arc_store = env_arc.clone();
+        let env = env_arc_store.read().unwrap();
+        let indexes = env.open_single("tx_address", StoreOptions::create()).unwrap();
+        Self {
+            env: env_arc,
+            indexes
+        }
+    }
+
+    /// Read address derivation index for transaction slate identifier from database.
+    pub fn read_index(&self, slate_id: &String) -> Option<u32> {
+        let env = self.env.read().unwrap();
+        let reader = env.read().unwrap();
+        if let Ok(value) = self.indexes.get(&reader, slate_id) {
+            if let Some(index) = value {
+                return match index {
+                    Value::U64(v) => Some(v as u32),
+                    _ => None
+                };
+            }
+            return None;
+        }
+        None
+    }
+
+    /// Write address derivation index for transaction slate identifier to database.
+    pub fn write_index(&self, slate_id: &String, index: u32) {
+        let env = self.env.read().unwrap();
+        let mut writer = env.write().unwrap();
+        self.indexes.put(&mut writer, slate_id, &Value::U64(index as u64)).unwrap();
+        writer.commit().unwrap();
+    }
+}
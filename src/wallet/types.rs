@@ -12,15 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use chrono::NaiveDate;
+use grin_core::core::amount_from_hr_string;
 use grin_keychain::ExtKeychain;
-use grin_util::Mutex;
+use grin_util::{Mutex, ToHex, ZeroingString};
 use grin_wallet_impls::{DefaultLCProvider, HTTPNodeClient};
-use grin_wallet_libwallet::{Error, Slate, SlateState, SlatepackAddress, TxLogEntry, TxLogEntryType, WalletInfo, WalletInst};
+use grin_wallet_libwallet::{Error, OutputStatus, PaymentProof, Slate, SlateState, SlatepackAddress, TxLogEntry, TxLogEntryType, WalletInfo, WalletInst};
 use grin_wallet_util::OnionV3Address;
 use serde_derive::{Deserialize, Serialize};
 use std::sync::Arc;
 
-use crate::wallet::Wallet;
+use crate::wallet::{InvoiceRequest, ReceiveAddress, RecurringPayment, Wallet};
 
 /// Mnemonic phrase word.
 #[derive(Clone)]
@@ -143,6 +145,63 @@ pub struct WalletAccount {
     pub path: String
 }
 
+/// Wallet output data.
+#[derive(Clone)]
+pub struct WalletOutput {
+    /// Output commitment.
+    pub commit: String,
+    /// Output value.
+    pub value: u64,
+    /// Output status.
+    pub status: OutputStatus,
+    /// Block height of the output.
+    pub height: u64,
+    /// Block height after which output can be spent.
+    pub lock_height: u64,
+    /// Flag to check if output is coinbase.
+    pub is_coinbase: bool,
+    /// Label of account which output belongs to.
+    pub account: String,
+    /// Flag to check if output can be spent with current minimum confirmations.
+    pub spendable: bool,
+}
+
+/// Inputs selection policy for sending.
+#[derive(Clone, PartialEq)]
+pub struct CoinControl {
+    /// Commitments of outputs to spend, selected by the wallet if empty.
+    pub outputs: Vec<String>,
+    /// Maximum amount of inputs to use.
+    pub max_inputs: Option<u32>,
+    /// Amount of change outputs to create.
+    pub change_outputs: usize,
+}
+
+impl Default for CoinControl {
+    fn default() -> Self {
+        Self {
+            outputs: vec![],
+            max_inputs: None,
+            change_outputs: 1,
+        }
+    }
+}
+
+/// Estimation of transaction to send.
+#[derive(Clone, PartialEq)]
+pub struct SendEstimate {
+    /// Amount receiver gets.
+    pub amount: u64,
+    /// Transaction fee.
+    pub fee: u64,
+    /// Total value of inputs.
+    pub inputs_amount: u64,
+    /// Amount of inputs, `None` if it can not be calculated.
+    pub inputs: Option<usize>,
+    /// Amount of change outputs.
+    pub change_outputs: usize,
+}
+
 /// Wallet balance and transactions data.
 #[derive(Clone)]
 pub struct WalletData {
@@ -200,6 +259,33 @@ impl WalletData {
     }
 }
 
+/// User note for wallet transaction.
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct WalletTransactionNote {
+    /// Short label.
+    pub label: String,
+    /// Payment counterparty.
+    pub counterparty: String,
+    /// Note text.
+    pub note: String,
+}
+
+impl WalletTransactionNote {
+    /// Check if note has no values.
+    pub fn is_empty(&self) -> bool {
+        self.label.trim().is_empty() && self.counterparty.trim().is_empty() &&
+            self.note.trim().is_empty()
+    }
+
+    /// Check if note contains provided text ignoring case.
+    pub fn contains(&self, text: &str) -> bool {
+        let text = text.to_lowercase();
+        self.label.to_lowercase().contains(&text) ||
+            self.counterparty.to_lowercase().contains(&text) ||
+            self.note.to_lowercase().contains(&text)
+    }
+}
+
 /// Wallet transaction action.
 #[derive(Clone, PartialEq)]
 pub enum WalletTransactionAction {
@@ -226,7 +312,12 @@ pub struct WalletTransaction {
     /// Action on transaction.
     pub action: Option<WalletTransactionAction>,
     /// Action result error.
-    pub action_error: Option<Error>
+    pub action_error: Option<Error>,
+
+    /// User note.
+    pub note: Option<WalletTransactionNote>,
+    /// Additional address transaction was received at.
+    pub receive_address: Option<ReceiveAddress>,
 }
 
 impl WalletTransaction {
@@ -242,14 +333,12 @@ impl WalletTransaction {
         } else {
             tx.amount_credited - tx.amount_debited
         };
-        let receiver: Option<SlatepackAddress> = {
-            if let Some(proof) = &tx.payment_proof {
+        let receiver: Option<SlatepackAddress> = match &tx.payment_proof {
+            Some(proof) => {
                 let onion_addr = OnionV3Address::from_bytes(proof.receiver_address.to_bytes());
-                if let Ok(addr) = SlatepackAddress::try_from(onion_addr) {
-                    Some(addr);
-                }
+                SlatepackAddress::try_from(onion_addr).ok()
             }
-            None
+            None => None
         };
         let mut t = Self {
             data: tx,
@@ -260,6 +349,8 @@ impl WalletTransaction {
             broadcasting_height,
             action,
             action_error,
+            note: None,
+            receive_address: None,
         };
         // Update Slate state for unconfirmed.
         if !t.data.confirmed {
@@ -397,14 +488,17 @@ pub enum WalletTask {
     /// Create request to send.
     /// * amount
     /// * receiver
-    Send(u64, Option<SlatepackAddress>),
+    /// * payment proof request flag
+    /// * inputs selection policy
+    Send(u64, Option<SlatepackAddress>, bool, Option<CoinControl>),
     /// Send request over Tor.
     /// * local tx id
     /// * receiver
     SendTor(u32, SlatepackAddress),
     /// Invoice creation.
     /// * amount
-    Receive(u64),
+    /// * invoice details
+    Receive(u64, InvoiceRequest),
     /// Transaction finalization.
     /// * tx
     /// * local tx id
@@ -416,4 +510,243 @@ pub enum WalletTask {
     /// Cancel transaction.
     /// * tx
     Cancel(WalletTransaction),
-}
\ No newline at end of file
+    /// Verify payment proof.
+    /// * proof JSON
+    VerifyProof(String),
+    /// Send pending and failed rows of imported batch payout over Tor.
+    SendBatch,
+    /// Merge smallest spendable outputs into single output.
+    /// * maximum amount of outputs
+    Consolidate(usize),
+    /// Estimate fee, inputs and change outputs to send amount.
+    /// * amount
+    /// * inputs selection policy
+    EstimateSend(u64, Option<CoinControl>),
+    /// Create encrypted backup of wallet files.
+    /// * wallet password
+    CreateBackup(ZeroingString),
+    /// Send confirmed recurring payment over Tor and save run result.
+    /// * payment
+    SendPayment(RecurringPayment),
+}
+
+impl WalletTask {
+    /// Check if task is available for watch-only wallet,
+    /// only payment proof verification and backup can be done without the seed.
+    pub fn is_watch_only(&self) -> bool {
+        matches!(self, WalletTask::VerifyProof(_) | WalletTask::CreateBackup(_))
+    }
+}
+
+/// Payment proof verification status.
+#[derive(Clone)]
+pub enum PaymentProofStatus {
+    /// Proof is verifying.
+    Verifying,
+    /// Proof is valid.
+    /// * proof
+    /// * flag to check if sender address belongs to the wallet
+    /// * flag to check if receiver address belongs to the wallet
+    Valid(PaymentProof, bool, bool),
+    /// Proof is not valid.
+    /// * error
+    Invalid(String),
+}
+
+/// Wallet backup creation status.
+#[derive(Clone)]
+pub enum BackupStatus {
+    /// Backup is creating.
+    Creating,
+    /// Backup was created.
+    /// * backup content
+    Created(String),
+    /// Wrong wallet password was entered.
+    WrongPassword,
+    /// Backup creation failed.
+    /// * error
+    Failed(String),
+}
+
+/// Format of transaction history export.
+#[derive(Clone, PartialEq)]
+pub enum TxExportFormat {
+    /// Comma-separated values with header row.
+    Csv,
+    /// Array of transaction objects.
+    Json
+}
+
+impl TxExportFormat {
+    /// Get file extension for the format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            TxExportFormat::Csv => "csv",
+            TxExportFormat::Json => "json"
+        }
+    }
+}
+
+/// Direction of transactions to show.
+#[derive(Clone, PartialEq)]
+pub enum WalletTxDirection {
+    /// Sent transactions including cancelled.
+    Sent,
+    /// Received transactions including coinbase and cancelled.
+    Received
+}
+
+/// Status of transactions to show.
+#[derive(Clone, PartialEq)]
+pub enum WalletTxStatus {
+    /// Transactions which this wallet can finalize with the response to its request.
+    AwaitingFinalization,
+    /// Finalized transactions posted to the chain but not confirmed yet.
+    Broadcasting,
+    /// Cancelled transactions.
+    Cancelled,
+    /// Confirmed transactions.
+    Confirmed
+}
+
+/// Filter for wallet transactions list.
+#[derive(Clone, Default, PartialEq)]
+pub struct WalletTxFilter {
+    /// Text to search by slate id, kernel or note.
+    pub search: String,
+    /// Transaction direction.
+    pub direction: Option<WalletTxDirection>,
+    /// Transaction status.
+    pub status: Option<WalletTxStatus>,
+    /// Start of creation date range in `dd/mm/yyyy` format.
+    pub date_from: String,
+    /// End of creation date range in `dd/mm/yyyy` format.
+    pub date_to: String,
+    /// Minimal amount.
+    pub amount_from: String,
+    /// Maximal amount.
+    pub amount_to: String,
+
+    /// Parsed creation date range.
+    date_range: (Option<NaiveDate>, Option<NaiveDate>),
+    /// Parsed amount range.
+    amount_range: (Option<u64>, Option<u64>),
+}
+
+impl WalletTxFilter {
+    /// Format of date range values.
+    pub const DATE_FORMAT: &'static str = "%d/%m/%Y";
+
+    /// Get amount of applied filters without search text.
+    pub fn count(&self) -> usize {
+        let ranges = [&self.date_from, &self.date_to, &self.amount_from, &self.amount_to];
+        self.direction.is_some() as usize + self.status.is_some() as usize +
+            ranges.iter().filter(|v| !v.trim().is_empty()).count()
+    }
+
+    /// Parse date range value.
+    pub fn parse_date(value: &String) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(value.trim(), Self::DATE_FORMAT).ok()
+    }
+
+    /// Parse amount range value.
+    pub fn parse_amount(value: &String) -> Option<u64> {
+        amount_from_hr_string(value.trim().replace(",", ".").as_str()).ok()
+    }
+
+    /// Parse range values to use for transactions matching.
+    pub fn parse_ranges(&mut self) {
+        self.date_range = (Self::parse_date(&self.date_from), Self::parse_date(&self.date_to));
+        self.amount_range = (Self::parse_amount(&self.amount_from),
+                             Self::parse_amount(&self.amount_to));
+    }
+
+    /// Check if range values are empty or correct.
+    pub fn is_valid(&self) -> bool {
+        let date_ok = |v: &String| v.trim().is_empty() || Self::parse_date(v).is_some();
+        let amount_ok = |v: &String| v.trim().is_empty() || Self::parse_amount(v).is_some();
+        date_ok(&self.date_from) && date_ok(&self.date_to) &&
+            amount_ok(&self.amount_from) && amount_ok(&self.amount_to)
+    }
+
+    /// Check if transaction matches the filter with ranges parsed on applying.
+    pub fn matches(&self, tx: &WalletTransaction) -> bool {
+        // Check direction.
+        if let Some(direction) = &self.direction {
+            let sent = match tx.data.tx_type {
+                TxLogEntryType::TxSent | TxLogEntryType::TxSentCancelled => true,
+                _ => false
+            };
+            if sent != (direction == &WalletTxDirection::Sent) {
+                return false;
+            }
+        }
+        // Check status.
+        if let Some(status) = &self.status {
+            let matches = match status {
+                WalletTxStatus::AwaitingFinalization => tx.can_finalize(),
+                WalletTxStatus::Broadcasting => tx.broadcasting(),
+                WalletTxStatus::Cancelled => tx.cancelled(),
+                WalletTxStatus::Confirmed => tx.data.confirmed
+            };
+            if !matches {
+                return false;
+            }
+        }
+        // Check creation date range.
+        let date = tx.data.creation_ts.with_timezone(&chrono::Local).date_naive();
+        if let Some(from) = self.date_range.0 {
+            if date < from {
+                return false;
+            }
+        }
+        if let Some(to) = self.date_range.1 {
+            if date > to {
+                return false;
+            }
+        }
+        // Check amount range.
+        if let Some(from) = self.amount_range.0 {
+            if tx.amount < from {
+                return false;
+            }
+        }
+        if let Some(to) = self.amount_range.1 {
+            if tx.amount > to {
+                return false;
+            }
+        }
+        // Check search text.
+        let search = self.search.trim().to_lowercase();
+        if search.is_empty() {
+            return true;
+        }
+        let slate_id = tx.data.tx_slate_id.map(|id| id.to_string()).unwrap_or_default();
+        let kernel = tx.data.kernel_excess.map(|k| k.0.to_hex()).unwrap_or_default();
+        slate_id.contains(&search) || kernel.contains(&search) ||
+            tx.note.as_ref().map(|n| n.contains(&search)).unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_watch_only_tasks() {
+        assert!(WalletTask::VerifyProof("{}".to_string()).is_watch_only());
+        assert!(WalletTask::CreateBackup(ZeroingString::from("password")).is_watch_only());
+        let tasks = [
+            WalletTask::OpenMessage("BEGINSLATEPACK. ENDSLATEPACK.".to_string()),
+            WalletTask::Send(1_000_000_000, None, false, None),
+            WalletTask::Finalize(None, 1),
+            WalletTask::Post(None, 1),
+            WalletTask::SendBatch,
+            WalletTask::Consolidate(10),
+            WalletTask::EstimateSend(1_000_000_000, None),
+        ];
+        for task in tasks {
+            assert!(!task.is_watch_only());
+        }
+    }
+}
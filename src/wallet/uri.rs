@@ -0,0 +1,144 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Display, Formatter};
+use grin_core::core::{amount_from_hr_string, amount_to_hr_string};
+use grin_wallet_libwallet::SlatepackAddress;
+use url::form_urlencoded;
+use url::Url;
+
+/// Payment request URI in `grin:<address>?amount=<amount>&memo=<memo>` format.
+#[derive(Clone, PartialEq)]
+pub struct PaymentUri {
+    /// Receiver Slatepack address.
+    pub address: String,
+    /// Requested amount.
+    pub amount: Option<u64>,
+    /// Payment description.
+    pub memo: Option<String>,
+}
+
+impl PaymentUri {
+    /// URI scheme.
+    pub const SCHEME: &'static str = "grin";
+
+    /// Create payment URI for provided address, amount and memo.
+    pub fn new(address: String, amount: Option<u64>, memo: Option<String>) -> Self {
+        Self {
+            address,
+            amount: amount.filter(|a| *a > 0),
+            memo: memo.filter(|m| !m.trim().is_empty()),
+        }
+    }
+
+    /// Parse payment URI from provided text, returns `None` if text is not a valid URI
+    /// or address is incorrect.
+    pub fn parse(text: &str) -> Option<Self> {
+        let url = Url::parse(text.trim()).ok()?;
+        if url.scheme() != Self::SCHEME {
+            return None;
+        }
+        // Address can be uppercase at alphanumeric QR codes.
+        let address = url.path().trim_matches('/').to_lowercase();
+        SlatepackAddress::try_from(address.as_str()).ok()?;
+        let mut amount = None;
+        let mut memo = None;
+        for (key, value) in url.query_pairs() {
+            match key.as_ref() {
+                // Ignore incorrect amount to keep the address.
+                "amount" => {
+                    amount = amount_from_hr_string(value.trim()).ok().filter(|a| *a > 0);
+                }
+                "memo" => memo = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        Some(Self::new(address, amount, memo))
+    }
+}
+
+impl Display for PaymentUri {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        if let Some(amount) = self.amount {
+            query.append_pair("amount", amount_to_hr_string(amount, true).as_str());
+        }
+        if let Some(memo) = &self.memo {
+            query.append_pair("memo", memo.as_str());
+        }
+        let query = query.finish();
+        if query.is_empty() {
+            write!(f, "{}:{}", Self::SCHEME, self.address)
+        } else {
+            write!(f, "{}:{}?{}", Self::SCHEME, self.address, query)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "grin1tpnxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenxvenqnr5dak";
+
+    #[test]
+    fn parses_address_with_amount_and_memo() {
+        let text = format!("grin:{}?amount=1.5&memo=Coffee%20and%20cake", ADDRESS);
+        let uri = PaymentUri::parse(&text).unwrap();
+        assert_eq!(uri.address, ADDRESS);
+        assert_eq!(uri.amount, Some(1_500_000_000));
+        assert_eq!(uri.memo, Some("Coffee and cake".to_string()));
+    }
+
+    #[test]
+    fn parses_uppercase_address() {
+        let text = format!("GRIN:{}", ADDRESS.to_uppercase());
+        let uri = PaymentUri::parse(&text).unwrap();
+        assert_eq!(uri.address, ADDRESS);
+        assert_eq!(uri.amount, None);
+        assert_eq!(uri.memo, None);
+    }
+
+    #[test]
+    fn ignores_incorrect_amount() {
+        for amount in ["abc", "0", "-1", ""] {
+            let text = format!("grin:{}?amount={}&memo=test", ADDRESS, amount);
+            let uri = PaymentUri::parse(&text).unwrap();
+            assert_eq!(uri.address, ADDRESS);
+            assert_eq!(uri.amount, None);
+            assert_eq!(uri.memo, Some("test".to_string()));
+        }
+    }
+
+    #[test]
+    fn ignores_empty_memo() {
+        let uri = PaymentUri::parse(&format!("grin:{}?memo=%20", ADDRESS)).unwrap();
+        assert_eq!(uri.memo, None);
+    }
+
+    #[test]
+    fn rejects_incorrect_uri() {
+        assert!(PaymentUri::parse(ADDRESS).is_none());
+        assert!(PaymentUri::parse(&format!("bitcoin:{}", ADDRESS)).is_none());
+        assert!(PaymentUri::parse("grin:grin1invalid?amount=1").is_none());
+        assert!(PaymentUri::parse("").is_none());
+    }
+
+    #[test]
+    fn formats_and_parses_back() {
+        let uri = PaymentUri::new(ADDRESS.to_string(), Some(2_000_000_000), Some("a&b=c".into()));
+        let parsed = PaymentUri::parse(&uri.to_string()).unwrap();
+        assert!(parsed == uri);
+    }
+}
@@ -12,12 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use grin_keychain::mnemonic::{search, to_entropy};
 use sha2::{Sha256, Digest};
+use zeroize::Zeroizing;
 
 /// Wallet utilities functions.
 pub struct WalletUtils {}
 
 impl WalletUtils {
+    /// Amounts of recovery phrase words supported by SeedQR format.
+    pub const SEED_QR_WORDS: [usize; 2] = [12, 24];
+
+    /// Check if recovery phrase can be shown as SeedQR code.
+    pub fn is_seed_qr_supported(phrase: &str) -> bool {
+        Self::SEED_QR_WORDS.contains(&phrase.split_whitespace().count())
+    }
+
     /// Setup entropy data checksum.
     pub fn setup_checksum(data: &mut Vec<u8>) {
         let mut hasher = Sha256::new();
@@ -25,4 +35,89 @@ impl WalletUtils {
         let checksum = hasher.finalize();
         data.extend(checksum);
     }
-}
\ No newline at end of file
+
+    /// Create Standard SeedQR digits from mnemonic phrase.
+    /// https://github.com/SeedSigner/seedsigner/blob/dev/docs/seed_qr/README.md#standard-seedqr-specification
+    pub fn standard_seed_qr(phrase: &str) -> Option<String> {
+        if !Self::is_seed_qr_supported(phrase) {
+            return None;
+        }
+        let mut digits = String::new();
+        for word in phrase.split_whitespace() {
+            let index = search(word).ok()?;
+            digits.push_str(format!("{:04}", index).as_str());
+        }
+        Some(digits)
+    }
+
+    /// Create Compact SeedQR data from mnemonic phrase.
+    /// https://github.com/SeedSigner/seedsigner/blob/dev/docs/seed_qr/README.md#compactseedqr-specification
+    pub fn compact_seed_qr(phrase: &str) -> Option<Zeroizing<Vec<u8>>> {
+        if !Self::is_seed_qr_supported(phrase) {
+            return None;
+        }
+        to_entropy(phrase).ok().map(Zeroizing::new)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use grin_keychain::mnemonic::{from_entropy, WORDS};
+    use grin_util::{from_hex, ToHex};
+
+    /// SeedQR test vectors from specification with phrase, Standard digits and Compact data.
+    /// https://github.com/SeedSigner/seedsigner/blob/dev/docs/seed_qr/README.md
+    const VECTORS: [(&str, &str, &str); 2] = [
+        (
+            "attack pizza motion avocado network gather crop fresh patrol unusual wild holiday",
+            "011513251154012711900771041507421289190620080870",
+            "0e74b64107f94cc0ccfae6a13dcbec36",
+        ),
+        (
+            "sound federal bonus bleak light raise false engage round stock update render quote \
+            truck quality fringe palace foot recipe labor glow tortoise potato still",
+            "166206750203018810361417065805941507171219081456140818651401074412730727143709940798\
+            183613501710",
+            "cfca8c658bc81962549252bc7ac3ba5b0b01d26bcae89f2b5ecebe263dcb2a36",
+        ),
+    ];
+
+    #[test]
+    fn creates_standard_seed_qr() {
+        for (phrase, digits, _) in VECTORS {
+            assert_eq!(WalletUtils::standard_seed_qr(phrase), Some(digits.to_string()));
+        }
+    }
+
+    #[test]
+    fn creates_compact_seed_qr() {
+        for (phrase, _, data) in VECTORS {
+            let entropy = WalletUtils::compact_seed_qr(phrase).unwrap();
+            assert_eq!(entropy.to_hex(), data);
+        }
+    }
+
+    #[test]
+    fn decodes_seed_qr_to_phrase() {
+        for (phrase, digits, data) in VECTORS {
+            let words: Vec<String> = digits.as_bytes()
+                .chunks(4)
+                .map(|c| {
+                    let index: usize = std::str::from_utf8(c).unwrap().parse().unwrap();
+                    WORDS[index].clone()
+                })
+                .collect();
+            assert_eq!(words.join(" "), phrase);
+            assert_eq!(from_entropy(&from_hex(data).unwrap()).unwrap(), phrase);
+        }
+    }
+
+    #[test]
+    fn skips_unsupported_words_amount() {
+        let phrase = "attack pizza motion avocado network gather crop fresh patrol unusual wild \
+        holiday attack pizza motion";
+        assert!(!WalletUtils::is_seed_qr_supported(phrase));
+        assert_eq!(WalletUtils::standard_seed_qr(phrase), None);
+        assert!(WalletUtils::compact_seed_qr(phrase).is_none());
+    }
+}
@@ -15,15 +15,19 @@
 use crate::node::{Node, NodeConfig};
 use crate::tor::Tor;
 use crate::wallet::seed::WalletSeed;
-use crate::wallet::store::TxHeightStore;
-use crate::wallet::types::{ConnectionMethod, PhraseMode, WalletAccount, WalletData, WalletInstance, WalletTask, WalletTransaction, WalletTransactionAction};
-use crate::wallet::{ConnectionsConfig, Mnemonic, WalletConfig};
+use crate::wallet::store::{TxAddressStore, TxHeightStore, TxNoteStore};
+use crate::wallet::types::{BackupStatus, CoinControl, ConnectionMethod, PaymentProofStatus, PhraseMode, SendEstimate, TxExportFormat, WalletAccount, WalletData, WalletInstance, WalletOutput, WalletTask, WalletTransaction, WalletTransactionAction, WalletTransactionNote, WalletTxFilter};
+use crate::wallet::{BatchPayout, BatchRowStatus, ConnectionsConfig, HiddenOutputs, InvoiceRequest, InvoiceStatus, Mnemonic, PaymentRun, PaymentRunStatus, PaymentsLog, ReceiveAddress, ReceiveAddressProxy, RecurringPayment, WalletBackup, WalletConfig, WalletContact, WalletContacts, WalletInvoice, WalletInvoices, WatchOnlyData, WatchOnlyOutput};
 use crate::AppConfig;
 
 use futures::channel::oneshot;
+use lazy_static::lazy_static;
 use grin_api::{ApiServer, Router};
 use grin_chain::SyncStatus;
+use grin_core::core::amount_to_hr_string;
+use grin_core::libtx::tx_fee;
 use grin_keychain::{ExtKeychain, Identifier, Keychain};
+use grin_util::secp::pedersen::Commitment;
 use grin_util::secp::SecretKey;
 use grin_util::types::ZeroingString;
 use grin_util::{Mutex, ToHex};
@@ -32,8 +36,9 @@ use grin_wallet_controller::command::parse_slatepack;
 use grin_wallet_controller::controller;
 use grin_wallet_controller::controller::ForeignAPIHandlerV2;
 use grin_wallet_impls::{DefaultLCProvider, DefaultWalletImpl, HTTPNodeClient, LMDBBackend};
+use grin_wallet_libwallet::api_impl::owner;
 use grin_wallet_libwallet::api_impl::owner::{cancel_tx, retrieve_summary_info, retrieve_txs};
-use grin_wallet_libwallet::{address, Error, InitTxArgs, IssueInvoiceTxArgs, NodeClient, RetrieveTxQueryArgs, RetrieveTxQuerySortField, RetrieveTxQuerySortOrder, Slate, SlateState, SlateVersion, SlatepackAddress, StatusMessage, TxLogEntry, TxLogEntryType, VersionedSlate, WalletBackend, WalletInfo, WalletInitStatus, WalletInst, WalletLCProvider};
+use grin_wallet_libwallet::{address, Error, InitTxArgs, IssueInvoiceTxArgs, NodeClient, PaymentProof, RetrieveTxQueryArgs, RetrieveTxQuerySortField, RetrieveTxQuerySortOrder, Slate, SlateState, SlateVersion, SlatepackAddress, StatusMessage, TxLogEntry, TxLogEntryType, VersionedSlate, WalletInfo, WalletInitStatus, WalletInst, WalletLCProvider};
 use grin_wallet_util::OnionV3Address;
 use parking_lot::RwLock;
 use rand::Rng;
@@ -50,6 +55,14 @@ use std::thread::Thread;
 use std::time::Duration;
 use std::{fs, thread};
 
+lazy_static! {
+    /// Opened wallets to access from outside of the GUI.
+    static ref OPENED_WALLETS: Arc<RwLock<Vec<Wallet>>> = Arc::new(RwLock::new(vec![]));
+}
+
+/// Flag to ask user for confirmation of recurring payments instead of sending automatically.
+static PAYMENTS_ENABLED: AtomicBool = AtomicBool::new(false);
+
 /// Contains wallet instance, configuration and state, handles wallet commands.
 #[derive(Clone)]
 pub struct Wallet {
@@ -66,6 +79,28 @@ pub struct Wallet {
     /// Wallet accounts.
     accounts: Arc<RwLock<Vec<WalletAccount>>>,
 
+    /// Wallet address book.
+    contacts: Arc<RwLock<WalletContacts>>,
+
+    /// Filter for transactions list.
+    txs_filter: Arc<RwLock<WalletTxFilter>>,
+
+    /// Recurring payments run log.
+    payments_log: Arc<RwLock<PaymentsLog>>,
+    /// Identifiers of due recurring payments waiting for confirmation.
+    pending_payments: Arc<RwLock<Vec<i64>>>,
+
+    /// Issued invoices.
+    invoices: Arc<RwLock<WalletInvoices>>,
+
+    /// Scanned data of watch-only wallet.
+    watch_data: Arc<RwLock<WatchOnlyData>>,
+
+    /// Imported batch payout.
+    batch_payout: Arc<RwLock<Option<BatchPayout>>>,
+    /// Flag to check if batch payout is sending.
+    batch_sending: Arc<AtomicBool>,
+
     /// Wallet sync thread.
     sync_thread: Arc<RwLock<Option<Thread>>>,
     /// Flag to check if wallet is syncing.
@@ -91,6 +126,8 @@ pub struct Wallet {
 
     /// Running wallet foreign API server and port.
     foreign_api_server: Arc<RwLock<Option<(ApiServer, u16)>>>,
+    /// Running proxies for additional receive addresses.
+    address_proxies: Arc<RwLock<Vec<ReceiveAddressProxy>>>,
 
     /// Flag to check if wallet repairing and restoring missing outputs is needed.
     repair_needed: Arc<AtomicBool>,
@@ -104,18 +141,32 @@ pub struct Wallet {
     send_creating: Arc<AtomicBool>,
     /// Flag to check if invoice is creating.
     invoice_creating: Arc<AtomicBool>,
+    /// Flag to check if outputs consolidation is in progress.
+    consolidating: Arc<AtomicBool>,
+    /// Payment proof verification status.
+    proof_status: Arc<RwLock<Option<PaymentProofStatus>>>,
+    /// Wallet backup creation status.
+    backup_status: Arc<RwLock<Option<BackupStatus>>>,
+    /// Send estimation result for amount and inputs selection policy.
+    send_estimate: Arc<RwLock<Option<(u64, Option<CoinControl>, Result<SendEstimate, String>)>>>,
 
     /// Tasks sender.
     tasks_sender: Arc<RwLock<Option<Sender<WalletTask>>>>,
     /// Transaction identifier after successful task completion.
     /// To be replaced with https://github.com/lucasmerlin/hello_egui/tree/main/crates/egui_inbox.
     task_result_slate_id: Arc<RwLock<Option<String>>>,
+    /// Address of new receiver to offer saving at address book after successful sending over Tor.
+    contact_prompt: Arc<RwLock<Option<String>>>,
 }
 
 impl Wallet {
     /// Create new [`Wallet`] instance with provided [`WalletConfig`].
     fn new(config: WalletConfig) -> Self {
         let connection = config.connection();
+        let contacts = WalletContacts::load(&config);
+        let payments_log = PaymentsLog::load(&config);
+        let invoices = WalletInvoices::load(&config);
+        let watch_data = WatchOnlyData::load(&config);
         Self {
             config: Arc::new(RwLock::new(config)),
             instance: Arc::new(RwLock::new(None)),
@@ -123,6 +174,7 @@ impl Wallet {
             slatepack_address: Arc::new(RwLock::new(None)),
             sync_thread: Arc::from(RwLock::new(None)),
             foreign_api_server: Arc::new(RwLock::new(None)),
+            address_proxies: Arc::new(RwLock::new(vec![])),
             reopen: Arc::new(AtomicBool::new(false)),
             is_open: Arc::from(AtomicBool::new(false)),
             closing: Arc::new(AtomicBool::new(false)),
@@ -130,6 +182,14 @@ impl Wallet {
             sync_error: Arc::from(AtomicBool::new(false)),
             info_sync_progress: Arc::from(AtomicU8::new(0)),
             accounts: Arc::new(RwLock::new(vec![])),
+            contacts: Arc::new(RwLock::new(contacts)),
+            txs_filter: Arc::new(RwLock::new(WalletTxFilter::default())),
+            payments_log: Arc::new(RwLock::new(payments_log)),
+            pending_payments: Arc::new(RwLock::new(vec![])),
+            invoices: Arc::new(RwLock::new(invoices)),
+            watch_data: Arc::new(RwLock::new(watch_data)),
+            batch_payout: Arc::new(RwLock::new(None)),
+            batch_sending: Arc::new(AtomicBool::new(false)),
             data: Arc::new(RwLock::new(None)),
             sync_attempts: Arc::new(AtomicU8::new(0)),
             syncing: Arc::new(AtomicBool::new(false)),
@@ -138,8 +198,13 @@ impl Wallet {
             message_opening: Arc::new(AtomicBool::from(false)),
             send_creating: Arc::new(AtomicBool::new(false)),
             invoice_creating: Arc::new(AtomicBool::new(false)),
+            consolidating: Arc::new(AtomicBool::new(false)),
+            proof_status: Arc::new(RwLock::new(None)),
+            backup_status: Arc::new(RwLock::new(None)),
+            send_estimate: Arc::new(RwLock::new(None)),
             tasks_sender: Arc::new(RwLock::new(None)),
             task_result_slate_id: Arc::new(RwLock::new(None)),
+            contact_prompt: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -151,33 +216,81 @@ impl Wallet {
         conn_method: &ConnectionMethod
     ) -> Result<Wallet, Error> {
         let config = WalletConfig::create(name.clone(), conn_method);
-        let w = Wallet::new(config.clone());
-        {
-            // create directory if it doesn't exist
-            fs::create_dir_all(config.get_data_path())
-                .map_err(|_| Error::IO("Directory creation error".to_string()))?;
-            // Create seed file.
-            let _ = WalletSeed::init_file(config.seed_path().as_str(),
-                                          ZeroingString::from(mnemonic.get_phrase()),
-                                          password.clone())
-                .map_err(|_| Error::IO("Seed file creation error".to_string()))?;
-            let node_client = Self::create_node_client(&config)?;
-            let mut wallet: LMDBBackend<'static, HTTPNodeClient, ExtKeychain> =
-                match LMDBBackend::new(config.get_data_path().as_str(), node_client) {
-                    Err(_) => {
-                        return Err(Error::Lifecycle("DB creation error".to_string()).into());
-                    }
-                    Ok(d) => d,
-                };
-            // Save init status of this wallet, to determine whether it needs a full UTXO scan
-            let mut batch = wallet.batch_no_mask()?;
-            match mnemonic.mode() {
-                PhraseMode::Generate => batch.save_init_status(WalletInitStatus::InitNoScanning)?,
-                PhraseMode::Import => batch.save_init_status(WalletInitStatus::InitNeedsScanning)?,
-            }
-            batch.commit()?;
+        Self::init_files(&config, password, mnemonic)?;
+        Ok(Wallet::new(config))
+    }
+
+    /// Create new watch-only wallet from rewind hash.
+    pub fn create_watch_only(
+        name: &String,
+        password: &ZeroingString,
+        rewind_hash: &String,
+        conn_method: &ConnectionMethod
+    ) -> Result<Wallet, Error> {
+        let rewind_hash = rewind_hash.trim().to_lowercase();
+        if !WatchOnlyData::is_valid_rewind_hash(rewind_hash.as_str()) {
+            return Err(Error::GenericError("Invalid rewind hash".to_string()));
         }
-        Ok(w)
+        let mut config = WalletConfig::create(name.clone(), conn_method);
+        config.rewind_hash = Some(rewind_hash);
+        config.save();
+        // Local database with random seed is only used to access the node and scan the chain,
+        // funds are never received into it.
+        Self::init_files(&config, password, &Mnemonic::default())?;
+        Ok(Wallet::new(config))
+    }
+
+    /// Restore wallet from encrypted backup with provided name and password.
+    pub fn restore_backup(
+        name: &String,
+        password: &ZeroingString,
+        data: &str
+    ) -> Result<Wallet, Error> {
+        let config = WalletBackup::restore(data, name, password)?;
+        // Backup does not include wallet database, outputs are restored by chain scanning.
+        if let Err(e) = Self::init_db(&config, WalletInitStatus::InitNeedsScanning) {
+            let _ = fs::remove_dir_all(config.get_wallet_path());
+            return Err(e);
+        }
+        Ok(Wallet::new(config))
+    }
+
+    /// Create wallet seed file and database for provided config.
+    fn init_files(
+        config: &WalletConfig,
+        password: &ZeroingString,
+        mnemonic: &Mnemonic
+    ) -> Result<(), Error> {
+        // create directory if it doesn't exist
+        fs::create_dir_all(config.get_data_path())
+            .map_err(|_| Error::IO("Directory creation error".to_string()))?;
+        // Create seed file.
+        let _ = WalletSeed::init_file(config.seed_path().as_str(),
+                                      ZeroingString::from(mnemonic.get_phrase()),
+                                      password.clone())
+            .map_err(|_| Error::IO("Seed file creation error".to_string()))?;
+        let status = match mnemonic.mode() {
+            PhraseMode::Generate => WalletInitStatus::InitNoScanning,
+            PhraseMode::Import => WalletInitStatus::InitNeedsScanning,
+        };
+        Self::init_db(config, status)
+    }
+
+    /// Create wallet database for provided config with init status to determine whether
+    /// it needs a full UTXO scan.
+    fn init_db(config: &WalletConfig, status: WalletInitStatus) -> Result<(), Error> {
+        let node_client = Self::create_node_client(&config)?;
+        let mut wallet: LMDBBackend<'static, HTTPNodeClient, ExtKeychain> =
+            match LMDBBackend::new(config.get_data_path().as_str(), node_client) {
+                Err(_) => {
+                    return Err(Error::Lifecycle("DB creation error".to_string()).into());
+                }
+                Ok(d) => d,
+            };
+        let mut batch = wallet.batch_no_mask()?;
+        batch.save_init_status(status)?;
+        batch.commit()?;
+        Ok(())
     }
 
     /// Initialize [`Wallet`] from provided data path.
@@ -331,6 +444,7 @@ impl Wallet {
                         thread_w.clone().unwrap().unpark();
                     }
                     self.is_open.store(true, Ordering::Relaxed);
+                    self.set_opened(true);
                 }
                 Err(e) => {
                     if !self.syncing() {
@@ -342,6 +456,14 @@ impl Wallet {
             }
         }
 
+        // Watch-only wallet has no address to receive.
+        if self.is_watch_only() {
+            return Ok(());
+        }
+
+        // Unlock outputs left hidden by interrupted sending with coin control.
+        self.restore_hidden_outputs()?;
+
         // Set slatepack address.
         let r_inst = self.instance.as_ref().read();
         let instance = r_inst.clone().unwrap();
@@ -367,6 +489,11 @@ impl Wallet {
 
     /// Get wallet [`SecretKey`] for transports.
     pub fn get_secret_key(&self) -> Result<SecretKey, Error> {
+        self.get_address_secret_key(0)
+    }
+
+    /// Get [`SecretKey`] of address at provided derivation index for transports.
+    pub fn get_address_secret_key(&self, index: u32) -> Result<SecretKey, Error> {
         let r_inst = self.instance.as_ref().read();
         let instance = r_inst.clone().unwrap();
         let mut w_lock = instance.lock();
@@ -374,7 +501,7 @@ impl Wallet {
         let w_inst = lc.wallet_inst()?;
         let k = w_inst.keychain((&None).as_ref())?;
         let parent_key_id = w_inst.parent_key_id();
-        let sec_key = address::address_from_derivation_path(&k, &parent_key_id, 0)
+        let sec_key = address::address_from_derivation_path(&k, &parent_key_id, index)
             .map_err(|e| Error::TorConfig(format!("{:?}", e)))?;
         Ok(sec_key)
     }
@@ -395,6 +522,19 @@ impl Wallet {
         None
     }
 
+    /// Check if wallet is watch-only.
+    pub fn is_watch_only(&self) -> bool {
+        self.config.read().is_watch_only()
+    }
+
+    /// Return an error for transactions at watch-only wallet.
+    fn check_spending(&self) -> Result<(), Error> {
+        if self.is_watch_only() {
+            return Err(Error::GenericError("Watch-only wallet".to_string()));
+        }
+        Ok(())
+    }
+
     /// Get wallet config.
     pub fn get_config(&self) -> WalletConfig {
         self.config.read().clone()
@@ -453,6 +593,19 @@ impl Wallet {
         w_config.save();
     }
 
+    /// Get maximum amount of outputs to merge by one consolidation transaction.
+    pub fn consolidation_batch(&self) -> usize {
+        let r_config = self.config.read();
+        r_config.consolidation_batch.unwrap_or(WalletConfig::CONSOLIDATION_BATCH_DEFAULT)
+    }
+
+    /// Update maximum amount of outputs to merge by one consolidation transaction.
+    pub fn update_consolidation_batch(&self, batch: usize) {
+        let mut w_config = self.config.write();
+        w_config.consolidation_batch = Some(batch);
+        w_config.save();
+    }
+
     /// Update external connection identifier.
     pub fn update_connection(&self, conn: &ConnectionMethod) {
         let mut w_config = self.config.write();
@@ -515,14 +668,24 @@ impl Wallet {
                 w_api_server.as_mut().unwrap().0.stop();
                 *w_api_server = None;
             }
-            // Stop running Tor service.
+            // Stop running Tor services.
             Tor::stop_service(&service_id);
+            wallet_close.stop_receive_services();
+            // Stop running address proxies.
+            {
+                let mut w_proxies = wallet_close.address_proxies.write();
+                for p in w_proxies.iter() {
+                    p.stop();
+                }
+                *w_proxies = vec![];
+            }
             // Close the wallet.
             let r_inst = wallet_close.instance.as_ref().read();
             let instance = r_inst.clone().unwrap();
             Self::close_wallet(&instance);
             wallet_close.closing.store(false, Ordering::Relaxed);
             wallet_close.is_open.store(false, Ordering::Relaxed);
+            wallet_close.set_opened(false);
             // Setup current connection.
             {
                 let mut w_conn = conn.write();
@@ -533,6 +696,21 @@ impl Wallet {
         });
     }
 
+    /// Add or remove the wallet from the list of opened wallets.
+    fn set_opened(&self, opened: bool) {
+        let id = self.get_config().id;
+        let mut w_opened = OPENED_WALLETS.write();
+        w_opened.retain(|w| w.get_config().id != id);
+        if opened {
+            w_opened.push(self.clone());
+        }
+    }
+
+    /// Get list of opened wallets.
+    pub fn opened_list() -> Vec<Wallet> {
+        OPENED_WALLETS.read().clone()
+    }
+
     /// Close wallet for provided [`WalletInstance`].
     fn close_wallet(instance: &WalletInstance) {
         let mut wallet_lock = instance.lock();
@@ -582,11 +760,16 @@ impl Wallet {
         self.sync_attempts.store(0, Ordering::Relaxed);
     }
 
-    /// Send a task to the wallet.
-    pub fn task(&self, task: WalletTask) {
+    /// Send a task to the wallet, returns an error if task is not available for the wallet.
+    pub fn task(&self, task: WalletTask) -> Result<(), Error> {
+        if self.is_watch_only() && !task.is_watch_only() {
+            return Err(Error::GenericError("Not available for watch-only wallet".to_string()));
+        }
         let r_tasks = self.tasks_sender.read();
-        if r_tasks.is_some() {
-            let _ = r_tasks.as_ref().unwrap().send(task);
+        match r_tasks.as_ref() {
+            Some(tx) => tx.send(task)
+                .map_err(|_| Error::GenericError("Wallet is not opened".to_string())),
+            None => Err(Error::GenericError("Wallet is not opened".to_string()))
         }
     }
 
@@ -627,9 +810,10 @@ impl Wallet {
             Ok(())
         })?;
 
-        // Stop service from previous account.
+        // Stop services from previous account.
         let cur_service_id = self.identifier();
         Tor::stop_service(&cur_service_id);
+        self.stop_receive_services();
 
         // Save account label into config.
         let mut w_config = self.config.write();
@@ -677,6 +861,388 @@ impl Wallet {
         self.accounts.read().clone()
     }
 
+    /// Get address book contacts.
+    pub fn contacts(&self) -> Vec<WalletContact> {
+        self.contacts.read().list().clone()
+    }
+
+    /// Find address book contact by Slatepack address.
+    pub fn contact(&self, address: &String) -> Option<WalletContact> {
+        self.contacts.read().find(address).cloned()
+    }
+
+    /// Save contact to address book.
+    pub fn save_contact(&self, contact: WalletContact) {
+        let mut w_contacts = self.contacts.write();
+        w_contacts.add(contact);
+        w_contacts.save(&self.get_config());
+    }
+
+    /// Delete contact from address book.
+    pub fn delete_contact(&self, address: &String) {
+        let mut w_contacts = self.contacts.write();
+        w_contacts.remove(address);
+        w_contacts.save(&self.get_config());
+    }
+
+    /// Export address book to JSON.
+    pub fn export_contacts(&self) -> Vec<u8> {
+        self.contacts.read().to_json().into_bytes()
+    }
+
+    /// Import contacts from JSON into address book, returns `false` on parsing error.
+    pub fn import_contacts(&self, data: &String) -> bool {
+        if let Some(contacts) = WalletContacts::from_json(data) {
+            let mut w_contacts = self.contacts.write();
+            for c in contacts {
+                w_contacts.add(c);
+            }
+            w_contacts.save(&self.get_config());
+            return true;
+        }
+        false
+    }
+
+    /// Get additional addresses to receive transactions for current account.
+    pub fn receive_addresses(&self) -> Vec<ReceiveAddress> {
+        let config = self.get_config();
+        config.receive_addresses
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|a| a.account == config.account)
+            .collect()
+    }
+
+    /// Get additional receive address of current account by derivation index.
+    pub fn receive_address(&self, index: u32) -> Option<ReceiveAddress> {
+        self.receive_addresses().into_iter().find(|a| a.index == index)
+    }
+
+    /// Derive new address with provided label to receive transactions for current account.
+    pub fn add_receive_address(&self, label: String) -> Result<ReceiveAddress, Error> {
+        let config = self.get_config();
+        let last_index = config.receive_address_indexes
+            .as_ref()
+            .and_then(|i| i.get(&config.account).cloned())
+            .unwrap_or(0);
+        let index = self.receive_addresses().iter()
+            .map(|a| a.index)
+            .max()
+            .unwrap_or(0)
+            .max(last_index) + 1;
+        let instance = self.instance.as_ref().read().clone().unwrap();
+        let mut api = Owner::new(instance, None);
+        let mut address = "".to_string();
+        controller::owner_single_use(None, None, Some(&mut api), |api, m| {
+            address = api.get_slatepack_address(m, index)?.to_string();
+            Ok(())
+        })?;
+        let receive_address = ReceiveAddress {
+            index,
+            account: config.account,
+            label,
+            address,
+        };
+        {
+            let mut w_config = self.config.write();
+            let mut addresses = w_config.receive_addresses.clone().unwrap_or_default();
+            addresses.push(receive_address.clone());
+            w_config.receive_addresses = Some(addresses);
+            let mut indexes = w_config.receive_address_indexes.clone().unwrap_or_default();
+            indexes.insert(receive_address.account.clone(), index);
+            w_config.receive_address_indexes = Some(indexes);
+            w_config.save();
+        }
+        // Start listener if main address service is running.
+        if Tor::is_service_running(&self.identifier()) {
+            self.start_receive_service(&receive_address);
+        }
+        Ok(receive_address)
+    }
+
+    /// Delete additional receive address of current account and stop its listener.
+    pub fn delete_receive_address(&self, index: u32) {
+        Tor::stop_service(&self.receive_service_id(index));
+        {
+            let mut w_proxies = self.address_proxies.write();
+            if let Some(pos) = w_proxies.iter().position(|p| p.index == index) {
+                w_proxies.remove(pos).stop();
+            }
+        }
+        let mut w_config = self.config.write();
+        let account = w_config.account.clone();
+        let mut addresses = w_config.receive_addresses.clone().unwrap_or_default();
+        addresses.retain(|a| a.index != index || a.account != account);
+        w_config.receive_addresses = Some(addresses);
+        w_config.save();
+    }
+
+    /// Get Tor service identifier for additional receive address.
+    pub fn receive_service_id(&self, index: u32) -> String {
+        format!("{}_{}", self.identifier(), index)
+    }
+
+    /// Start Tor listeners for additional receive addresses of current account.
+    pub fn start_receive_services(&self) {
+        for a in self.receive_addresses() {
+            self.start_receive_service(&a);
+        }
+    }
+
+    /// Stop Tor listeners for additional receive addresses of current account.
+    pub fn stop_receive_services(&self) {
+        for a in self.receive_addresses() {
+            Tor::stop_service(&self.receive_service_id(a.index));
+        }
+    }
+
+    /// Restart running Tor listeners for additional receive addresses of current account.
+    pub fn restart_receive_services(&self) {
+        for a in self.receive_addresses() {
+            let service_id = self.receive_service_id(a.index);
+            if Tor::is_service_running(&service_id) {
+                Tor::stop_service(&service_id);
+                self.start_receive_service(&a);
+            }
+        }
+    }
+
+    /// Start Tor listener for additional receive address.
+    fn start_receive_service(&self, address: &ReceiveAddress) {
+        let api_port = match self.foreign_api_port() {
+            None => return,
+            Some(p) => p
+        };
+        // Start proxy to tag transactions received at address.
+        let proxy_port = {
+            let mut w_proxies = self.address_proxies.write();
+            match w_proxies.iter().find(|p| p.index == address.index) {
+                Some(p) => p.port,
+                None => {
+                    let db_path = self.get_config().get_extra_db_path();
+                    match ReceiveAddressProxy::start(address.index, api_port, db_path) {
+                        Ok(p) => {
+                            let port = p.port;
+                            w_proxies.push(p);
+                            port
+                        }
+                        Err(_) => return
+                    }
+                }
+            }
+        };
+        if let Ok(key) = self.get_address_secret_key(address.index) {
+            Tor::start_service(proxy_port, key, &self.receive_service_id(address.index));
+        }
+    }
+
+    /// Enable recurring payments for long-lived GUI session, due payments are sent
+    /// after user confirmation.
+    pub fn enable_payments() {
+        PAYMENTS_ENABLED.store(true, Ordering::Relaxed);
+    }
+
+    /// Get list of recurring payments.
+    pub fn recurring_payments(&self) -> Vec<RecurringPayment> {
+        self.config.read().recurring_payments.clone().unwrap_or_default()
+    }
+
+    /// Add new or update existing recurring payment.
+    pub fn save_recurring_payment(&self, payment: RecurringPayment) {
+        let mut w_config = self.config.write();
+        let mut payments = w_config.recurring_payments.clone().unwrap_or_default();
+        if let Some(pos) = payments.iter().position(|p| p.id == payment.id) {
+            payments[pos] = payment;
+        } else {
+            payments.push(payment);
+        }
+        w_config.recurring_payments = Some(payments);
+        w_config.save();
+    }
+
+    /// Delete recurring payment.
+    pub fn delete_recurring_payment(&self, id: i64) {
+        {
+            let mut w_config = self.config.write();
+            let mut payments = w_config.recurring_payments.clone().unwrap_or_default();
+            payments.retain(|p| p.id != id);
+            w_config.recurring_payments = Some(payments);
+            w_config.save();
+        }
+        let mut w_pending = self.pending_payments.write();
+        w_pending.retain(|p| *p != id);
+    }
+
+    /// Get recurring payments run log.
+    pub fn payments_log(&self) -> Vec<PaymentRun> {
+        self.payments_log.read().runs().clone()
+    }
+
+    /// Get due recurring payment waiting for confirmation.
+    pub fn pending_payment(&self) -> Option<RecurringPayment> {
+        let id = self.pending_payments.read().first().cloned()?;
+        self.recurring_payments().into_iter().find(|p| p.id == id)
+    }
+
+    /// Confirm sending or skip due recurring payment waiting for confirmation.
+    pub fn confirm_payment(&self, id: i64, send: bool) {
+        {
+            let mut w_pending = self.pending_payments.write();
+            w_pending.retain(|p| *p != id);
+        }
+        if let Some(mut p) = self.recurring_payments().into_iter().find(|p| p.id == id) {
+            if !send {
+                self.log_payment_run(&p, PaymentRunStatus::Skipped);
+            }
+            // Schedule next payment before sending to not ask again.
+            let payment = p.clone();
+            p.schedule_next(chrono::Utc::now().timestamp());
+            self.save_recurring_payment(p);
+            if send {
+                let _ = self.task(WalletTask::SendPayment(payment));
+            }
+        }
+    }
+
+    /// Ask for confirmation of due recurring payments when payments are enabled.
+    fn check_payments(&self) {
+        if !PAYMENTS_ENABLED.load(Ordering::Relaxed) {
+            return;
+        }
+        let now = chrono::Utc::now().timestamp();
+        for p in self.recurring_payments() {
+            if !p.is_due(now) {
+                continue;
+            }
+            let mut w_pending = self.pending_payments.write();
+            if !w_pending.contains(&p.id) {
+                w_pending.push(p.id);
+            }
+        }
+    }
+
+    /// Send recurring payment over Tor and save run result.
+    async fn send_payment(&self, payment: &RecurringPayment) {
+        let spendable = self.get_data().map(|d| d.info.amount_currently_spendable).unwrap_or(0);
+        let status = if spendable < payment.amount {
+            PaymentRunStatus::Failed("Not enough funds".to_string())
+        } else {
+            match self.send_over_tor(&payment.address, payment.amount).await {
+                BatchRowStatus::Sent(id) => PaymentRunStatus::Sent(id),
+                BatchRowStatus::Failed(e) | BatchRowStatus::NotPosted(_, e) => {
+                    PaymentRunStatus::Failed(e)
+                }
+                // Log unfinished sending as failed run to not lose it.
+                BatchRowStatus::Pending | BatchRowStatus::Sending => {
+                    PaymentRunStatus::Failed("Payment was not sent".to_string())
+                }
+            }
+        };
+        self.log_payment_run(payment, status);
+    }
+
+    /// Save recurring payment run result.
+    fn log_payment_run(&self, payment: &RecurringPayment, status: PaymentRunStatus) {
+        let run = PaymentRun {
+            payment_id: payment.id,
+            time: chrono::Utc::now().timestamp(),
+            amount: payment.amount,
+            address: payment.address.clone(),
+            status,
+        };
+        let mut w_log = self.payments_log.write();
+        w_log.add(run, &self.get_config());
+    }
+
+    /// Get imported batch payout.
+    pub fn batch_payout(&self) -> Option<BatchPayout> {
+        self.batch_payout.read().clone()
+    }
+
+    /// Set batch payout to send, can not be changed while sending.
+    pub fn set_batch_payout(&self, batch: Option<BatchPayout>) {
+        if self.batch_sending() {
+            return;
+        }
+        let mut w_batch = self.batch_payout.write();
+        *w_batch = batch;
+    }
+
+    /// Start sending of batch payout if it is not sending already.
+    pub fn send_batch(&self) {
+        if self.is_watch_only() || self.batch_sending.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        let sent = self.tasks_sender
+            .read()
+            .as_ref()
+            .map(|tx| tx.send(WalletTask::SendBatch).is_ok())
+            .unwrap_or(false);
+        if !sent {
+            self.batch_sending.store(false, Ordering::Relaxed);
+        }
+    }
+
+    /// Check if batch payout is sending.
+    pub fn batch_sending(&self) -> bool {
+        self.batch_sending.load(Ordering::Relaxed)
+    }
+
+    /// Update status of batch payout row.
+    fn on_batch_row_status(&self, index: usize, status: BatchRowStatus) {
+        let mut w_batch = self.batch_payout.write();
+        if let Some(row) = w_batch.as_mut().and_then(|b| b.rows.get_mut(index)) {
+            row.status = status;
+        }
+    }
+
+    /// Send amount to the address over Tor, returns sending status.
+    async fn send_over_tor(&self, address: &String, amount: u64) -> BatchRowStatus {
+        let addr = match SlatepackAddress::try_from(address.trim()) {
+            Ok(addr) => addr,
+            Err(_) => return BatchRowStatus::Failed("Incorrect address".to_string())
+        };
+        let slate = match self.send(amount, Some(addr.clone()), true, None) {
+            Ok(s) => s,
+            Err(e) => return BatchRowStatus::Failed(e.to_string())
+        };
+        sync_wallet_data(self, false);
+        let id = slate.id.to_string();
+        match self.send_tor(&slate, &addr).await {
+            Ok(s) => {
+                // Transaction can not be cancelled after receiver response.
+                match self.finalize(&s).and_then(|s| self.post(&s)) {
+                    Ok(_) => BatchRowStatus::Sent(id),
+                    Err(e) => {
+                        let err = e.to_string();
+                        self.on_tx_error(id.clone(), Some(e));
+                        BatchRowStatus::NotPosted(id, err)
+                    }
+                }
+            }
+            Err(e) => {
+                // Cancel transaction to unlock outputs and allow to retry.
+                let r_inst = self.instance.as_ref().read();
+                let instance = r_inst.clone().unwrap();
+                let _ = cancel_tx(instance, None, &None, None, Some(slate.id));
+                self.on_tx_action(id, None);
+                BatchRowStatus::Failed(e.to_string())
+            }
+        }
+    }
+
+    /// Get filter for transactions list.
+    pub fn txs_filter(&self) -> WalletTxFilter {
+        self.txs_filter.read().clone()
+    }
+
+    /// Update filter for transactions list.
+    pub fn set_txs_filter(&self, mut filter: WalletTxFilter) {
+        filter.parse_ranges();
+        let mut w_filter = self.txs_filter.write();
+        *w_filter = filter;
+    }
+
     /// Get wallet data.
     pub fn get_data(&self) -> Option<WalletData> {
         let r_data = self.data.read();
@@ -782,7 +1348,7 @@ impl Wallet {
     }
 
     /// Parse Slatepack message into [`Slate`].
-    fn parse_slatepack(&self, text: &String) -> Result<Slate, grin_wallet_controller::Error> {
+    pub fn parse_slatepack(&self, text: &String) -> Result<Slate, grin_wallet_controller::Error> {
         let r_inst = self.instance.as_ref().read();
         let instance = r_inst.clone().unwrap();
         let mut api = Owner::new(instance, None);
@@ -794,12 +1360,17 @@ impl Wallet {
 
     /// Create Slatepack message from provided slate.
     fn create_slatepack_message(&self, slate: &Slate) -> Result<String, Error> {
+        self.create_slatepack_message_from(slate, 0)
+    }
+
+    /// Create Slatepack message from provided slate with sender address derived at index.
+    fn create_slatepack_message_from(&self, slate: &Slate, index: u32) -> Result<String, Error> {
         let mut message = "".to_string();
         let r_inst = self.instance.as_ref().read();
         let instance = r_inst.clone().unwrap();
         let mut api = Owner::new(instance, None);
         controller::owner_single_use(None, None, Some(&mut api), |api, m| {
-            message = api.create_slatepack_message(m, &slate, Some(0), vec![])?;
+            message = api.create_slatepack_message(m, &slate, Some(index), vec![])?;
             Ok(())
         })?;
 
@@ -826,25 +1397,297 @@ impl Wallet {
         None
     }
 
-    /// Initialize a transaction to send amount, return request for funds receiver.
-    fn send(&self, a: u64, r: Option<SlatepackAddress>) -> Result<Slate, Error> {
+    /// Save user note for transaction of current account.
+    pub fn update_tx_note(&self, id: u32, note: WalletTransactionNote) {
+        let mut w_data = self.data.write();
+        if let Some(txs) = w_data.as_mut().and_then(|d| d.txs.as_mut()) {
+            for tx in txs {
+                if tx.data.id == id {
+                    let tx_note_store = TxNoteStore::new(self.get_config().get_extra_db_path());
+                    tx_note_store.write_note(&tx.data, &note);
+                    // Update note at wallet data.
+                    tx.note = if note.is_empty() {
+                        None
+                    } else {
+                        Some(note.clone())
+                    };
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Export transaction history of current account in provided format.
+    pub fn export_txs(&self, format: &TxExportFormat) -> Result<Vec<u8>, Error> {
+        let txs = self.get_data()
+            .and_then(|d| d.txs)
+            .ok_or(Error::GenericError("Wallet data is not loaded".to_string()))?;
+        let tx_height_store = TxHeightStore::new(self.get_config().get_extra_db_path());
+        let rows = txs.iter().map(|tx| {
+            let direction = if tx.data.tx_type == TxLogEntryType::ConfirmedCoinbase {
+                "coinbase"
+            } else if tx.data.amount_debited > tx.data.amount_credited {
+                "sent"
+            } else {
+                "received"
+            };
+            let status = match tx.data.tx_type {
+                TxLogEntryType::TxSentCancelled |
+                TxLogEntryType::TxReceivedCancelled => "cancelled",
+                TxLogEntryType::TxReverted => "reverted",
+                _ => if tx.data.confirmed {
+                    "confirmed"
+                } else if tx.broadcasting() {
+                    "broadcasting"
+                } else {
+                    "pending"
+                }
+            };
+            let height = tx_height_store.read_tx_height(tx.data.id).or(tx.height);
+            json!({
+                "id": tx.data.id,
+                "slate_id": tx.data.tx_slate_id.map(|id| id.to_string()),
+                "direction": direction,
+                "status": status,
+                "amount": amount_to_hr_string(tx.amount, false),
+                "fee": tx.data.fee.map(|f| amount_to_hr_string(f.fee(), false)),
+                "kernel_excess": tx.data.kernel_excess.map(|k| k.0.to_hex()),
+                "height": height,
+                "created": tx.data.creation_ts.to_rfc3339(),
+                "confirmed": tx.data.confirmation_ts.map(|t| t.to_rfc3339()),
+                "label": tx.note.as_ref().map(|n| n.label.clone()),
+                "counterparty": tx.note.as_ref().map(|n| n.counterparty.clone()),
+                "note": tx.note.as_ref().map(|n| n.note.clone()),
+            })
+        }).collect::<Vec<Value>>();
+
+        match format {
+            TxExportFormat::Json => {
+                Ok(serde_json::to_vec_pretty(&rows).unwrap_or_default())
+            }
+            TxExportFormat::Csv => {
+                const COLUMNS: [&'static str; 13] = [
+                    "id", "slate_id", "direction", "status", "amount", "fee",
+                    "kernel_excess", "height", "created", "confirmed", "label", "counterparty",
+                    "note"
+                ];
+                let mut csv = format!("{}\n", COLUMNS.join(","));
+                for row in rows {
+                    let values = COLUMNS.iter().map(|c| match &row[c] {
+                        Value::Null => "".to_string(),
+                        Value::String(v) => csv_value(v),
+                        v => v.to_string()
+                    }).collect::<Vec<String>>();
+                    csv.push_str(&format!("{}\n", values.join(",")));
+                }
+                Ok(csv.into_bytes())
+            }
+        }
+    }
+
+    /// Get smallest spendable outputs of current account to merge by consolidation transaction.
+    pub fn consolidation_outputs(&self, batch: usize) -> Vec<WalletOutput> {
+        let account = self.get_config().account;
+        let mut outputs = self.outputs()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|o| o.spendable && o.account == account)
+            .collect::<Vec<WalletOutput>>();
+        outputs.sort_by_key(|o| o.value);
+        outputs.truncate(batch);
+        outputs
+    }
+
+    /// Calculate fee of consolidation transaction for provided amount of outputs.
+    pub fn consolidation_fee(outputs: usize) -> u64 {
+        // Merged outputs are spent into single output without change.
+        tx_fee(outputs, 1, 1)
+    }
+
+    /// Merge provided outputs into single output of current account.
+    fn consolidate(&self, outputs: &Vec<WalletOutput>) -> Result<Slate, Error> {
+        self.check_spending()?;
+        let total: u64 = outputs.iter().map(|o| o.value).sum();
+        let fee = Self::consolidation_fee(outputs.len());
+        if outputs.len() < 2 || total <= fee {
+            return Err(Error::GenericError("Not enough outputs to consolidate".to_string()));
+        }
+        let coin_control = CoinControl {
+            outputs: outputs.iter().map(|o| o.commit.clone()).collect(),
+            max_inputs: Some(outputs.len() as u32),
+            change_outputs: 1,
+        };
+        let slate = self.send(total - fee, None, false, Some(coin_control))?;
+        sync_wallet_data(self, false);
+
+        // Receive funds at the same account.
+        let res = (|| -> Result<Slate, Error> {
+            let r_inst = self.instance.as_ref().read();
+            let instance = r_inst.clone().unwrap();
+            let api = Owner::new(instance, None);
+            let mut slate = slate.clone();
+            controller::foreign_single_use(api.wallet_inst.clone(), None, |api| {
+                slate = api.receive_tx(&slate, Some(self.get_config().account.as_str()), None)?;
+                Ok(())
+            })?;
+            Ok(slate)
+        })();
+        match res {
+            Ok(s) => {
+                match self.finalize(&s).and_then(|s| self.post(&s).map(|_| s)) {
+                    Ok(s) => Ok(s),
+                    Err(e) => {
+                        let err = e.to_string();
+                        self.on_tx_error(s.id.to_string(), Some(e));
+                        Err(Error::GenericError(err))
+                    }
+                }
+            }
+            Err(e) => {
+                // Cancel transaction to unlock outputs.
+                let r_inst = self.instance.as_ref().read();
+                let instance = r_inst.clone().unwrap();
+                let _ = cancel_tx(instance, None, &None, None, Some(slate.id));
+                Err(e)
+            }
+        }
+    }
+
+    /// Check if outputs consolidation is in progress.
+    pub fn consolidating(&self) -> bool {
+        self.consolidating.load(Ordering::Relaxed)
+    }
+
+    /// Get spendable outputs of current account to use as inputs by provided policy.
+    fn eligible_inputs(&self, coin_control: &CoinControl) -> Vec<WalletOutput> {
+        let account = self.get_config().account;
+        let mut outputs = self.outputs()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|o| o.spendable && o.account == account)
+            .filter(|o| coin_control.outputs.is_empty() || coin_control.outputs.contains(&o.commit))
+            .collect::<Vec<WalletOutput>>();
+        outputs.sort_by_key(|o| o.value);
+        outputs
+    }
+
+    /// Get maximum amount to send spending all available inputs without change.
+    pub fn max_send_amount(&self, coin_control: Option<CoinControl>) -> u64 {
+        let coin_control = coin_control.unwrap_or_default();
+        let mut inputs = self.eligible_inputs(&coin_control);
+        if coin_control.outputs.is_empty() {
+            // Use largest outputs when amount of inputs is limited.
+            let max = coin_control.max_inputs.unwrap_or(InitTxArgs::default().max_outputs);
+            inputs.reverse();
+            inputs.truncate(max as usize);
+        }
+        let total: u64 = inputs.iter().map(|o| o.value).sum();
+        total.saturating_sub(tx_fee(inputs.len(), 1, 1))
+    }
+
+    /// Get send estimation result for provided amount and inputs selection policy,
+    /// `None` if estimation is not finished.
+    pub fn send_estimate(&self,
+                         a: u64,
+                         coin_control: &Option<CoinControl>) -> Option<Result<SendEstimate, String>> {
+        let r_estimate = self.send_estimate.read();
+        r_estimate.as_ref()
+            .filter(|(amount, c, _)| *amount == a && c == coin_control)
+            .map(|(_, _, res)| res.clone())
+    }
+
+    /// Estimate fee, inputs and change outputs to send amount without locking outputs.
+    fn estimate_send(&self,
+                         a: u64,
+                         coin_control: Option<CoinControl>) -> Result<SendEstimate, Error> {
+        let coin_control = coin_control.unwrap_or_default();
+        let inputs = self.eligible_inputs(&coin_control);
+        let change = coin_control.change_outputs.max(1);
+        let (inputs_amount, fee, inputs_count) = if !coin_control.outputs.is_empty() {
+            // Selected outputs are spent all, so calculate fee as wallet does.
+            let total: u64 = inputs.iter().map(|o| o.value).sum();
+            let mut fee = tx_fee(inputs.len(), 1, 1);
+            if total != a + fee {
+                fee = tx_fee(inputs.len(), change + 1, 1);
+            }
+            if inputs.is_empty() || total < a + fee {
+                return Err(Error::GenericError("Not enough funds".to_string()));
+            }
+            (total, fee, Some(inputs.len()))
+        } else {
+            let config = self.get_config();
+            let mut args = InitTxArgs {
+                src_acct_name: Some(config.account),
+                amount: a,
+                minimum_confirmations: config.min_confirmations,
+                num_change_outputs: change,
+                selection_strategy_is_use_all: false,
+                estimate_only: Some(true),
+                ..Default::default()
+            };
+            if let Some(max) = coin_control.max_inputs {
+                args.max_outputs = max;
+            }
+            let r_inst = self.instance.as_ref().read();
+            let instance = r_inst.clone().unwrap();
+            let api = Owner::new(instance, None);
+            let slate = api.init_send_tx(None, args)?;
+            let total = slate.amount;
+
+            // Find amount of inputs by their total value, smallest outputs are selected first.
+            let mut sum = 0;
+            let count = inputs.iter().position(|o| {
+                sum += o.value;
+                sum == total
+            }).map(|i| i + 1);
+            (total, slate.fee_fields.fee(), count)
+        };
+        let change_outputs = if inputs_amount > a + fee { change } else { 0 };
+        Ok(SendEstimate {
+            amount: a,
+            fee,
+            inputs_amount,
+            inputs: inputs_count,
+            change_outputs,
+        })
+    }
+
+    /// Initialize a transaction to send amount with optional inputs selection policy,
+    /// return request for funds receiver.
+    pub fn send(&self,
+                a: u64,
+                r: Option<SlatepackAddress>,
+                proof: bool,
+                coin_control: Option<CoinControl>) -> Result<Slate, Error> {
+        self.check_spending()?;
         let config = self.get_config();
-        let args = InitTxArgs {
-            payment_proof_recipient_address: r,
+        let coin_control = coin_control.unwrap_or_default();
+        let use_selected = !coin_control.outputs.is_empty();
+        let mut args = InitTxArgs {
+            payment_proof_recipient_address: if proof { r } else { None },
             src_acct_name: Some(config.account),
             amount: a,
             minimum_confirmations: config.min_confirmations,
-            num_change_outputs: 1,
-            selection_strategy_is_use_all: false,
+            num_change_outputs: coin_control.change_outputs.max(1),
+            selection_strategy_is_use_all: use_selected,
             ..Default::default()
         };
-        let r_inst = self.instance.as_ref().read();
-        let instance = r_inst.clone().unwrap();
-        let api = Owner::new(instance, None);
-        let slate = api.init_send_tx(None, args)?;
+        if let Some(max) = coin_control.max_inputs {
+            args.max_outputs = max;
+        }
 
-        // Lock outputs to for this transaction.
-        api.tx_lock_outputs(None, &slate)?;
+        let slate = if use_selected {
+            self.init_send_selected(args, &coin_control.outputs)?
+        } else {
+            let r_inst = self.instance.as_ref().read();
+            let instance = r_inst.clone().unwrap();
+            let api = Owner::new(instance, None);
+            let slate = api.init_send_tx(None, args)?;
+
+            // Lock outputs to for this transaction.
+            api.tx_lock_outputs(None, &slate)?;
+            slate
+        };
 
         // Create Slatepack message response.
         let _ = self.create_slatepack_message(&slate)?;
@@ -852,8 +1695,74 @@ impl Wallet {
         Ok(slate)
     }
 
+    /// Initialize transaction spending only provided outputs of current account.
+    /// Other spendable outputs are locked while the wallet selects inputs and unlocked
+    /// under the same wallet lock, they are saved to unlock on opening if sending was interrupted.
+    fn init_send_selected(&self, args: InitTxArgs, commits: &Vec<String>) -> Result<Slate, Error> {
+        let config = self.get_config();
+        let r_inst = self.instance.as_ref().read();
+        let instance = r_inst.clone().unwrap();
+        let mut w_lock = instance.lock();
+        let w = w_lock.lc_provider()?.wallet_inst()?;
+        let path = config.get_hidden_outputs_path();
+        HiddenOutputs::with_hidden(&mut **w, commits, path, |w| {
+            let slate = owner::init_send_tx(w, None, args, false)?;
+            // Lock outputs to for this transaction.
+            owner::tx_lock_outputs(w, None, &slate)?;
+            Ok(slate)
+        })
+    }
+
+    /// Unlock outputs left hidden from inputs selection by interrupted sending.
+    fn restore_hidden_outputs(&self) -> Result<(), Error> {
+        let hidden = match HiddenOutputs::load(self.get_config().get_hidden_outputs_path()) {
+            Some(h) => h,
+            None => return Ok(())
+        };
+        let r_inst = self.instance.as_ref().read();
+        let instance = r_inst.clone().unwrap();
+        let mut w_lock = instance.lock();
+        let w = w_lock.lc_provider()?.wallet_inst()?;
+        hidden.restore(&mut **w)
+    }
+
+    /// Get outputs of all accounts from newest to oldest.
+    pub fn outputs(&self) -> Result<Vec<WalletOutput>, Error> {
+        let height = self.get_data().map(|d| d.info.last_confirmed_height).unwrap_or(0);
+        let min_confirmations = self.get_config().min_confirmations;
+        if self.is_watch_only() {
+            let account = self.get_config().account;
+            let r_data = self.watch_data.read();
+            return Ok(r_data.wallet_outputs(&account, height, min_confirmations));
+        }
+        let accounts = self.accounts();
+        let r_inst = self.instance.as_ref().read();
+        let instance = r_inst.clone().unwrap();
+        let mut w_lock = instance.lock();
+        let w = w_lock.lc_provider()?.wallet_inst()?;
+        let mut outputs: Vec<WalletOutput> = w.iter().map(|o| {
+            let path = o.root_key_id.to_bip_32_string();
+            let account = accounts.iter()
+                .find(|a| a.path == path)
+                .map(|a| a.label.clone())
+                .unwrap_or(path);
+            WalletOutput {
+                commit: o.commit.clone().unwrap_or_default(),
+                value: o.value,
+                status: o.status.clone(),
+                height: o.height,
+                lock_height: o.lock_height,
+                is_coinbase: o.is_coinbase,
+                account,
+                spendable: o.eligible_to_spend(height, min_confirmations),
+            }
+        }).collect();
+        outputs.sort_by_key(|o| std::cmp::Reverse(o.height));
+        Ok(outputs)
+    }
+
     /// Send slate to Tor address.
-    async fn send_tor(&self, slate: &Slate, addr: &SlatepackAddress) -> Result<Slate, Error> {
+    pub async fn send_tor(&self, slate: &Slate, addr: &SlatepackAddress) -> Result<Slate, Error> {
         self.on_tx_action(slate.id.to_string(), Some(WalletTransactionAction::SendingTor));
 
         let tor_addr = OnionV3Address::try_from(addr).unwrap().to_http_str();
@@ -879,7 +1788,12 @@ impl Wallet {
         // Parse response.
         let res: Value = serde_json::from_str(&req_res.unwrap()).unwrap();
         if res["error"] != json!(null) {
-            return Err(Error::GenericError("Response error".to_string()));
+            // Show receiver error message if provided.
+            let msg = match res["error"]["message"].as_str() {
+                Some(m) => format!("Response error: {}", m),
+                None => "Response error".to_string()
+            };
+            return Err(Error::GenericError(msg));
         }
         let slate_value = res["result"]["Ok"].clone();
         let res = Slate::deserialize_upgrade(&serde_json::to_string(&slate_value).unwrap());
@@ -895,8 +1809,21 @@ impl Wallet {
         self.send_creating.load(Ordering::Relaxed)
     }
 
-    /// Initialize an invoice transaction to receive amount, return request for funds sender.
-    fn issue_invoice(&self, amount: u64) -> Result<Slate, Error> {
+    /// Initialize an invoice transaction to receive amount with provided details,
+    /// return request for funds sender.
+    pub fn issue_invoice(&self, amount: u64, request: InvoiceRequest) -> Result<Slate, Error> {
+        self.check_spending()?;
+        // Find derivation index of address invoice is requested by.
+        let index = if request.requested_by.is_empty()
+            || self.slatepack_address().as_ref() == Some(&request.requested_by) {
+            0
+        } else {
+            self.receive_addresses()
+                .into_iter()
+                .find(|a| a.address == request.requested_by)
+                .map(|a| a.index)
+                .ok_or(Error::GenericError("Unknown invoice address".to_string()))?
+        };
         let args = IssueInvoiceTxArgs {
             dest_acct_name: None,
             amount,
@@ -907,19 +1834,106 @@ impl Wallet {
         let api = Owner::new(instance, None);
         let slate = api.issue_invoice_tx(None, args)?;
 
-        // Create Slatepack message response.
-        let _ = self.create_slatepack_message(&slate)?;
+        // Create Slatepack message response from address invoice is requested by.
+        let _ = self.create_slatepack_message_from(&slate, index)?;
+
+        // Save invoice details.
+        let config = self.get_config();
+        let invoice = WalletInvoice {
+            slate_id: slate.id.to_string(),
+            account: config.account.clone(),
+            amount,
+            created: chrono::Utc::now().timestamp(),
+            request,
+        };
+        self.invoices.write().add(invoice, &config);
 
         Ok(slate)
     }
 
+    /// Get issued invoices of current account.
+    pub fn invoices(&self) -> Vec<WalletInvoice> {
+        let account = self.get_config().account;
+        self.invoices.read()
+            .list()
+            .iter()
+            .filter(|i| i.account == account)
+            .cloned()
+            .collect()
+    }
+
+    /// Get issued invoice by transaction slate identifier.
+    pub fn invoice(&self, slate_id: &String) -> Option<WalletInvoice> {
+        self.invoices.read().find(slate_id).cloned()
+    }
+
+    /// Cancel expired invoices waiting for payment.
+    fn check_invoices(&self) {
+        let data = match self.get_data() {
+            None => return,
+            Some(d) => d
+        };
+        let mut cancelled = false;
+        for i in self.invoices() {
+            if !i.expired() {
+                continue;
+            }
+            if let Some(tx) = data.tx_by_slate_id(i.slate_id.clone()) {
+                if i.status(Some(&tx)) == InvoiceStatus::Expired && tx.can_cancel() {
+                    cancelled = self.cancel(&tx).is_ok() || cancelled;
+                }
+            }
+        }
+        if cancelled {
+            sync_wallet_data(self, false);
+        }
+    }
+
     /// Check if request to receive funds is creating.
     pub fn invoice_creating(&self) -> bool {
         self.invoice_creating.load(Ordering::Relaxed)
     }
 
+    /// Get payment proof for sent transaction by local identifier.
+    pub fn payment_proof(&self, id: u32) -> Result<PaymentProof, Error> {
+        let r_inst = self.instance.as_ref().read();
+        let instance = r_inst.clone().unwrap();
+        let api = Owner::new(instance, None);
+        api.retrieve_payment_proof(None, false, Some(id), None)
+    }
+
+    /// Export payment proof for sent transaction as JSON.
+    pub fn export_payment_proof(&self, id: u32) -> Result<Vec<u8>, Error> {
+        let proof = self.payment_proof(id)?;
+        serde_json::to_vec_pretty(&proof).map_err(|e| Error::GenericError(e.to_string()))
+    }
+
+    /// Verify payment proof from JSON, returns proof with flags to check if sender or receiver
+    /// address belongs to the wallet.
+    pub fn verify_payment_proof(&self, data: &String) -> Result<(PaymentProof, bool, bool), Error> {
+        let proof = serde_json::from_str::<PaymentProof>(data.trim())
+            .map_err(|e| Error::GenericError(e.to_string()))?;
+        let r_inst = self.instance.as_ref().read();
+        let instance = r_inst.clone().unwrap();
+        let api = Owner::new(instance, None);
+        let (sender, receiver) = api.verify_payment_proof(None, &proof)?;
+        Ok((proof, sender, receiver))
+    }
+
+    /// Get payment proof verification status.
+    pub fn proof_status(&self) -> Option<PaymentProofStatus> {
+        self.proof_status.read().clone()
+    }
+
+    /// Clear payment proof verification status.
+    pub fn clear_proof_status(&self) {
+        let mut w_status = self.proof_status.write();
+        *w_status = None;
+    }
+
     /// Handle message from the invoice issuer to send founds, return response for funds receiver.
-    fn pay(&self, slate: &Slate) -> Result<Slate, Error> {
+    pub fn pay(&self, slate: &Slate) -> Result<Slate, Error> {
+        self.check_spending()?;
         let config = self.get_config();
         let args = InitTxArgs {
             src_acct_name: None,
@@ -941,7 +1955,8 @@ impl Wallet {
     }
 
     /// Create response to sender to receive funds.
-    fn receive(&self, slate: &Slate) -> Result<Slate, Error> {
+    pub fn receive(&self, slate: &Slate) -> Result<Slate, Error> {
+        self.check_spending()?;
         let r_inst = self.instance.as_ref().read();
         let instance = r_inst.clone().unwrap();
         let api = Owner::new(instance, None);
@@ -958,7 +1973,7 @@ impl Wallet {
     }
 
     /// Finalize transaction from provided message as sender or invoice issuer.
-    fn finalize(&self, slate: &Slate) -> Result<Slate, Error> {
+    pub fn finalize(&self, slate: &Slate) -> Result<Slate, Error> {
         self.on_tx_action(slate.id.to_string(), Some(WalletTransactionAction::Finalizing));
 
         let r_inst = self.instance.as_ref().read();
@@ -976,7 +1991,7 @@ impl Wallet {
     }
 
     /// Post transaction to blockchain.
-    fn post(&self, slate: &Slate) -> Result<(), Error> {
+    pub fn post(&self, slate: &Slate) -> Result<(), Error> {
         self.on_tx_action(slate.id.to_string(), Some(WalletTransactionAction::Posting));
 
         let r_inst = self.instance.as_ref().read();
@@ -1037,6 +2052,12 @@ impl Wallet {
         res
     }
 
+    /// Consume address of new receiver to offer saving at address book.
+    pub fn consume_contact_prompt(&self) -> Option<String> {
+        let mut w_prompt = self.contact_prompt.write();
+        w_prompt.take()
+    }
+
     /// Get possible transaction confirmation height, .
     fn tx_height(&self, tx: &WalletTransaction) -> Result<Option<u64>, Error> {
         let mut tx_height = None;
@@ -1065,7 +2086,7 @@ impl Wallet {
     }
 
     /// Get transaction from database.
-    fn get_tx(&self, tx_id: u32) -> Option<Slate> {
+    pub fn get_tx(&self, tx_id: u32) -> Option<Slate> {
         let r_inst = self.instance.as_ref().read();
         let instance = r_inst.clone().unwrap();
         let api = Owner::new(instance, None);
@@ -1131,6 +2152,46 @@ impl Wallet {
         lc.get_mnemonic(None, ZeroingString::from(password))
     }
 
+    /// Get rewind hash to create watch-only wallet.
+    pub fn get_rewind_hash(&self, password: String) -> Result<String, Error> {
+        // Check password.
+        self.get_recovery(password)?;
+        if let Some(hash) = self.get_config().rewind_hash {
+            return Ok(hash);
+        }
+        let r_inst = self.instance.as_ref().read();
+        let instance = r_inst.clone().unwrap();
+        let api = Owner::new(instance, None);
+        api.get_rewind_hash(None)
+    }
+
+    /// Create encrypted backup of wallet files with provided password.
+    fn create_backup(&self, password: &ZeroingString) -> BackupStatus {
+        // Check password.
+        if self.get_recovery(password.to_string()).is_err() {
+            return BackupStatus::WrongPassword;
+        }
+        // Hold wallet instance lock to not modify database while its files are reading.
+        let r_inst = self.instance.as_ref().read();
+        let instance = r_inst.clone().unwrap();
+        let _wallet_lock = instance.lock();
+        match WalletBackup::create(&self.get_config(), password) {
+            Ok(data) => BackupStatus::Created(data),
+            Err(e) => BackupStatus::Failed(e.to_string())
+        }
+    }
+
+    /// Get wallet backup creation status.
+    pub fn backup_status(&self) -> Option<BackupStatus> {
+        self.backup_status.read().clone()
+    }
+
+    /// Clear wallet backup creation status.
+    pub fn clear_backup_status(&self) {
+        let mut w_status = self.backup_status.write();
+        *w_status = None;
+    }
+
     /// Close the wallet, delete its files and mark it as deleted.
     pub fn delete_wallet(&self) {
         if self.is_open() {
@@ -1257,7 +2318,7 @@ fn start_sync(wallet: Wallet) -> Thread {
                 sync_wallet_data(&wallet, false);
             }
 
-            if wallet.is_open() && !wallet.is_closing() {
+            if wallet.is_open() && !wallet.is_closing() && !wallet.is_watch_only() {
                 // Start Foreign API listener if not running.
                 let mut api_server_running = {
                     wallet.foreign_api_server.read().is_some()
@@ -1282,11 +2343,20 @@ fn start_sync(wallet: Wallet) -> Thread {
                     if let Ok(sec_key) = wallet.get_secret_key() {
                         Tor::start_service(api.1, sec_key, &wallet.identifier());
                     }
+                    drop(r_foreign_api);
+                    wallet.start_receive_services();
                 }
             }
 
             // Sync wallet from node.
             sync_wallet_data(&wallet, true);
+
+            // Check recurring payments and expired invoices after successful sync.
+            if wallet.get_sync_attempts() == 0 && !wallet.sync_error() &&
+                !wallet.is_watch_only() {
+                wallet.check_payments();
+                wallet.check_invoices();
+            }
         }
 
         // Stop sync if wallet was closed.
@@ -1323,6 +2393,12 @@ async fn handle_task(w: &Wallet, t: WalletTask) {
                         match w.post(&s) {
                             Ok(_) => {
                                 sync_wallet_data(&w, false);
+                                // Offer to save new receiver at address book.
+                                let address = r.to_string();
+                                if w.contact(&address).is_none() {
+                                    let mut w_prompt = w.contact_prompt.write();
+                                    *w_prompt = Some(address);
+                                }
                                 w.on_tx_result(&s);
                             }
                             Err(e) => {
@@ -1345,9 +2421,9 @@ async fn handle_task(w: &Wallet, t: WalletTask) {
         WalletTask::OpenMessage(m) => {
             w.open_message(m);
         }
-        WalletTask::Send(a, r) => {
+        WalletTask::Send(a, r, p, c) => {
             w.send_creating.store(true, Ordering::Relaxed);
-            if let Ok(s) = w.send(*a, r.clone()) {
+            if let Ok(s) = w.send(*a, r.clone(), *p, c.clone()) {
                 sync_wallet_data(&w, false);
                 w.send_creating.store(false, Ordering::Relaxed);
                 if let Some(r) = r {
@@ -1355,6 +2431,8 @@ async fn handle_task(w: &Wallet, t: WalletTask) {
                 } else {
                     w.on_tx_result(&s);
                 }
+            } else {
+                w.send_creating.store(false, Ordering::Relaxed);
             }
         }
         WalletTask::SendTor(id, r) => {
@@ -1362,9 +2440,9 @@ async fn handle_task(w: &Wallet, t: WalletTask) {
                send_tor(&s, r).await;
             }
         }
-        WalletTask::Receive(a) => {
+        WalletTask::Receive(a, r) => {
             w.invoice_creating.store(true, Ordering::Relaxed);
-            if let Ok(s) = w.issue_invoice(*a) {
+            if let Ok(s) = w.issue_invoice(*a, r.clone()) {
                 sync_wallet_data(&w, false);
                 w.on_tx_result(&s);
             }
@@ -1440,6 +2518,66 @@ async fn handle_task(w: &Wallet, t: WalletTask) {
                 }
             }
         }
+        WalletTask::Consolidate(batch) => {
+            w.consolidating.store(true, Ordering::Relaxed);
+            let outputs = w.consolidation_outputs(*batch);
+            match w.consolidate(&outputs) {
+                Ok(s) => {
+                    sync_wallet_data(&w, false);
+                    w.on_tx_result(&s);
+                }
+                Err(_) => {
+                    sync_wallet_data(&w, false);
+                }
+            }
+            w.consolidating.store(false, Ordering::Relaxed);
+        }
+        WalletTask::SendBatch => {
+            let rows = w.batch_payout().map(|b| b.rows).unwrap_or_default();
+            for (index, row) in rows.iter().enumerate() {
+                if !row.can_send() {
+                    continue;
+                }
+                if !w.is_open() || w.is_closing() {
+                    break;
+                }
+                w.on_batch_row_status(index, BatchRowStatus::Sending);
+                let status = w.send_over_tor(&row.address, row.amount).await;
+                w.on_batch_row_status(index, status);
+            }
+            sync_wallet_data(&w, false);
+            w.batch_sending.store(false, Ordering::Relaxed);
+        }
+        WalletTask::EstimateSend(a, coin_control) => {
+            let res = w.estimate_send(a, coin_control.clone()).map_err(|e| e.to_string());
+            let mut w_estimate = w.send_estimate.write();
+            *w_estimate = Some((a, coin_control, res));
+        }
+        WalletTask::CreateBackup(password) => {
+            {
+                let mut w_status = w.backup_status.write();
+                *w_status = Some(BackupStatus::Creating);
+            }
+            let status = w.create_backup(&password);
+            let mut w_status = w.backup_status.write();
+            *w_status = Some(status);
+        }
+        WalletTask::SendPayment(payment) => {
+            w.send_payment(&payment).await;
+            sync_wallet_data(&w, false);
+        }
+        WalletTask::VerifyProof(data) => {
+            {
+                let mut w_status = w.proof_status.write();
+                *w_status = Some(PaymentProofStatus::Verifying);
+            }
+            let status = match w.verify_payment_proof(data) {
+                Ok((p, sender, receiver)) => PaymentProofStatus::Valid(p, sender, receiver),
+                Err(e) => PaymentProofStatus::Invalid(e.to_string())
+            };
+            let mut w_status = w.proof_status.write();
+            *w_status = Some(status);
+        }
     };
 }
 
@@ -1467,6 +2605,23 @@ fn sync_wallet_data(wallet: &Wallet, from_node: bool) {
 
     let config = wallet.get_config();
 
+    // Scan the chain for watch-only wallet.
+    if wallet.is_watch_only() {
+        if !from_node {
+            // Show saved data before scanning.
+            let data = wallet.watch_data.read().clone();
+            if data.height != 0 {
+                update_watch_only_data(wallet, data.info(data.height, config.min_confirmations));
+            }
+            return;
+        }
+        match scan_watch_only(wallet, info_tx) {
+            Ok(_) => wallet.reset_sync_attempts(),
+            Err(_) => on_sync_failed(wallet)
+        }
+        return;
+    }
+
     // Retrieve wallet info.
     let r_inst = wallet.instance.as_ref().read();
     if r_inst.is_some() {
@@ -1514,6 +2669,11 @@ fn sync_wallet_data(wallet: &Wallet, from_node: bool) {
         }
     }
 
+    on_sync_failed(wallet);
+}
+
+/// Handle failed [`WalletData`] sync.
+fn on_sync_failed(wallet: &Wallet) {
     // Reset progress.
     wallet.info_sync_progress.store(0, Ordering::Relaxed);
 
@@ -1536,6 +2696,81 @@ fn sync_wallet_data(wallet: &Wallet, from_node: bool) {
     }
 }
 
+/// Scan the chain for outputs of watch-only wallet by rewind hash.
+fn scan_watch_only(wallet: &Wallet, info_tx: Sender<StatusMessage>) -> Result<(), Error> {
+    let config = wallet.get_config();
+    let rewind_hash = config.rewind_hash.clone().unwrap();
+    let instance = {
+        let r_inst = wallet.instance.as_ref().read();
+        r_inst.clone().ok_or(Error::GenericError("Wallet is not open".to_string()))?
+    };
+    let mut data = wallet.watch_data.read().clone();
+
+    // Remove spent outputs and get current chain height.
+    let height = {
+        let mut w_lock = instance.lock();
+        let w = w_lock.lc_provider()?.wallet_inst()?;
+        let client = w.w2n_client();
+        if !data.outputs.is_empty() {
+            let commits = data.outputs.iter()
+                .filter_map(|o| grin_util::from_hex(o.commit.as_str()).ok())
+                .map(Commitment::from_vec)
+                .collect::<Vec<Commitment>>();
+            let unspent = client.get_outputs_from_node(&commits)?
+                .keys()
+                .map(|c| c.0.to_hex())
+                .collect::<Vec<String>>();
+            data.outputs.retain(|o| unspent.contains(&o.commit));
+        }
+        client.get_chain_tip()?.0
+    };
+
+    // Scan new outputs.
+    let start_height = data.height.saturating_sub(WatchOnlyData::RESCAN_DEPTH).max(1);
+    let api = Owner::new(instance, Some(info_tx));
+    let view = api.scan_rewind_hash(rewind_hash, Some(start_height))?;
+    let outputs = view.output_result.into_iter().map(|o| {
+        WatchOnlyOutput {
+            commit: o.commit,
+            value: o.value,
+            height: o.height,
+            lock_height: o.lock_height,
+            is_coinbase: o.is_coinbase,
+        }
+    }).collect();
+    data.add_outputs(outputs);
+    data.height = height;
+
+    // Exit if wallet was closed.
+    if !wallet.is_open() || wallet.is_closing() {
+        return Err(Error::GenericError("Wallet is not open".to_string()));
+    }
+
+    // Save scanned data.
+    data.save(&config);
+    update_watch_only_data(wallet, data.info(height, config.min_confirmations));
+    {
+        let mut w_data = wallet.watch_data.write();
+        *w_data = data;
+    }
+    wallet.info_sync_progress.store(100, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Update account and [`WalletData`] of watch-only wallet.
+fn update_watch_only_data(wallet: &Wallet, info: WalletInfo) {
+    {
+        let mut w_accounts = wallet.accounts.write();
+        *w_accounts = vec![WalletAccount {
+            spendable_amount: info.amount_currently_spendable,
+            label: wallet.get_config().account,
+            path: "m/0/0".to_string(),
+        }];
+    }
+    let mut w_data = wallet.data.write();
+    *w_data = Some(WalletData { info, txs: Some(vec![]) });
+}
+
 /// Update wallet transactions.
 fn update_txs(wallet: &Wallet, instance: WalletInstance, info: WalletInfo)
     -> Result<(), Error> {
@@ -1566,6 +2801,8 @@ fn update_txs(wallet: &Wallet, instance: WalletInstance, info: WalletInfo)
     }).collect::<Vec<TxLogEntry>>();
 
     let tx_height_store = TxHeightStore::new(wallet.get_config().get_extra_db_path());
+    let tx_note_store = TxNoteStore::new(wallet.get_config().get_extra_db_path());
+    let tx_address_store = TxAddressStore::new(wallet.get_config().get_extra_db_path());
     let data = wallet.get_data().unwrap();
     let data_txs = data.txs.unwrap_or(vec![]);
     let mut new_txs: Vec<WalletTransaction> = vec![];
@@ -1589,6 +2826,13 @@ fn update_txs(wallet: &Wallet, instance: WalletInstance, info: WalletInfo)
                                              broadcasting_height,
                                              action,
                                              action_error);
+        new.note = tx_note_store.read_note(tx);
+        // Setup additional address transaction was received at.
+        if tx.tx_type == TxLogEntryType::TxReceived {
+            new.receive_address = tx.tx_slate_id
+                .and_then(|id| tx_address_store.read_index(&id.to_string()))
+                .and_then(|index| wallet.receive_address(index));
+        }
         // Update Slate state for unconfirmed.
         let unconfirmed = !tx.confirmed && (tx.tx_type == TxLogEntryType::TxSent ||
             tx.tx_type == TxLogEntryType::TxReceived);
@@ -1720,6 +2964,18 @@ fn update_accounts(wallet: &Wallet, height: u64, spendable: Option<u64>) {
 
 /// Scan wallet's outputs, repairing and restoring missing outputs if required.
 fn repair_wallet(wallet: &Wallet) {
+    // Scan the chain from the start for watch-only wallet.
+    if wallet.is_watch_only() {
+        let data = WatchOnlyData::default();
+        data.save(&wallet.get_config());
+        {
+            let mut w_data = wallet.watch_data.write();
+            *w_data = data;
+        }
+        wallet.repair_needed.store(false, Ordering::Relaxed);
+        return;
+    }
+
     let (info_tx, info_rx) = mpsc::channel::<StatusMessage>();
     // Update scan progress at separate thread.
     let wallet_scan = wallet.clone();
@@ -1765,4 +3021,13 @@ fn repair_wallet(wallet: &Wallet) {
 
     // Reset repair progress.
     wallet.repair_progress.store(0, Ordering::Relaxed);
+}
+
+/// Format CSV value, quoting it when it contains separators, quotes or line breaks.
+fn csv_value(value: &String) -> String {
+    if value.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", value.replace("\"", "\"\""))
+    } else {
+        value.clone()
+    }
 }
\ No newline at end of file
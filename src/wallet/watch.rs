@@ -0,0 +1,180 @@
+// Copyright 2025 The Grim Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use grin_wallet_libwallet::{OutputStatus, WalletInfo};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::Settings;
+use crate::wallet::types::WalletOutput;
+use crate::wallet::WalletConfig;
+
+/// Unspent output found at the chain by rewind hash of watched wallet.
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct WatchOnlyOutput {
+    /// Output commitment.
+    pub commit: String,
+    /// Output value.
+    pub value: u64,
+    /// Block height of the output.
+    pub height: u64,
+    /// Block height after which output can be spent.
+    pub lock_height: u64,
+    /// Flag to check if output is coinbase.
+    pub is_coinbase: bool,
+}
+
+/// Data of watch-only wallet scanned from the chain.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct WatchOnlyData {
+    /// Last scanned block height.
+    pub height: u64,
+    /// Unspent outputs from newest to oldest.
+    pub outputs: Vec<WatchOnlyOutput>,
+}
+
+impl WatchOnlyData {
+    /// Watch-only data file name.
+    pub const FILE_NAME: &'static str = "watch.toml";
+
+    /// Amount of blocks to scan again on sync to handle chain reorganization.
+    pub const RESCAN_DEPTH: u64 = 60;
+
+    /// Check if provided text is a valid rewind hash.
+    pub fn is_valid_rewind_hash(hash: &str) -> bool {
+        hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit())
+    }
+
+    /// Load data for provided wallet configuration.
+    pub fn load(config: &WalletConfig) -> Self {
+        Settings::read_from_file::<WatchOnlyData>(config.get_watch_data_path())
+            .unwrap_or_default()
+    }
+
+    /// Save data for provided wallet configuration.
+    pub fn save(&self, config: &WalletConfig) {
+        Settings::write_to_file(self, config.get_watch_data_path());
+    }
+
+    /// Add scanned outputs skipping already known.
+    pub fn add_outputs(&mut self, outputs: Vec<WatchOnlyOutput>) {
+        for o in outputs {
+            if !self.outputs.iter().any(|e| e.commit == o.commit) {
+                self.outputs.push(o);
+            }
+        }
+        self.outputs.sort_by_key(|o| std::cmp::Reverse(o.height));
+    }
+
+    /// Calculate wallet balance at provided chain height.
+    pub fn info(&self, height: u64, min_confirmations: u64) -> WalletInfo {
+        let mut info = WalletInfo {
+            last_confirmed_height: height,
+            minimum_confirmations: min_confirmations,
+            total: 0,
+            amount_awaiting_finalization: 0,
+            amount_awaiting_confirmation: 0,
+            amount_immature: 0,
+            amount_currently_spendable: 0,
+            amount_locked: 0,
+            amount_reverted: 0,
+        };
+        for o in &self.outputs {
+            info.total += o.value;
+            if o.is_coinbase && o.lock_height > height {
+                info.amount_immature += o.value;
+            } else if o.height + min_confirmations > height + 1 {
+                info.amount_awaiting_confirmation += o.value;
+            } else {
+                info.amount_currently_spendable += o.value;
+            }
+        }
+        info
+    }
+
+    /// Get outputs for provided account label at chain height.
+    pub fn wallet_outputs(&self,
+                          account: &String,
+                          height: u64,
+                          min_confirmations: u64) -> Vec<WalletOutput> {
+        self.outputs.iter().map(|o| {
+            let mature = !o.is_coinbase || o.lock_height <= height;
+            let confirmed = o.height + min_confirmations <= height + 1;
+            WalletOutput {
+                commit: o.commit.clone(),
+                value: o.value,
+                status: OutputStatus::Unspent,
+                height: o.height,
+                lock_height: o.lock_height,
+                is_coinbase: o.is_coinbase,
+                account: account.clone(),
+                spendable: mature && confirmed,
+            }
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Create output with provided value, height and coinbase lock height.
+    fn output(value: u64, height: u64, lock_height: Option<u64>) -> WatchOnlyOutput {
+        WatchOnlyOutput {
+            commit: format!("commit{}", value),
+            value,
+            height,
+            lock_height: lock_height.unwrap_or(0),
+            is_coinbase: lock_height.is_some(),
+        }
+    }
+
+    #[test]
+    fn calculates_balance() {
+        let data = WatchOnlyData {
+            height: 1000,
+            outputs: vec![
+                output(1, 1000, None),
+                output(2, 992, None),
+                output(4, 990, None),
+                output(8, 500, Some(1500)),
+                output(16, 100, Some(999)),
+            ],
+        };
+        let info = data.info(1000, 10);
+        assert_eq!(info.last_confirmed_height, 1000);
+        assert_eq!(info.minimum_confirmations, 10);
+        assert_eq!(info.total, 31);
+        assert_eq!(info.amount_awaiting_confirmation, 3);
+        assert_eq!(info.amount_immature, 8);
+        assert_eq!(info.amount_currently_spendable, 20);
+        assert_eq!(info.amount_locked, 0);
+        assert_eq!(info.amount_awaiting_finalization, 0);
+    }
+
+    #[test]
+    fn calculates_empty_balance() {
+        let info = WatchOnlyData::default().info(1000, 10);
+        assert_eq!(info.total, 0);
+        assert_eq!(info.amount_currently_spendable, 0);
+    }
+
+    #[test]
+    fn adds_unknown_outputs_only() {
+        let mut data = WatchOnlyData::default();
+        data.add_outputs(vec![output(1, 10, None), output(2, 30, None)]);
+        data.add_outputs(vec![output(1, 10, None), output(4, 20, None)]);
+        let heights: Vec<u64> = data.outputs.iter().map(|o| o.height).collect();
+        assert_eq!(heights, vec![30, 20, 10]);
+    }
+}